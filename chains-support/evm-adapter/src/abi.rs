@@ -0,0 +1,401 @@
+//! Contract ABIs registered with [`EvmStorage`](crate::EvmStorage), served as the decoded
+//! `events.<Event>` and `calls.<function>` entities.
+
+use {
+    crate::{error::EvmStorageError, resolvers::address_value},
+    alloy::{
+        dyn_abi::{DynSolType, DynSolValue, Specifier},
+        hex,
+        json_abi::{Event, Function, JsonAbi},
+        primitives::Address,
+    },
+    std::collections::{hash_map::Entry, HashMap},
+    wql_core::{
+        ast::{ColumnDef, DataType},
+        data::{Schema, Value},
+        error::Result,
+    },
+};
+
+const EVENTS_PREFIX: &str = "events.";
+const CALLS_PREFIX: &str = "calls.";
+
+/// Columns every decoded event row starts with.
+pub const EVENT_COLUMNS: [(&str, DataType); 4] = [
+    ("block_number", DataType::Uint64),
+    ("transaction_hash", DataType::Text),
+    ("log_index", DataType::Uint64),
+    ("contract", DataType::Text),
+];
+
+/// Columns every decoded call row starts with.
+pub const CALL_COLUMNS: [(&str, DataType); 5] = [
+    ("block_number", DataType::Uint64),
+    ("transaction_hash", DataType::Text),
+    ("transaction_index", DataType::Uint64),
+    ("caller", DataType::Text),
+    ("contract", DataType::Text),
+];
+
+/// ABI item along with the contracts it was registered against.
+#[derive(Clone, Debug)]
+pub struct Registered<T> {
+    pub item: T,
+    /// Empty when the item was registered for any contract.
+    pub addresses: Vec<Address>,
+}
+
+impl<T> Registered<T> {
+    pub fn accepts(&self, address: &Address) -> bool {
+        self.addresses.is_empty() || self.addresses.contains(address)
+    }
+
+    fn merge(&mut self, address: Option<Address>) {
+        match address {
+            _ if self.addresses.is_empty() => {}
+            None => self.addresses.clear(),
+            Some(address) if !self.addresses.contains(&address) => self.addresses.push(address),
+            Some(_) => {}
+        }
+    }
+}
+
+/// Events and functions of the registered ABIs, by lowercase name.
+///
+/// Only the first of overloaded items is served, as decoded entities are addressed by name.
+#[derive(Clone, Debug, Default)]
+pub struct AbiRegistry {
+    events: HashMap<String, Registered<Event>>,
+    functions: HashMap<String, Registered<Function>>,
+}
+
+/// Decoded entity a table name resolves to.
+#[derive(Clone, Copy, Debug)]
+pub enum Decoded<'a> {
+    Event(&'a Registered<Event>),
+    Call(&'a Registered<Function>),
+}
+
+impl AbiRegistry {
+    /// Registers the events and functions of `abi`, decoded only for `address` when given and
+    /// for any contract otherwise.
+    pub fn register(&mut self, abi: &JsonAbi, address: Option<Address>) -> Result<()> {
+        for event in abi.events.values().filter_map(|events| events.first()) {
+            register(&mut self.events, &event.name, event, address, |a, b| {
+                a.selector() == b.selector()
+            })?;
+        }
+
+        for function in abi
+            .functions
+            .values()
+            .filter_map(|functions| functions.first())
+        {
+            register(
+                &mut self.functions,
+                &function.name,
+                function,
+                address,
+                |a, b| a.selector() == b.selector(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn decoded(&self, table_name: &str) -> Option<Decoded<'_>> {
+        let table_name = table_name.to_lowercase();
+
+        if let Some(name) = table_name.strip_prefix(EVENTS_PREFIX) {
+            self.events.get(name).map(Decoded::Event)
+        } else if let Some(name) = table_name.strip_prefix(CALLS_PREFIX) {
+            self.functions.get(name).map(Decoded::Call)
+        } else {
+            None
+        }
+    }
+
+    pub fn schemas(&self) -> impl Iterator<Item = Schema> + '_ {
+        let events = self.events.values().map(Decoded::Event);
+        let calls = self.functions.values().map(Decoded::Call);
+
+        events.chain(calls).map(|decoded| decoded.schema())
+    }
+}
+
+fn register<T: Clone>(
+    items: &mut HashMap<String, Registered<T>>,
+    name: &str,
+    item: &T,
+    address: Option<Address>,
+    same_signature: impl Fn(&T, &T) -> bool,
+) -> Result<()> {
+    match items.entry(name.to_lowercase()) {
+        Entry::Vacant(entry) => {
+            entry.insert(Registered {
+                item: item.clone(),
+                addresses: address.into_iter().collect(),
+            });
+        }
+        Entry::Occupied(mut entry) if same_signature(&entry.get().item, item) => {
+            entry.get_mut().merge(address);
+        }
+        Entry::Occupied(_) => {
+            return Err(EvmStorageError::ConflictingAbiItem(name.to_owned()).into());
+        }
+    }
+
+    Ok(())
+}
+
+impl Decoded<'_> {
+    pub fn table_name(&self) -> String {
+        match self {
+            Self::Event(event) => format!("{EVENTS_PREFIX}{}", event.item.name),
+            Self::Call(function) => format!("{CALLS_PREFIX}{}", function.item.name),
+        }
+    }
+
+    /// Names and types of the decoded inputs, in ABI order.
+    ///
+    /// Inputs which are unnamed or clash with the leading columns are named `arg{i}`.
+    pub fn inputs(&self) -> Vec<(String, DataType)> {
+        let (leading, inputs): (&[(&str, DataType)], Vec<_>) = match self {
+            Self::Event(event) => (
+                &EVENT_COLUMNS,
+                event
+                    .item
+                    .inputs
+                    .iter()
+                    .map(|param| (param.name.as_str(), param.resolve(), param.indexed))
+                    .collect(),
+            ),
+            Self::Call(function) => (
+                &CALL_COLUMNS,
+                function
+                    .item
+                    .inputs
+                    .iter()
+                    .map(|param| (param.name.as_str(), param.resolve(), false))
+                    .collect(),
+            ),
+        };
+
+        inputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, ty, indexed))| {
+                let name = match name {
+                    "" => format!("arg{i}"),
+                    name if leading.iter().any(|(column, _)| *column == name) => format!("arg{i}"),
+                    name => name.to_owned(),
+                };
+                let data_type = match ty {
+                    Ok(ty) if indexed && !is_value_type(&ty) => DataType::Text,
+                    Ok(ty) => sol_data_type(&ty),
+                    Err(_) => DataType::Text,
+                };
+
+                (name, data_type)
+            })
+            .collect()
+    }
+
+    pub fn schema(&self) -> Schema {
+        let leading = match self {
+            Self::Event(_) => EVENT_COLUMNS.as_slice(),
+            Self::Call(_) => CALL_COLUMNS.as_slice(),
+        };
+        let leading = leading
+            .iter()
+            .map(|(name, data_type)| ((*name).to_owned(), data_type.clone()));
+        let column_defs = leading
+            .chain(self.inputs())
+            .map(|(name, data_type)| ColumnDef {
+                name,
+                data_type,
+                nullable: true,
+                default: None,
+                comment: None,
+            })
+            .collect();
+
+        Schema {
+            table_name: self.table_name(),
+            column_defs: Some(column_defs),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        }
+    }
+}
+
+/// Types encoded in place in event topics, other indexed types are replaced by their hash.
+pub fn is_value_type(ty: &DynSolType) -> bool {
+    matches!(
+        ty,
+        DynSolType::Bool
+            | DynSolType::Int(_)
+            | DynSolType::Uint(_)
+            | DynSolType::Address
+            | DynSolType::FixedBytes(_)
+            | DynSolType::Function
+    )
+}
+
+pub fn sol_data_type(ty: &DynSolType) -> DataType {
+    match ty {
+        DynSolType::Bool => DataType::Boolean,
+        DynSolType::Int(bits) => match bits {
+            0..=8 => DataType::Int8,
+            9..=16 => DataType::Int16,
+            17..=32 => DataType::Int32,
+            33..=64 => DataType::Int,
+            _ => DataType::Int128,
+        },
+        DynSolType::Uint(bits) => match bits {
+            0..=8 => DataType::Uint8,
+            9..=16 => DataType::Uint16,
+            17..=32 => DataType::Uint32,
+            33..=64 => DataType::Uint64,
+            _ => DataType::Uint128,
+        },
+        DynSolType::Array(_) | DynSolType::FixedArray(..) | DynSolType::Tuple(_) => DataType::List,
+        _ => DataType::Text,
+    }
+}
+
+/// Maps a decoded Solidity value onto the [`Value`] of its [`sol_data_type`].
+///
+/// Integers wider than 128 bits which do not fit are kept as their decimal string.
+pub fn sol_value(value: DynSolValue) -> Value {
+    match value {
+        DynSolValue::Bool(v) => Value::Bool(v),
+        DynSolValue::Int(v, bits) => match bits {
+            0..=8 => i8::try_from(v).map(Value::I8).ok(),
+            9..=16 => i16::try_from(v).map(Value::I16).ok(),
+            17..=32 => i32::try_from(v).map(Value::I32).ok(),
+            33..=64 => i64::try_from(v).map(Value::I64).ok(),
+            _ => i128::try_from(v).map(Value::I128).ok(),
+        }
+        .unwrap_or_else(|| Value::Str(v.to_string())),
+        DynSolValue::Uint(v, bits) => match bits {
+            0..=8 => u8::try_from(v).map(Value::U8).ok(),
+            9..=16 => u16::try_from(v).map(Value::U16).ok(),
+            17..=32 => u32::try_from(v).map(Value::U32).ok(),
+            33..=64 => u64::try_from(v).map(Value::U64).ok(),
+            _ => u128::try_from(v).map(Value::U128).ok(),
+        }
+        .unwrap_or_else(|| Value::Str(v.to_string())),
+        DynSolValue::FixedBytes(word, size) => Value::Str(hex::encode_prefixed(&word[..size])),
+        DynSolValue::Address(address) => address_value(address),
+        DynSolValue::Function(function) => Value::Str(hex::encode_prefixed(function)),
+        DynSolValue::Bytes(bytes) => Value::Str(hex::encode_prefixed(bytes)),
+        DynSolValue::String(v) => Value::Str(v),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => Value::List(values.into_iter().map(sol_value).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{sol_value, AbiRegistry, Decoded},
+        crate::mock_rpc::ERC20_ABI,
+        alloy::{
+            dyn_abi::DynSolValue,
+            json_abi::JsonAbi,
+            primitives::{address, Address, I256, U256},
+        },
+        wql_core::{ast::DataType, data::Value},
+    };
+
+    #[test]
+    fn register() {
+        let abi: JsonAbi = serde_json::from_str(ERC20_ABI).unwrap();
+        let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let usdt = address!("dac17f958d2ee523a2206206994597c13d831ec7");
+
+        let mut registry = AbiRegistry::default();
+        registry.register(&abi, Some(usdc)).unwrap();
+        registry.register(&abi, Some(usdt)).unwrap();
+
+        let Some(Decoded::Event(transfer)) = registry.decoded("events.transfer") else {
+            panic!("event expected");
+        };
+        assert_eq!(transfer.addresses, vec![usdc, usdt]);
+        assert!(!transfer.accepts(&Address::ZERO));
+
+        let columns = Decoded::Event(transfer).schema().column_defs.unwrap();
+        let columns = columns
+            .into_iter()
+            .map(|column_def| (column_def.name, column_def.data_type))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns[3..],
+            [
+                ("contract".to_owned(), DataType::Text),
+                ("from".to_owned(), DataType::Text),
+                ("to".to_owned(), DataType::Text),
+                ("value".to_owned(), DataType::Uint128),
+            ]
+        );
+
+        registry.register(&abi, None).unwrap();
+        let Some(Decoded::Call(transfer)) = registry.decoded("calls.Transfer") else {
+            panic!("call expected");
+        };
+        assert!(transfer.accepts(&Address::ZERO));
+        assert!(registry.decoded("events.Approval").is_none());
+        assert!(registry.decoded("blocks").is_none());
+
+        let conflicting: JsonAbi = serde_json::from_str(
+            r#"[{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+                {"name":"from","type":"address","indexed":true}]}]"#,
+        )
+        .unwrap();
+        assert!(registry.register(&conflicting, None).is_err());
+    }
+
+    #[test]
+    fn sol_values() {
+        let value = |value| sol_value(value);
+
+        assert_eq!(value(DynSolValue::Bool(true)), Value::Bool(true));
+        assert_eq!(value(DynSolValue::Uint(U256::from(7), 8)), Value::U8(7));
+        assert_eq!(
+            value(DynSolValue::Uint(U256::from(10).pow(U256::from(18)), 256)),
+            Value::U128(1_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            value(DynSolValue::Uint(U256::MAX, 256)),
+            Value::Str(U256::MAX.to_string())
+        );
+        assert_eq!(
+            value(DynSolValue::Int(I256::try_from(-5).unwrap(), 24)),
+            Value::I32(-5)
+        );
+        assert_eq!(
+            value(DynSolValue::Address(address!(
+                "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            ))),
+            Value::Str("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_owned())
+        );
+        assert_eq!(
+            value(DynSolValue::Bytes(vec![0xde, 0xad])),
+            Value::Str("0xdead".to_owned())
+        );
+        assert_eq!(
+            value(DynSolValue::Array(vec![
+                DynSolValue::String("a".to_owned()),
+                DynSolValue::Tuple(vec![DynSolValue::Bool(false)]),
+            ])),
+            Value::List(vec![
+                Value::Str("a".to_owned()),
+                Value::List(vec![Value::Bool(false)]),
+            ])
+        );
+    }
+}
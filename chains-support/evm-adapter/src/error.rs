@@ -0,0 +1,44 @@
+use {
+    thiserror::Error as ThisError,
+    wql_core::error::{Error, Result},
+};
+
+#[derive(ThisError, Debug, PartialEq, Eq)]
+pub enum EvmStorageError {
+    #[error("invalid rpc url: {0}")]
+    InvalidRpcUrl(String),
+
+    #[error("entity not found: {0}")]
+    EntityNotFound(String),
+
+    #[error("unsupported key for {0}: {1}")]
+    UnsupportedKey(String, String),
+
+    #[error("invalid abi: {0}")]
+    InvalidAbi(String),
+
+    #[error("invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("conflicting abi item: {0}")]
+    ConflictingAbiItem(String),
+
+    #[error("{0} does not fit in UINT128: {1}")]
+    ValueOutOfRange(String, String),
+}
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<EvmStorageError> for Error {
+    fn from(e: EvmStorageError) -> Error {
+        Error::StorageMsg(e.to_string())
+    }
+}
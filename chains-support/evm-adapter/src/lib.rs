@@ -1 +1,114 @@
-//mod resolvers;
+#![deny(clippy::str_to_string)]
+
+mod abi;
+mod error;
+mod range;
+mod resolvers;
+mod store;
+
+#[cfg(test)]
+mod mock_rpc;
+
+pub use error::{EvmStorageError, ResultExt};
+
+use {
+    abi::AbiRegistry,
+    alloy::{
+        json_abi::JsonAbi,
+        providers::{ProviderBuilder, RootProvider},
+        transports::http::{Client, Http},
+    },
+    std::{fs, path::Path},
+    wql_core::{
+        error::Result,
+        store::{
+            CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, StoreMut, Transaction,
+        },
+    },
+};
+
+/// Number of blocks scanned when a query does not bound the block range on both sides.
+pub const DEFAULT_SCAN_DEPTH: u64 = 10;
+
+/// Short code under which the entities are served unless set with [`EvmStorage::with_chain`].
+pub const DEFAULT_CHAIN: &str = "eth";
+
+/// Read-only storage which serves EVM chain entities over a JSON-RPC endpoint.
+#[derive(Debug, Clone)]
+pub struct EvmStorage {
+    pub provider: RootProvider<Http<Client>>,
+    pub scan_depth: u64,
+    /// Short code of the chain, accepted as a prefix of the table names as in `eth.blocks`.
+    pub chain: String,
+    /// Contract ABIs served as the `events.<Event>` and `calls.<function>` entities.
+    pub abis: AbiRegistry,
+}
+
+impl EvmStorage {
+    pub fn new(rpc_url: &str) -> Result<Self> {
+        let url = rpc_url
+            .parse()
+            .map_err(|_| EvmStorageError::InvalidRpcUrl(rpc_url.to_owned()))?;
+        let provider = ProviderBuilder::new().on_http(url);
+
+        Ok(Self {
+            provider,
+            scan_depth: DEFAULT_SCAN_DEPTH,
+            chain: DEFAULT_CHAIN.to_owned(),
+            abis: AbiRegistry::default(),
+        })
+    }
+
+    pub fn with_scan_depth(self, scan_depth: u64) -> Self {
+        Self {
+            scan_depth: scan_depth.max(1),
+            ..self
+        }
+    }
+
+    pub fn with_chain(self, chain: &str) -> Self {
+        Self {
+            chain: chain.to_lowercase(),
+            ..self
+        }
+    }
+
+    /// Registers the JSON `abi` of the contract at `address`, or of any contract when `None`.
+    pub fn with_abi(mut self, abi: &str, address: Option<&str>) -> Result<Self> {
+        let abi: JsonAbi =
+            serde_json::from_str(abi).map_err(|e| EvmStorageError::InvalidAbi(e.to_string()))?;
+        let address = address
+            .map(|address| {
+                address
+                    .parse()
+                    .map_err(|_| EvmStorageError::InvalidAddress(address.to_owned()))
+            })
+            .transpose()?;
+
+        self.abis.register(&abi, address)?;
+
+        Ok(self)
+    }
+
+    pub fn with_abi_file<P: AsRef<Path>>(self, path: P, address: Option<&str>) -> Result<Self> {
+        let abi = fs::read_to_string(path).map_storage_err()?;
+
+        self.with_abi(&abi, address)
+    }
+
+    /// Strips the chain prefix off `table_name`, names without one are left as they are.
+    fn entity_name<'a>(&self, table_name: &'a str) -> &'a str {
+        match table_name.split_once('.') {
+            Some((chain, name)) if chain.eq_ignore_ascii_case(&self.chain) => name,
+            _ => table_name,
+        }
+    }
+}
+
+impl Metadata for EvmStorage {}
+impl StoreMut for EvmStorage {}
+impl Index for EvmStorage {}
+impl IndexMut for EvmStorage {}
+impl Transaction for EvmStorage {}
+impl CustomFunction for EvmStorage {}
+impl CustomFunctionMut for EvmStorage {}
@@ -0,0 +1,220 @@
+//! Minimal JSON-RPC server answering with canned responses, used to test the storage
+//! without a live node.
+
+use {
+    serde_json::{json, Value as JsonValue},
+    std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::Arc,
+        thread,
+    },
+};
+
+/// `Transfer` event and `transfer` function of the ERC-20 ABI.
+pub const ERC20_ABI: &str = r#"[
+    {"type":"event","name":"Transfer","anonymous":false,"inputs":[
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"function","name":"transfer","stateMutability":"nonpayable",
+     "inputs":[{"name":"to","type":"address"},{"name":"value","type":"uint256"}],
+     "outputs":[{"name":"","type":"bool"}]}
+]"#;
+
+pub type RpcResult = Result<JsonValue, (i64, String)>;
+
+/// Spawns a server on a random local port and returns its url.
+///
+/// `handler` receives the method name and params of every request.
+pub fn serve<F>(handler: F) -> String
+where
+    F: Fn(&str, &JsonValue) -> RpcResult + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { break };
+            let handler = Arc::clone(&handler);
+
+            thread::spawn(move || handle_connection(stream, handler.as_ref()));
+        }
+    });
+
+    url
+}
+
+fn handle_connection<F>(stream: TcpStream, handler: &F)
+where
+    F: Fn(&str, &JsonValue) -> RpcResult,
+{
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+
+    loop {
+        let mut content_length = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) => {}
+            }
+
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+
+        let request: JsonValue = serde_json::from_slice(&body).unwrap();
+        let response = match request {
+            JsonValue::Array(requests) => {
+                JsonValue::Array(requests.iter().map(|r| respond(r, handler)).collect())
+            }
+            request => respond(&request, handler),
+        };
+        let response = response.to_string();
+
+        let written = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        if written.is_err() {
+            return;
+        }
+    }
+}
+
+fn respond<F>(request: &JsonValue, handler: &F) -> JsonValue
+where
+    F: Fn(&str, &JsonValue) -> RpcResult,
+{
+    let id = request["id"].clone();
+    let method = request["method"].as_str().unwrap_or_default();
+
+    match handler(method, &request["params"]) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": code, "message": message },
+        }),
+    }
+}
+
+/// Canned `eth_getBlockByNumber` response for the block `number`.
+pub fn block_json(number: u64, transactions: Vec<JsonValue>) -> JsonValue {
+    json!({
+        "hash": format!("0x{:064x}", number),
+        "parentHash": format!("0x{:064x}", number.saturating_sub(1)),
+        "sha3Uncles": format!("0x{:064x}", 0),
+        "miner": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+        "stateRoot": format!("0x{:064x}", 0),
+        "transactionsRoot": format!("0x{:064x}", 0),
+        "receiptsRoot": format!("0x{:064x}", 0),
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "difficulty": "0x0",
+        "number": format!("{:#x}", number),
+        "gasLimit": "0x1c9c380",
+        "gasUsed": "0xe4e1c0",
+        "timestamp": format!("{:#x}", 1_700_000_000 + number * 12),
+        "extraData": "0x",
+        "mixHash": format!("0x{:064x}", 0),
+        "nonce": "0x0000000000000000",
+        "baseFeePerGas": "0x3b9aca00",
+        "uncles": [],
+        "transactions": transactions,
+        "size": "0x100",
+    })
+}
+
+/// Canned legacy transaction `index` of the block `number`, see [`transaction_hash`].
+pub fn transaction_json(number: u64, index: u64) -> JsonValue {
+    json!({
+        "hash": transaction_hash(number, index),
+        "nonce": format!("{:#x}", index),
+        "blockHash": format!("0x{:064x}", number),
+        "blockNumber": format!("{:#x}", number),
+        "transactionIndex": format!("{:#x}", index),
+        "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+        "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+        "value": "0xde0b6b3a7640000",
+        "gasPrice": "0x3b9aca00",
+        "gas": "0x5208",
+        "input": "0x",
+        "v": "0x25",
+        "r": "0x1",
+        "s": "0x1",
+        "type": "0x0",
+        "chainId": "0x1",
+    })
+}
+
+/// Canned successful receipt of the transaction built by [`transaction_json`].
+pub fn receipt_json(number: u64, index: u64) -> JsonValue {
+    json!({
+        "transactionHash": transaction_hash(number, index),
+        "transactionIndex": format!("{:#x}", index),
+        "blockHash": format!("0x{:064x}", number),
+        "blockNumber": format!("{:#x}", number),
+        "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+        "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+        "cumulativeGasUsed": "0x5208",
+        "gasUsed": "0x5208",
+        "effectiveGasPrice": "0x3b9aca00",
+        "contractAddress": null,
+        "logs": [],
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "status": "0x1",
+        "type": "0x0",
+    })
+}
+
+/// Canned log `log_index` of the block `number`, emitted by `address` with `topics`.
+pub fn log_json(number: u64, log_index: u64, address: &str, topics: &[String]) -> JsonValue {
+    json!({
+        "address": address,
+        "topics": topics,
+        "data": format!("0x{:064x}", log_index + 1),
+        "blockNumber": format!("{:#x}", number),
+        "blockHash": format!("0x{:064x}", number),
+        "transactionHash": transaction_hash(number, 0),
+        "transactionIndex": "0x0",
+        "logIndex": format!("{:#x}", log_index),
+        "removed": false,
+    })
+}
+
+/// Hash of the canned transaction `index` of the block `number`.
+pub fn transaction_hash(number: u64, index: u64) -> String {
+    format!("0x{:032x}{:032x}", number, index)
+}
+
+/// Extracts the block number from the first param of a block request.
+pub fn requested_block(params: &JsonValue) -> Option<u64> {
+    quantity(&params[0])
+}
+
+/// Parses a hex encoded quantity.
+pub fn quantity(value: &JsonValue) -> Option<u64> {
+    value
+        .as_str()
+        .and_then(|hex| u64::from_str_radix(hex.trim_start_matches("0x"), 16).ok())
+}
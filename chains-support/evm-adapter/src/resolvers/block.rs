@@ -0,0 +1,38 @@
+use {
+    super::{address_value, hash_value, optional_u64, timestamp_value},
+    crate::error::ResultExt,
+    alloy::{
+        eips::BlockNumberOrTag,
+        providers::{Provider, RootProvider},
+        rpc::types::{Block, BlockTransactionsKind},
+        transports::http::{Client, Http},
+    },
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+pub async fn fetch_block(
+    provider: &RootProvider<Http<Client>>,
+    number: u64,
+    kind: BlockTransactionsKind,
+) -> Result<Option<Block>> {
+    provider
+        .get_block_by_number(BlockNumberOrTag::Number(number), kind)
+        .await
+        .map_storage_err()
+}
+
+pub fn block_row(block: &Block) -> HashMap<String, Value> {
+    let header = &block.header;
+
+    HashMap::from([
+        ("number".to_owned(), Value::U64(header.number)),
+        ("hash".to_owned(), hash_value(header.hash)),
+        ("parent_hash".to_owned(), hash_value(header.parent_hash)),
+        ("timestamp".to_owned(), timestamp_value(header.timestamp)),
+        ("miner".to_owned(), address_value(header.beneficiary)),
+        ("gas_used".to_owned(), Value::U64(header.gas_used)),
+        ("gas_limit".to_owned(), Value::U64(header.gas_limit)),
+        ("base_fee".to_owned(), optional_u64(header.base_fee_per_gas)),
+    ])
+}
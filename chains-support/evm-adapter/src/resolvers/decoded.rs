@@ -0,0 +1,165 @@
+use {
+    super::{address_value, hash_value, optional_u64, LogFilter},
+    crate::abi::{is_value_type, sol_value, Decoded, Registered},
+    alloy::{
+        consensus::Transaction as _,
+        dyn_abi::{DecodedEvent, DynSolType, EventExt, JsonAbiExt, Specifier},
+        json_abi::{Event, Function},
+        primitives::B256,
+        rpc::types::{Log, Transaction},
+    },
+    std::collections::HashMap,
+    wql_core::{ast::IndexOperator, data::Value, store::Predicate},
+};
+
+/// Filter of the logs emitted by `event`, narrowed down by equalities on `contract` and on
+/// the indexed inputs stored in place in the topics. Those predicates are reported as handled.
+pub fn event_filter(event: &Registered<Event>, predicates: &[Predicate]) -> (LogFilter, Vec<bool>) {
+    let mut filter = LogFilter {
+        addresses: event.addresses.clone(),
+        ..LogFilter::default()
+    };
+    let offset = match event.item.anonymous {
+        true => 0,
+        false => {
+            filter.topics[0] = Some(event.item.selector());
+
+            1
+        }
+    };
+
+    let names = Decoded::Event(event).inputs();
+    let indexed = event
+        .item
+        .inputs
+        .iter()
+        .zip(names)
+        .filter(|(param, _)| param.indexed)
+        .map(|(param, (name, _))| (name, param.resolve().ok()))
+        .collect::<Vec<_>>();
+
+    let mut contract = None;
+    let handled = predicates
+        .iter()
+        .map(|predicate| {
+            if predicate.op != IndexOperator::Eq {
+                return false;
+            }
+
+            if predicate.column == "contract" {
+                let address = match &predicate.value {
+                    Value::Str(value) => value.parse().ok(),
+                    _ => None,
+                };
+
+                return match (contract, address) {
+                    (None, Some(address))
+                        if predicate.check(&address_value(address)) == Some(true) =>
+                    {
+                        contract = Some(address);
+                        filter.addresses = vec![address];
+
+                        true
+                    }
+                    _ => false,
+                };
+            }
+
+            let Some((i, ty)) = indexed
+                .iter()
+                .position(|(name, _)| *name == predicate.column)
+                .and_then(|i| indexed[i].1.as_ref().map(|ty| (i + offset, ty)))
+            else {
+                return false;
+            };
+
+            match (filter.topics.get(i), topic(ty, predicate)) {
+                (Some(None), Some(topic)) => {
+                    filter.topics[i] = Some(topic);
+
+                    true
+                }
+                _ => false,
+            }
+        })
+        .collect();
+
+    (filter, handled)
+}
+
+/// Topic of an indexed value type input equal to the value of `predicate`, provided the
+/// decoded input would compare equal to it as well.
+fn topic(ty: &DynSolType, predicate: &Predicate) -> Option<B256> {
+    if !is_value_type(ty) || matches!(predicate.value, Value::Null) {
+        return None;
+    }
+
+    let value = ty.coerce_str(&String::from(&predicate.value)).ok()?;
+    let topic = value.as_word()?;
+
+    (predicate.check(&sol_value(value)) == Some(true)).then_some(topic)
+}
+
+/// Decodes `log` as `event`, `None` when it was emitted by an unregistered contract or does
+/// not match the event inputs.
+pub fn event_row(event: &Registered<Event>, log: &Log) -> Option<HashMap<String, Value>> {
+    if !event.accepts(&log.address()) {
+        return None;
+    }
+
+    let DecodedEvent { indexed, body, .. } = event.item.decode_log(log.data(), true).ok()?;
+    let (mut indexed, mut body) = (indexed.into_iter(), body.into_iter());
+    let values = event.item.inputs.iter().map(|param| match param.indexed {
+        true => indexed.next(),
+        false => body.next(),
+    });
+
+    let mut row = HashMap::from([
+        ("block_number".to_owned(), optional_u64(log.block_number)),
+        (
+            "transaction_hash".to_owned(),
+            log.transaction_hash.map(hash_value).unwrap_or(Value::Null),
+        ),
+        ("log_index".to_owned(), optional_u64(log.log_index)),
+        ("contract".to_owned(), address_value(log.address())),
+    ]);
+    for ((name, _), value) in Decoded::Event(event).inputs().into_iter().zip(values) {
+        row.insert(name, value.map(sol_value).unwrap_or(Value::Null));
+    }
+
+    Some(row)
+}
+
+/// Decodes the calldata of `transaction` as a call of `function`, `None` when it calls an
+/// unregistered contract or another function.
+pub fn call_row(
+    function: &Registered<Function>,
+    transaction: &Transaction,
+) -> Option<HashMap<String, Value>> {
+    let contract = transaction.to().filter(|to| function.accepts(to))?;
+    let selector = function.item.selector();
+    let data = transaction.input().strip_prefix(selector.as_slice())?;
+    let values = function.item.abi_decode_input(data, true).ok()?;
+
+    let mut row = HashMap::from([
+        (
+            "block_number".to_owned(),
+            optional_u64(transaction.block_number),
+        ),
+        (
+            "transaction_hash".to_owned(),
+            hash_value(*transaction.inner.tx_hash()),
+        ),
+        (
+            "transaction_index".to_owned(),
+            optional_u64(transaction.transaction_index),
+        ),
+        ("caller".to_owned(), address_value(transaction.from)),
+        ("contract".to_owned(), address_value(contract)),
+    ]);
+    for ((name, _), value) in Decoded::Call(function).inputs().into_iter().zip(values) {
+        row.insert(name, sol_value(value));
+    }
+
+    Some(row)
+}
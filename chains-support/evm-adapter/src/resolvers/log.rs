@@ -0,0 +1,232 @@
+use {
+    super::{address_value, bytes_value, hash_value, optional_u64},
+    crate::error::ResultExt,
+    alloy::{
+        primitives::{Address, B256},
+        providers::{Provider, RootProvider},
+        rpc::types::{Filter, Log},
+        transports::{
+            http::{Client, Http},
+            RpcError, TransportErrorKind,
+        },
+    },
+    std::{collections::HashMap, ops::RangeInclusive},
+    wql_core::{ast::IndexOperator, data::Value, error::Result, store::Predicate},
+};
+
+/// Block span of a single `eth_getLogs` request, before any split on rejection.
+pub const LOG_CHUNK_SIZE: u64 = 2_000;
+
+const TOPIC_COLUMNS: [&str; 4] = ["topic0", "topic1", "topic2", "topic3"];
+
+/// Nodes report too wide `eth_getLogs` requests in their own words, e.g.
+/// "query returned more than 10000 results" or "exceed maximum block range: 5000".
+///
+/// Kept specific to the request size, as rate limiting errors ("rate limit exceeded",
+/// "too many requests", even code -32005 on some nodes) are not helped by splitting.
+const SIZE_REJECTIONS: [&str; 5] = [
+    "query returned more than",
+    "block range",
+    "response size exceeded",
+    "is limited to a",
+    "requested too many blocks",
+];
+
+/// Address and topics of the `eth_getLogs` filter object, taken from pushed down predicates.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Empty when logs of any contract are requested.
+    pub addresses: Vec<Address>,
+    pub topics: [Option<B256>; 4],
+}
+
+impl LogFilter {
+    /// Takes the first equality on `address` and on each of `topic0..topic3`, and reports
+    /// those predicates as handled. Other predicates are left to the executor.
+    pub fn from_predicates(predicates: &[Predicate]) -> (Self, Vec<bool>) {
+        let mut filter = Self::default();
+        let handled = predicates
+            .iter()
+            .map(|predicate| {
+                let value = match (&predicate.op, &predicate.value) {
+                    (IndexOperator::Eq, Value::Str(value)) => value,
+                    _ => return false,
+                };
+
+                if predicate.column == "address" {
+                    return match (filter.addresses.is_empty(), value.parse()) {
+                        (true, Ok(address))
+                            if predicate.check(&address_value(address)) == Some(true) =>
+                        {
+                            filter.addresses = vec![address];
+
+                            true
+                        }
+                        _ => false,
+                    };
+                }
+
+                let Some(i) = TOPIC_COLUMNS.iter().position(|c| *c == predicate.column) else {
+                    return false;
+                };
+
+                match (filter.topics[i], value.parse()) {
+                    (None, Ok(topic)) if predicate.check(&hash_value(topic)) == Some(true) => {
+                        filter.topics[i] = Some(topic);
+
+                        true
+                    }
+                    _ => false,
+                }
+            })
+            .collect();
+
+        (filter, handled)
+    }
+
+    fn to_filter(&self, blocks: RangeInclusive<u64>) -> Filter {
+        let mut filter = Filter::new().select(blocks);
+        if !self.addresses.is_empty() {
+            filter = filter.address(self.addresses.clone());
+        }
+
+        for (i, topic) in self.topics.iter().enumerate() {
+            if let Some(topic) = topic {
+                filter.topics[i] = (*topic).into();
+            }
+        }
+
+        filter
+    }
+}
+
+/// Fetches the logs of `blocks`, halving the range whenever the node rejects it for its size.
+pub async fn fetch_logs(
+    provider: &RootProvider<Http<Client>>,
+    filter: &LogFilter,
+    blocks: RangeInclusive<u64>,
+) -> Result<Vec<Log>> {
+    let (start, end) = (*blocks.start(), *blocks.end());
+
+    match provider.get_logs(&filter.to_filter(blocks)).await {
+        Ok(logs) => Ok(logs),
+        Err(error) if start < end && is_rejected_for_size(&error) => {
+            let middle = start + (end - start) / 2;
+
+            let mut logs = Box::pin(fetch_logs(provider, filter, start..=middle)).await?;
+            logs.extend(Box::pin(fetch_logs(provider, filter, middle + 1..=end)).await?);
+
+            Ok(logs)
+        }
+        Err(error) => Err(error).map_storage_err(),
+    }
+}
+
+fn is_rejected_for_size(error: &RpcError<TransportErrorKind>) -> bool {
+    let Some(payload) = error.as_error_resp() else {
+        return false;
+    };
+
+    let message = payload.message.to_lowercase();
+
+    SIZE_REJECTIONS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+pub fn log_row(log: &Log) -> HashMap<String, Value> {
+    let topics = log.topics();
+    let topic = |i: usize| {
+        topics
+            .get(i)
+            .copied()
+            .map(hash_value)
+            .unwrap_or(Value::Null)
+    };
+
+    HashMap::from([
+        ("block_number".to_owned(), optional_u64(log.block_number)),
+        (
+            "block_hash".to_owned(),
+            log.block_hash.map(hash_value).unwrap_or(Value::Null),
+        ),
+        (
+            "transaction_hash".to_owned(),
+            log.transaction_hash.map(hash_value).unwrap_or(Value::Null),
+        ),
+        (
+            "transaction_index".to_owned(),
+            optional_u64(log.transaction_index),
+        ),
+        ("log_index".to_owned(), optional_u64(log.log_index)),
+        ("address".to_owned(), address_value(log.address())),
+        ("topic0".to_owned(), topic(0)),
+        ("topic1".to_owned(), topic(1)),
+        ("topic2".to_owned(), topic(2)),
+        ("topic3".to_owned(), topic(3)),
+        ("data".to_owned(), bytes_value(&log.data().data)),
+        ("removed".to_owned(), Value::Bool(log.removed)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{is_rejected_for_size, LogFilter},
+        alloy::{primitives::Address, transports::RpcError},
+        serde_json::json,
+        wql_core::{ast::IndexOperator, data::Value, store::Predicate},
+    };
+
+    const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    fn predicate(column: &str, value: &str) -> Predicate {
+        Predicate {
+            column: column.to_owned(),
+            op: IndexOperator::Eq,
+            value: Value::Str(value.to_owned()),
+        }
+    }
+
+    #[test]
+    fn from_predicates() {
+        let transfer = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        let predicates = [
+            predicate("address", TOKEN),
+            predicate("topic0", transfer),
+            predicate("topic1", &transfer.to_uppercase().replace("0X", "0x")),
+        ];
+        let (filter, handled) = LogFilter::from_predicates(&predicates);
+        assert_eq!(filter.addresses, vec![TOKEN.parse::<Address>().unwrap()]);
+        assert_eq!(filter.topics[0], Some(transfer.parse().unwrap()));
+        assert_eq!(filter.topics[1], None);
+        assert_eq!(handled, vec![true, true, false]);
+
+        let checksummed = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+        let (filter, handled) = LogFilter::from_predicates(&[predicate("address", checksummed)]);
+        assert_eq!(filter, LogFilter::default());
+        assert_eq!(handled, vec![false]);
+    }
+
+    #[test]
+    fn rejected_for_size() {
+        let rejected = |code, message: &str| {
+            let payload = json!({ "code": code, "message": message });
+
+            is_rejected_for_size(&RpcError::ErrorResp(
+                serde_json::from_value(payload).unwrap(),
+            ))
+        };
+
+        assert!(rejected(-32005, "query returned more than 10000 results"));
+        assert!(rejected(-32600, "exceed maximum block range: 5000"));
+        assert!(rejected(-32000, "Log response size exceeded."));
+        assert!(rejected(-32602, "eth_getLogs is limited to a 10,000 range"));
+        assert!(!rejected(
+            -32005,
+            "daily request count exceeded, request rate limited"
+        ));
+        assert!(!rejected(429, "rate limit exceeded"));
+        assert!(!rejected(-32000, "Too Many Requests"));
+    }
+}
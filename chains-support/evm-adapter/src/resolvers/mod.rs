@@ -0,0 +1,193 @@
+mod block;
+mod decoded;
+mod log;
+mod receipt;
+mod transaction;
+
+pub use {
+    block::{block_row, fetch_block},
+    decoded::{call_row, event_filter, event_row},
+    log::{fetch_logs, log_row, LogFilter, LOG_CHUNK_SIZE},
+    receipt::{fetch_receipt, receipt_row},
+    transaction::{fetch_transaction, transaction_row},
+};
+
+use {
+    crate::error::EvmStorageError,
+    alloy::primitives::{Address, Bytes, B256, U256},
+    wql_core::{
+        ast::{ColumnDef, DataType},
+        chrono::DateTime,
+        data::Value,
+        error::Result,
+    },
+};
+
+/// Chain entities served by [`crate::EvmStorage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entity {
+    Blocks,
+    Transactions,
+    Receipts,
+    Logs,
+}
+
+impl Entity {
+    pub const ALL: [Entity; 4] = [
+        Entity::Blocks,
+        Entity::Transactions,
+        Entity::Receipts,
+        Entity::Logs,
+    ];
+
+    pub fn from_table_name(table_name: &str) -> Option<Self> {
+        match table_name.to_lowercase().as_str() {
+            "blocks" => Some(Self::Blocks),
+            "transactions" => Some(Self::Transactions),
+            "receipts" => Some(Self::Receipts),
+            "logs" => Some(Self::Logs),
+            _ => None,
+        }
+    }
+
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Transactions => "transactions",
+            Self::Receipts => "receipts",
+            Self::Logs => "logs",
+        }
+    }
+
+    /// Column holding the number of the block a row belongs to.
+    pub fn block_column(&self) -> &'static str {
+        match self {
+            Self::Blocks => "number",
+            Self::Transactions | Self::Receipts | Self::Logs => "block_number",
+        }
+    }
+
+    /// Columns of the rows produced by the resolver of the entity, in `SELECT *` order.
+    pub fn column_defs(&self) -> Vec<ColumnDef> {
+        use DataType::*;
+
+        let columns: &[(&str, DataType, bool)] = match self {
+            Self::Blocks => &[
+                ("number", Uint64, false),
+                ("hash", Text, false),
+                ("parent_hash", Text, false),
+                ("timestamp", Timestamp, false),
+                ("miner", Text, false),
+                ("gas_used", Uint64, false),
+                ("gas_limit", Uint64, false),
+                ("base_fee", Uint64, true),
+            ],
+            Self::Transactions => &[
+                ("hash", Text, false),
+                ("block_number", Uint64, true),
+                ("transaction_index", Uint64, true),
+                ("from", Text, false),
+                ("to", Text, true),
+                // A `U256`, rejected when beyond `u128` as by `u256_value`. Gas amounts and
+                // prices are `u128` in the RPC responses.
+                ("value", Uint128, false),
+                ("input", Text, false),
+                ("nonce", Uint64, false),
+                ("gas", Uint64, false),
+                ("gas_price", Uint128, false),
+            ],
+            Self::Receipts => &[
+                ("transaction_hash", Text, false),
+                ("block_number", Uint64, true),
+                ("transaction_index", Uint64, true),
+                ("from", Text, false),
+                ("to", Text, true),
+                ("status", Boolean, false),
+                ("gas_used", Uint128, false),
+                ("effective_gas_price", Uint128, false),
+                ("contract_address", Text, true),
+            ],
+            Self::Logs => &[
+                ("block_number", Uint64, true),
+                ("block_hash", Text, true),
+                ("transaction_hash", Text, true),
+                ("transaction_index", Uint64, true),
+                ("log_index", Uint64, true),
+                ("address", Text, false),
+                ("topic0", Text, true),
+                ("topic1", Text, true),
+                ("topic2", Text, true),
+                ("topic3", Text, true),
+                ("data", Text, false),
+                ("removed", Boolean, false),
+            ],
+        };
+
+        columns
+            .iter()
+            .map(|(name, data_type, nullable)| ColumnDef {
+                name: (*name).to_owned(),
+                data_type: data_type.clone(),
+                nullable: *nullable,
+                default: None,
+                comment: None,
+            })
+            .collect()
+    }
+}
+
+pub fn hash_value(hash: B256) -> Value {
+    Value::Str(format!("{hash:#x}"))
+}
+
+pub fn address_value(address: Address) -> Value {
+    Value::Str(format!("{address:#x}"))
+}
+
+pub fn optional_address_value(address: Option<Address>) -> Value {
+    address.map(address_value).unwrap_or(Value::Null)
+}
+
+pub fn bytes_value(bytes: &Bytes) -> Value {
+    Value::Str(bytes.to_string())
+}
+
+/// Value of the `UINT128` amount `column`. No amount of ether comes close to `u128::MAX` wei, so
+/// a larger one is an error rather than a value of another type than the column's.
+pub fn u256_value(column: &str, value: U256) -> Result<Value> {
+    u128::try_from(value)
+        .map(Value::U128)
+        .map_err(|_| EvmStorageError::ValueOutOfRange(column.to_owned(), value.to_string()).into())
+}
+
+pub fn timestamp_value(timestamp: u64) -> Value {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|datetime| Value::Timestamp(datetime.naive_utc()))
+        .unwrap_or(Value::Null)
+}
+
+pub fn optional_u64(value: Option<u64>) -> Value {
+    value.map(Value::U64).unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::u256_value, crate::error::EvmStorageError, alloy::primitives::U256,
+        wql_core::data::Value,
+    };
+
+    #[test]
+    fn u256() {
+        assert_eq!(
+            u256_value("value", U256::from(u128::MAX)),
+            Ok(Value::U128(u128::MAX))
+        );
+        assert_eq!(
+            u256_value("value", U256::MAX),
+            Err(EvmStorageError::ValueOutOfRange("value".to_owned(), U256::MAX.to_string()).into())
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use {
+    super::{address_value, hash_value, optional_address_value, optional_u64},
+    crate::error::ResultExt,
+    alloy::{
+        primitives::B256,
+        providers::{Provider, RootProvider},
+        rpc::types::TransactionReceipt,
+        transports::http::{Client, Http},
+    },
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+pub async fn fetch_receipt(
+    provider: &RootProvider<Http<Client>>,
+    hash: B256,
+) -> Result<Option<TransactionReceipt>> {
+    provider
+        .get_transaction_receipt(hash)
+        .await
+        .map_storage_err()
+}
+
+pub fn receipt_row(receipt: &TransactionReceipt) -> HashMap<String, Value> {
+    HashMap::from([
+        (
+            "transaction_hash".to_owned(),
+            hash_value(receipt.transaction_hash),
+        ),
+        (
+            "block_number".to_owned(),
+            optional_u64(receipt.block_number),
+        ),
+        (
+            "transaction_index".to_owned(),
+            optional_u64(receipt.transaction_index),
+        ),
+        ("from".to_owned(), address_value(receipt.from)),
+        ("to".to_owned(), optional_address_value(receipt.to)),
+        ("status".to_owned(), Value::Bool(receipt.status())),
+        ("gas_used".to_owned(), Value::U128(receipt.gas_used)),
+        (
+            "effective_gas_price".to_owned(),
+            Value::U128(receipt.effective_gas_price),
+        ),
+        (
+            "contract_address".to_owned(),
+            optional_address_value(receipt.contract_address),
+        ),
+    ])
+}
@@ -0,0 +1,54 @@
+use {
+    super::{
+        address_value, bytes_value, hash_value, optional_address_value, optional_u64, u256_value,
+    },
+    crate::error::ResultExt,
+    alloy::{
+        consensus::Transaction as _,
+        primitives::B256,
+        providers::{Provider, RootProvider},
+        rpc::types::Transaction,
+        transports::http::{Client, Http},
+    },
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+pub async fn fetch_transaction(
+    provider: &RootProvider<Http<Client>>,
+    hash: B256,
+) -> Result<Option<Transaction>> {
+    provider
+        .get_transaction_by_hash(hash)
+        .await
+        .map_storage_err()
+}
+
+pub fn transaction_row(transaction: &Transaction) -> Result<HashMap<String, Value>> {
+    let gas_price = transaction
+        .effective_gas_price
+        .or_else(|| transaction.gas_price())
+        .unwrap_or_else(|| transaction.max_fee_per_gas());
+
+    Ok(HashMap::from([
+        ("hash".to_owned(), hash_value(*transaction.inner.tx_hash())),
+        (
+            "block_number".to_owned(),
+            optional_u64(transaction.block_number),
+        ),
+        (
+            "transaction_index".to_owned(),
+            optional_u64(transaction.transaction_index),
+        ),
+        ("from".to_owned(), address_value(transaction.from)),
+        ("to".to_owned(), optional_address_value(transaction.to())),
+        (
+            "value".to_owned(),
+            u256_value("value", transaction.value())?,
+        ),
+        ("input".to_owned(), bytes_value(transaction.input())),
+        ("nonce".to_owned(), Value::U64(transaction.nonce())),
+        ("gas".to_owned(), Value::U64(transaction.gas_limit())),
+        ("gas_price".to_owned(), Value::U128(gas_price)),
+    ]))
+}
@@ -0,0 +1,826 @@
+use {
+    crate::{
+        abi::Decoded,
+        error::{EvmStorageError, ResultExt},
+        range::BlockRange,
+        resolvers::{
+            block_row, call_row, event_filter, event_row, fetch_block, fetch_logs, fetch_receipt,
+            fetch_transaction, log_row, receipt_row, transaction_row, Entity, LogFilter,
+            LOG_CHUNK_SIZE,
+        },
+        EvmStorage,
+    },
+    alloy::{
+        primitives::B256,
+        providers::Provider,
+        rpc::types::{BlockTransactionsKind, Log, Transaction},
+    },
+    async_trait::async_trait,
+    futures::{
+        stream::{self, StreamExt, TryStreamExt},
+        Stream,
+    },
+    std::ops::RangeInclusive,
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, Predicate, RowIter, Store},
+    },
+};
+
+impl EvmStorage {
+    fn entity(table_name: &str) -> Result<Entity> {
+        Entity::from_table_name(table_name)
+            .ok_or_else(|| EvmStorageError::EntityNotFound(table_name.to_owned()).into())
+    }
+
+    fn entity_schema(entity: Entity) -> Schema {
+        Schema {
+            table_name: entity.table_name().to_owned(),
+            column_defs: Some(entity.column_defs()),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        }
+    }
+
+    fn chain_schema(&self, schema: Schema) -> Schema {
+        Schema {
+            chain_name: Some(self.chain.clone()),
+            ..schema
+        }
+    }
+
+    /// Block numbers to scan, narrowed down by the predicates on the block `column`.
+    async fn block_range(
+        &self,
+        column: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RangeInclusive<u64>, Vec<bool>)> {
+        let (range, handled) = BlockRange::from_predicates(column, predicates);
+        let latest = self.provider.get_block_number().await.map_storage_err()?;
+
+        Ok((range.resolve(latest, self.scan_depth), handled))
+    }
+
+    fn block_number(table_name: &str, key: &Key) -> Result<u64> {
+        match key {
+            Key::U64(number) => Ok(*number),
+            Key::I64(number) if *number >= 0 => Ok(*number as u64),
+            Key::U32(number) => Ok(*number as u64),
+            Key::I32(number) if *number >= 0 => Ok(*number as u64),
+            _ => Err(
+                EvmStorageError::UnsupportedKey(table_name.to_owned(), format!("{key:?}")).into(),
+            ),
+        }
+    }
+
+    fn transaction_hash(table_name: &str, key: &Key) -> Result<B256> {
+        match key {
+            Key::Str(hash) => hash.parse().map_err(|_| {
+                EvmStorageError::UnsupportedKey(table_name.to_owned(), format!("{key:?}")).into()
+            }),
+            _ => Err(
+                EvmStorageError::UnsupportedKey(table_name.to_owned(), format!("{key:?}")).into(),
+            ),
+        }
+    }
+
+    /// Transactions of the blocks in `range`, in block order.
+    fn transactions(
+        &self,
+        range: RangeInclusive<u64>,
+    ) -> impl Stream<Item = Result<Transaction>> + '_ {
+        stream::iter(range)
+            .then(move |number| async move {
+                fetch_block(&self.provider, number, BlockTransactionsKind::Full).await
+            })
+            .try_filter_map(|block| async move { Ok(block) })
+            .map_ok(|block| stream::iter(block.transactions.into_transactions().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Logs of the blocks in `range` matching `filter`, requested by chunks of
+    /// [`LOG_CHUNK_SIZE`] blocks.
+    fn logs(
+        &self,
+        filter: LogFilter,
+        range: RangeInclusive<u64>,
+    ) -> impl Stream<Item = Result<Log>> + '_ {
+        let (start, end) = (*range.start(), *range.end());
+        let chunks = (start..=end)
+            .step_by(LOG_CHUNK_SIZE as usize)
+            .map(move |from| from..=end.min(from + LOG_CHUNK_SIZE - 1));
+
+        stream::iter(chunks)
+            .then(move |blocks| {
+                let filter = filter.clone();
+
+                async move { fetch_logs(&self.provider, &filter, blocks).await }
+            })
+            .map_ok(|logs| stream::iter(logs.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    fn log_key(log: &Log) -> Key {
+        Key::Str(format!(
+            "{}:{}",
+            log.block_number.unwrap_or_default(),
+            log.log_index.unwrap_or_default()
+        ))
+    }
+
+    fn transaction_key(transaction: &Transaction) -> Key {
+        Key::Str(format!("{:#x}", transaction.inner.tx_hash()))
+    }
+
+    async fn scan_decoded(
+        &self,
+        decoded: Decoded<'_>,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let (range, mut handled) = self.block_range("block_number", predicates).await?;
+
+        let rows: RowIter = match decoded {
+            Decoded::Event(event) => {
+                let event = event.clone();
+                let (filter, filter_handled) = event_filter(&event, predicates);
+                merge_handled(&mut handled, filter_handled);
+
+                let rows = self.logs(filter, range).try_filter_map(move |log| {
+                    let row =
+                        event_row(&event, &log).map(|row| (Self::log_key(&log), DataRow::Map(row)));
+
+                    async move { Ok(row) }
+                });
+
+                Box::pin(rows)
+            }
+            Decoded::Call(function) => {
+                let function = function.clone();
+                let rows = self.transactions(range).try_filter_map(move |transaction| {
+                    let row = call_row(&function, &transaction)
+                        .map(|row| (Self::transaction_key(&transaction), DataRow::Map(row)));
+
+                    async move { Ok(row) }
+                });
+
+                Box::pin(rows)
+            }
+        };
+
+        Ok((rows, handled))
+    }
+}
+
+fn merge_handled(handled: &mut [bool], other: Vec<bool>) {
+    handled
+        .iter_mut()
+        .zip(other)
+        .for_each(|(handled, other)| *handled |= other);
+}
+
+#[async_trait(?Send)]
+impl Store for EvmStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let table_name = self.entity_name(table_name);
+        let schema = match self.abis.decoded(table_name) {
+            Some(decoded) => Some(decoded.schema()),
+            None => Entity::from_table_name(table_name).map(Self::entity_schema),
+        };
+
+        Ok(schema.map(|schema| self.chain_schema(schema)))
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let schemas = Entity::ALL
+            .into_iter()
+            .map(Self::entity_schema)
+            .chain(self.abis.schemas())
+            .map(|schema| self.chain_schema(schema))
+            .collect();
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let table_name = self.entity_name(table_name);
+        if self.abis.decoded(table_name).is_some() {
+            return Err(
+                EvmStorageError::UnsupportedKey(table_name.to_owned(), format!("{key:?}")).into(),
+            );
+        }
+
+        match Self::entity(table_name)? {
+            Entity::Blocks => {
+                let number = Self::block_number(table_name, key)?;
+                let block = fetch_block(&self.provider, number, BlockTransactionsKind::Hashes)
+                    .await?
+                    .map(|block| DataRow::Map(block_row(&block)));
+
+                Ok(block)
+            }
+            Entity::Transactions => {
+                let hash = Self::transaction_hash(table_name, key)?;
+                let transaction = fetch_transaction(&self.provider, hash)
+                    .await?
+                    .map(|transaction| transaction_row(&transaction).map(DataRow::Map))
+                    .transpose()?;
+
+                Ok(transaction)
+            }
+            Entity::Receipts => {
+                let hash = Self::transaction_hash(table_name, key)?;
+                let receipt = fetch_receipt(&self.provider, hash)
+                    .await?
+                    .map(|receipt| DataRow::Map(receipt_row(&receipt)));
+
+                Ok(receipt)
+            }
+            Entity::Logs => Err(EvmStorageError::UnsupportedKey(
+                table_name.to_owned(),
+                format!("{key:?}"),
+            )
+            .into()),
+        }
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        self.scan_data_with_predicates(table_name, &[])
+            .await
+            .map(|(rows, _)| rows)
+    }
+
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let table_name = self.entity_name(table_name);
+        if let Some(decoded) = self.abis.decoded(table_name) {
+            return self.scan_decoded(decoded, predicates).await;
+        }
+
+        let entity = Self::entity(table_name)?;
+        let (range, mut handled) = self.block_range(entity.block_column(), predicates).await?;
+
+        let rows: RowIter = match entity {
+            Entity::Blocks => {
+                let rows = stream::iter(range)
+                    .then(move |number| async move {
+                        fetch_block(&self.provider, number, BlockTransactionsKind::Hashes).await
+                    })
+                    .try_filter_map(|block| async move {
+                        Ok(block.map(|block| {
+                            let key = Key::U64(block.header.number);
+
+                            (key, DataRow::Map(block_row(&block)))
+                        }))
+                    });
+
+                Box::pin(rows)
+            }
+            Entity::Transactions => {
+                let rows = self.transactions(range).and_then(|transaction| async move {
+                    let row = transaction_row(&transaction)?;
+
+                    Ok((Self::transaction_key(&transaction), DataRow::Map(row)))
+                });
+
+                Box::pin(rows)
+            }
+            Entity::Receipts => {
+                let rows = stream::iter(range)
+                    .then(move |number| async move {
+                        fetch_block(&self.provider, number, BlockTransactionsKind::Hashes).await
+                    })
+                    .try_filter_map(|block| async move { Ok(block) })
+                    .map_ok(move |block| {
+                        let hashes = block.transactions.hashes().collect::<Vec<_>>();
+
+                        stream::iter(hashes)
+                            .then(move |hash| async move {
+                                fetch_receipt(&self.provider, hash).await
+                            })
+                            .try_filter_map(|receipt| async move {
+                                Ok(receipt.map(|receipt| {
+                                    let key =
+                                        Key::Str(format!("{:#x}", receipt.transaction_hash));
+
+                                    (key, DataRow::Map(receipt_row(&receipt)))
+                                }))
+                            })
+                    })
+                    .try_flatten();
+
+                Box::pin(rows)
+            }
+            Entity::Logs => {
+                let (filter, filter_handled) = LogFilter::from_predicates(predicates);
+                merge_handled(&mut handled, filter_handled);
+
+                let rows = self
+                    .logs(filter, range)
+                    .map_ok(|log| (Self::log_key(&log), DataRow::Map(log_row(&log))));
+
+                Box::pin(rows)
+            }
+        };
+
+        Ok((rows, handled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            mock_rpc::{
+                block_json, log_json, quantity, receipt_json, requested_block, serve,
+                transaction_hash, transaction_json, RpcResult, ERC20_ABI,
+            },
+            EvmStorage,
+        },
+        futures::TryStreamExt,
+        serde_json::json,
+        std::sync::{Arc, Mutex},
+        wql_core::{
+            data::{Key, Value},
+            executor::Payload,
+            prelude::Worm,
+            store::{DataRow, Store},
+        },
+    };
+
+    const TRANSACTIONS_PER_BLOCK: u64 = 2;
+
+    fn requested_transaction(params: &serde_json::Value) -> Option<(u64, u64)> {
+        let hash = params[0].as_str()?.trim_start_matches("0x");
+        let number = u64::from_str_radix(&hash[..32], 16).ok()?;
+        let index = u64::from_str_radix(&hash[32..], 16).ok()?;
+
+        (number <= 100 && index < TRANSACTIONS_PER_BLOCK).then_some((number, index))
+    }
+
+    fn rpc(method: &str, params: &serde_json::Value) -> RpcResult {
+        match method {
+            "eth_blockNumber" => Ok(json!("0x64")),
+            "eth_getBlockByNumber" => match requested_block(params) {
+                Some(number) if number <= 100 => {
+                    let transactions = (0..TRANSACTIONS_PER_BLOCK)
+                        .map(|index| match params[1].as_bool() {
+                            Some(true) => transaction_json(number, index),
+                            _ => json!(transaction_hash(number, index)),
+                        })
+                        .collect();
+
+                    Ok(block_json(number, transactions))
+                }
+                _ => Ok(json!(null)),
+            },
+            "eth_getTransactionByHash" => Ok(requested_transaction(params)
+                .map(|(number, index)| transaction_json(number, index))
+                .unwrap_or(json!(null))),
+            "eth_getTransactionReceipt" => Ok(requested_transaction(params)
+                .map(|(number, index)| receipt_json(number, index))
+                .unwrap_or(json!(null))),
+            _ => Err((-32601, format!("method not found: {method}"))),
+        }
+    }
+
+    fn storage() -> EvmStorage {
+        EvmStorage::new(&serve(rpc)).unwrap().with_scan_depth(3)
+    }
+
+    #[tokio::test]
+    async fn scan_blocks() {
+        let storage = storage();
+
+        let rows = storage
+            .scan_data("blocks")
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let keys = rows.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        assert_eq!(keys, vec![Key::U64(98), Key::U64(99), Key::U64(100)]);
+
+        let DataRow::Map(row) = &rows[2].1 else {
+            panic!("map row expected");
+        };
+        assert_eq!(row.get("number"), Some(&Value::U64(100)));
+        assert_eq!(
+            row.get("hash"),
+            Some(&Value::Str(format!("0x{:064x}", 100)))
+        );
+        assert_eq!(
+            row.get("parent_hash"),
+            Some(&Value::Str(format!("0x{:064x}", 99)))
+        );
+        assert_eq!(
+            row.get("miner"),
+            Some(&Value::Str(
+                "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5".to_owned()
+            ))
+        );
+        assert_eq!(row.get("gas_used"), Some(&Value::U64(15_000_000)));
+        assert_eq!(row.get("gas_limit"), Some(&Value::U64(30_000_000)));
+        assert_eq!(row.get("base_fee"), Some(&Value::U64(1_000_000_000)));
+        assert!(matches!(row.get("timestamp"), Some(Value::Timestamp(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_block() {
+        let storage = storage();
+
+        let row = storage.fetch_data("blocks", &Key::I64(42)).await.unwrap();
+        assert!(matches!(
+            row,
+            Some(DataRow::Map(values)) if values.get("number") == Some(&Value::U64(42))
+        ));
+
+        let row = storage.fetch_data("blocks", &Key::I64(500)).await.unwrap();
+        assert_eq!(row, None);
+
+        assert!(storage
+            .fetch_data("blocks", &Key::Str("latest".to_owned()))
+            .await
+            .is_err());
+        assert!(storage.scan_data("uncles").await.is_err());
+        assert_eq!(storage.fetch_schema("uncles").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn select_blocks() {
+        let mut worm = Worm::new(storage());
+
+        let payloads = worm
+            .execute("SELECT number, miner FROM eth.blocks WHERE number > 98")
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec!["number".to_owned(), "miner".to_owned()],
+            rows: [99, 100]
+                .into_iter()
+                .map(|number| {
+                    vec![
+                        Value::U64(number),
+                        Value::Str("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5".to_owned()),
+                    ]
+                })
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let payloads = worm.execute("SELECT * FROM eth.blocks").await.unwrap();
+        let Payload::Select { labels, rows } = &payloads[0] else {
+            panic!("select payload expected");
+        };
+        assert_eq!(labels[..2], ["number".to_owned(), "hash".to_owned()]);
+        assert_eq!(labels.len(), 8);
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn scan_transactions() {
+        let storage = storage();
+
+        let rows = storage
+            .scan_data("transactions")
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let keys = rows.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        let expected = [(98, 0), (98, 1), (99, 0), (99, 1), (100, 0), (100, 1)]
+            .into_iter()
+            .map(|(number, index)| Key::Str(transaction_hash(number, index)))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, expected);
+
+        let DataRow::Map(row) = &rows[5].1 else {
+            panic!("map row expected");
+        };
+        assert_eq!(row.get("block_number"), Some(&Value::U64(100)));
+        assert_eq!(row.get("transaction_index"), Some(&Value::U64(1)));
+        assert_eq!(
+            row.get("to"),
+            Some(&Value::Str(
+                "0x388c818ca8b9251b393131c08a736a67ccb19297".to_owned()
+            ))
+        );
+        assert_eq!(
+            row.get("value"),
+            Some(&Value::U128(1_000_000_000_000_000_000))
+        );
+        assert_eq!(row.get("input"), Some(&Value::Str("0x".to_owned())));
+        assert_eq!(row.get("nonce"), Some(&Value::U64(1)));
+        assert_eq!(row.get("gas"), Some(&Value::U64(21_000)));
+        assert_eq!(row.get("gas_price"), Some(&Value::U128(1_000_000_000)));
+    }
+
+    #[tokio::test]
+    async fn fetch_transaction_and_receipt() {
+        let storage = storage();
+        let hash = Key::Str(transaction_hash(42, 1));
+
+        let row = storage.fetch_data("transactions", &hash).await.unwrap();
+        assert!(matches!(
+            row,
+            Some(DataRow::Map(values)) if values.get("block_number") == Some(&Value::U64(42))
+        ));
+
+        let row = storage.fetch_data("receipts", &hash).await.unwrap();
+        let Some(DataRow::Map(row)) = row else {
+            panic!("map row expected");
+        };
+        assert_eq!(
+            row.get("transaction_hash"),
+            Some(&Value::Str(transaction_hash(42, 1)))
+        );
+        assert_eq!(row.get("status"), Some(&Value::Bool(true)));
+        assert_eq!(row.get("gas_used"), Some(&Value::U128(21_000)));
+        assert_eq!(
+            row.get("effective_gas_price"),
+            Some(&Value::U128(1_000_000_000))
+        );
+        assert_eq!(row.get("contract_address"), Some(&Value::Null));
+
+        let missing = Key::Str(transaction_hash(500, 0));
+        assert_eq!(
+            storage.fetch_data("receipts", &missing).await.unwrap(),
+            None
+        );
+        assert!(storage
+            .fetch_data("transactions", &Key::Str("0x12".to_owned()))
+            .await
+            .is_err());
+        assert!(storage.fetch_data("receipts", &Key::I64(1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn select_receipts() {
+        let mut worm = Worm::new(storage());
+
+        let payloads = worm
+            .execute("SELECT transaction_hash, status FROM eth.receipts WHERE block_number = 100")
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec!["transaction_hash".to_owned(), "status".to_owned()],
+            rows: (0..TRANSACTIONS_PER_BLOCK)
+                .map(|index| vec![Value::Str(transaction_hash(100, index)), Value::Bool(true)])
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let payloads = worm
+            .execute("SELECT * FROM eth.transactions")
+            .await
+            .unwrap();
+        let Payload::Select { labels, rows } = &payloads[0] else {
+            panic!("select payload expected");
+        };
+        assert_eq!(labels.len(), 10);
+        assert_eq!(rows.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn select_block_range() {
+        let url = serve(|method, params| match requested_block(params) {
+            Some(number) if method == "eth_getBlockByNumber" && !(40..=42).contains(&number) => {
+                Err((
+                    -32000,
+                    format!("block {number} is outside of the pushed down range"),
+                ))
+            }
+            _ => rpc(method, params),
+        });
+        let mut worm = Worm::new(EvmStorage::new(&url).unwrap().with_scan_depth(3));
+
+        let payloads = worm
+            .execute("SELECT number FROM eth.blocks WHERE number BETWEEN 40 AND 50 AND number < 43")
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec!["number".to_owned()],
+            rows: vec![
+                vec![Value::U64(40)],
+                vec![Value::U64(41)],
+                vec![Value::U64(42)],
+            ],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let payloads = worm
+            .execute(
+                "SELECT transaction_index FROM eth.transactions
+                 WHERE block_number = 41 AND transaction_index > 0",
+            )
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec!["transaction_index".to_owned()],
+            rows: vec![vec![Value::U64(1)]],
+        };
+        assert_eq!(payloads, vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn select_logs() {
+        const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        const OTHER_TOKEN: &str = "0xdac17f958d2ee523a2206206994597c13d831ec7";
+        const TRANSFER: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let url = serve({
+            let requests = Arc::clone(&requests);
+
+            move |method, params| {
+                if method != "eth_getLogs" {
+                    return rpc(method, params);
+                }
+
+                let filter = &params[0];
+                let from = quantity(&filter["fromBlock"]).unwrap();
+                let to = quantity(&filter["toBlock"]).unwrap();
+                requests.lock().unwrap().push((from, to));
+                if to - from >= 4 {
+                    return Err((-32005, "query returned more than 10000 results".to_owned()));
+                }
+
+                assert_eq!(filter["address"], json!(TOKEN));
+                assert_eq!(filter["topics"][0], json!(TRANSFER));
+
+                let logs = (from..=to)
+                    .flat_map(|number| {
+                        [(0, TOKEN), (1, OTHER_TOKEN)]
+                            .into_iter()
+                            .map(move |(index, address)| {
+                                log_json(number, index, address, &[TRANSFER.to_owned()])
+                            })
+                    })
+                    .filter(|log| log["address"] == filter["address"])
+                    .collect();
+
+                Ok(logs)
+            }
+        });
+        let mut worm = Worm::new(EvmStorage::new(&url).unwrap());
+
+        let sql = format!(
+            "SELECT block_number, log_index, topic1 FROM eth.logs
+             WHERE block_number BETWEEN 90 AND 99 AND address = '{TOKEN}' AND topic0 = '{TRANSFER}'"
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "block_number".to_owned(),
+                "log_index".to_owned(),
+                "topic1".to_owned(),
+            ],
+            rows: (90..=99)
+                .map(|number| vec![Value::U64(number), Value::U64(0), Value::Null])
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                (90, 99),
+                (90, 94),
+                (90, 92),
+                (93, 94),
+                (95, 99),
+                (95, 97),
+                (98, 99)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn select_events() {
+        const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        const TRANSFER: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        const SENDER: &str = "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5";
+        const RECIPIENT: &str = "0x388c818ca8b9251b393131c08a736a67ccb19297";
+
+        let topic = |address: &str| format!("0x{:0>64}", address.trim_start_matches("0x"));
+        let url = serve(move |method, params| {
+            if method != "eth_getLogs" {
+                return rpc(method, params);
+            }
+
+            let filter = &params[0];
+            assert_eq!(filter["address"], json!(TOKEN));
+            assert_eq!(filter["topics"][0], json!(TRANSFER));
+            assert_eq!(filter["topics"][2], json!(topic(RECIPIENT)));
+            assert_eq!(quantity(&filter["fromBlock"]), Some(95));
+            assert_eq!(quantity(&filter["toBlock"]), Some(100));
+
+            let transfer = [TRANSFER.to_owned(), topic(SENDER), topic(RECIPIENT)];
+            let logs = (95..=96)
+                .map(|number| log_json(number, 0, TOKEN, &transfer))
+                .chain([log_json(97, 1, TOKEN, &[TRANSFER.to_owned()])])
+                .collect();
+
+            Ok(logs)
+        });
+        let storage = EvmStorage::new(&url)
+            .unwrap()
+            .with_abi(ERC20_ABI, Some(TOKEN))
+            .unwrap();
+        assert!(storage
+            .fetch_schema("events.transfer")
+            .await
+            .unwrap()
+            .is_some());
+        assert_eq!(storage.fetch_all_schemas().await.unwrap().len(), 6);
+
+        let mut worm = Worm::new(storage);
+        let sql = format!(
+            r#"SELECT block_number, "from", value FROM eth.events.Transfer
+               WHERE contract = '{TOKEN}' AND "to" = '{RECIPIENT}' AND block_number > 94"#
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "block_number".to_owned(),
+                r#""from""#.to_owned(),
+                "value".to_owned(),
+            ],
+            rows: [95, 96]
+                .into_iter()
+                .map(|number| {
+                    vec![
+                        Value::U64(number),
+                        Value::Str(SENDER.to_owned()),
+                        Value::U128(1),
+                    ]
+                })
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn select_calls() {
+        const TRANSFER: &str = "0xa9059cbb";
+        const RECIPIENT: &str = "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5";
+
+        let url = serve(|method, params| match requested_block(params) {
+            Some(number) if method == "eth_getBlockByNumber" && number <= 100 => {
+                let mut transfer = transaction_json(number, 0);
+                transfer["input"] = json!(format!(
+                    "{TRANSFER}{:0>64}{:064x}",
+                    RECIPIENT.trim_start_matches("0x"),
+                    number
+                ));
+
+                Ok(block_json(
+                    number,
+                    vec![transfer, transaction_json(number, 1)],
+                ))
+            }
+            _ => rpc(method, params),
+        });
+        let storage = EvmStorage::new(&url)
+            .unwrap()
+            .with_abi(ERC20_ABI, None)
+            .unwrap();
+        assert!(storage.clone().with_abi("[", None).is_err());
+        assert!(storage.clone().with_abi(ERC20_ABI, Some("0x12")).is_err());
+
+        let mut worm = Worm::new(storage);
+        let payloads = worm
+            .execute(
+                r#"SELECT transaction_hash, "to", value FROM eth.calls.transfer
+                   WHERE block_number BETWEEN 99 AND 100"#,
+            )
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "transaction_hash".to_owned(),
+                r#""to""#.to_owned(),
+                "value".to_owned(),
+            ],
+            rows: [99, 100]
+                .into_iter()
+                .map(|number| {
+                    vec![
+                        Value::Str(transaction_hash(number, 0)),
+                        Value::Str(RECIPIENT.to_owned()),
+                        Value::U128(number as u128),
+                    ]
+                })
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+    }
+}
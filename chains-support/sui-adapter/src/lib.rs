@@ -1,2 +1,94 @@
-//mod resolvers;
+#![deny(clippy::str_to_string)]
 
+mod range;
+mod resolvers;
+mod result;
+mod store;
+
+#[cfg(test)]
+mod mock_rpc;
+
+pub use result::{ResultExt, SuiStorageError};
+
+use {
+    alloy::{
+        rpc::client::RpcClient,
+        transports::http::{Client, Http},
+    },
+    serde::{de::DeserializeOwned, Serialize},
+    std::fmt::Debug,
+    wql_core::{
+        error::Result,
+        store::{
+            CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, StoreMut, Transaction,
+        },
+    },
+};
+
+/// Number of most recent checkpoints scanned when a query does not narrow down the
+/// checkpoint range.
+pub const DEFAULT_SCAN_DEPTH: u64 = 10;
+
+/// Short code under which the entities are served unless set with [`SuiStorage::with_chain`].
+pub const DEFAULT_CHAIN: &str = "sui";
+
+/// Read-only storage which serves Sui entities over a JSON-RPC endpoint.
+#[derive(Debug, Clone)]
+pub struct SuiStorage {
+    pub client: RpcClient<Http<Client>>,
+    pub scan_depth: u64,
+    /// Short code of the chain, accepted as a prefix of the table names as in `sui.objects`.
+    pub chain: String,
+}
+
+impl SuiStorage {
+    pub fn new(rpc_url: &str) -> Result<Self> {
+        let url = rpc_url
+            .parse()
+            .map_err(|_| SuiStorageError::InvalidRpcUrl(rpc_url.to_owned()))?;
+
+        Ok(Self {
+            client: RpcClient::new_http(url),
+            scan_depth: DEFAULT_SCAN_DEPTH,
+            chain: DEFAULT_CHAIN.to_owned(),
+        })
+    }
+
+    pub fn with_scan_depth(self, scan_depth: u64) -> Self {
+        Self {
+            scan_depth: scan_depth.max(1),
+            ..self
+        }
+    }
+
+    pub fn with_chain(self, chain: &str) -> Self {
+        Self {
+            chain: chain.to_lowercase(),
+            ..self
+        }
+    }
+
+    /// Strips the chain prefix off `table_name`, names without one are left as they are.
+    fn entity_name<'a>(&self, table_name: &'a str) -> &'a str {
+        match table_name.split_once('.') {
+            Some((chain, name)) if chain.eq_ignore_ascii_case(&self.chain) => name,
+            _ => table_name,
+        }
+    }
+
+    async fn request<P, R>(&self, method: &'static str, params: P) -> Result<R>
+    where
+        P: Serialize + Clone + Debug + Send + Sync + Unpin,
+        R: DeserializeOwned + Debug + Send + Sync + Unpin + 'static,
+    {
+        self.client.request(method, params).await.map_storage_err()
+    }
+}
+
+impl Metadata for SuiStorage {}
+impl StoreMut for SuiStorage {}
+impl Index for SuiStorage {}
+impl IndexMut for SuiStorage {}
+impl Transaction for SuiStorage {}
+impl CustomFunction for SuiStorage {}
+impl CustomFunctionMut for SuiStorage {}
@@ -0,0 +1,255 @@
+//! Minimal JSON-RPC server answering with canned responses, used to test the storage
+//! without a live node.
+
+use {
+    serde_json::{json, Value as JsonValue},
+    std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::Arc,
+        thread,
+    },
+};
+
+pub type RpcResult = Result<JsonValue, (i64, String)>;
+
+/// Spawns a server on a random local port and returns its url.
+///
+/// `handler` receives the method name and params of every request.
+pub fn serve<F>(handler: F) -> String
+where
+    F: Fn(&str, &JsonValue) -> RpcResult + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { break };
+            let handler = Arc::clone(&handler);
+
+            thread::spawn(move || handle_connection(stream, handler.as_ref()));
+        }
+    });
+
+    url
+}
+
+fn handle_connection<F>(stream: TcpStream, handler: &F)
+where
+    F: Fn(&str, &JsonValue) -> RpcResult,
+{
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+
+    loop {
+        let mut content_length = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) => {}
+            }
+
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+
+        let request: JsonValue = serde_json::from_slice(&body).unwrap();
+        let response = match request {
+            JsonValue::Array(requests) => {
+                JsonValue::Array(requests.iter().map(|r| respond(r, handler)).collect())
+            }
+            request => respond(&request, handler),
+        };
+        let response = response.to_string();
+
+        let written = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        if written.is_err() {
+            return;
+        }
+    }
+}
+
+fn respond<F>(request: &JsonValue, handler: &F) -> JsonValue
+where
+    F: Fn(&str, &JsonValue) -> RpcResult,
+{
+    let id = request["id"].clone();
+    let method = request["method"].as_str().unwrap_or_default();
+
+    match handler(method, &request["params"]) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": code, "message": message },
+        }),
+    }
+}
+
+/// Address owning the canned objects and balances.
+pub const OWNER: &str = "0x4d6960d097167b4e9f0512d0a04d9d2a8742b428ab6b638a40940e1b827eeb35";
+
+/// Canned `sui_getCheckpoint` response for the checkpoint `sequence_number`.
+pub fn checkpoint_json(sequence_number: u64, transactions: u64) -> JsonValue {
+    json!({
+        "epoch": "500",
+        "sequenceNumber": sequence_number.to_string(),
+        "digest": checkpoint_digest(sequence_number),
+        "networkTotalTransactions": (sequence_number * transactions).to_string(),
+        "previousDigest": checkpoint_digest(sequence_number.saturating_sub(1)),
+        "timestampMs": (1_700_000_000_000 + sequence_number * 250).to_string(),
+        "transactions": (0..transactions)
+            .map(|index| transaction_digest(sequence_number, index))
+            .collect::<Vec<_>>(),
+        "checkpointCommitments": [],
+        "validatorSignature": "AA==",
+    })
+}
+
+/// Canned transaction `index` of the checkpoint `checkpoint`, emitting `events` events.
+pub fn transaction_json(checkpoint: u64, index: u64, events: u64) -> JsonValue {
+    let digest = transaction_digest(checkpoint, index);
+
+    json!({
+        "digest": digest,
+        "transaction": {
+            "data": {
+                "messageVersion": "v1",
+                "sender": OWNER,
+                "gasData": { "owner": OWNER, "price": "750", "budget": "5000000" },
+            },
+            "txSignatures": [],
+        },
+        "effects": {
+            "status": { "status": if index == 0 { "success" } else { "failure" } },
+            "gasUsed": {
+                "computationCost": "750000",
+                "storageCost": "1976000",
+                "storageRebate": "978120",
+                "nonRefundableStorageFee": "9880",
+            },
+        },
+        "events": (0..events).map(|seq| event_json(&digest, seq)).collect::<Vec<_>>(),
+        "timestampMs": (1_700_000_000_000 + checkpoint * 250).to_string(),
+        "checkpoint": checkpoint.to_string(),
+    })
+}
+
+/// Canned event `seq` of the transaction `digest`.
+pub fn event_json(digest: &str, seq: u64) -> JsonValue {
+    json!({
+        "id": { "txDigest": digest, "eventSeq": seq.to_string() },
+        "packageId": "0x3",
+        "transactionModule": "sui_system",
+        "sender": OWNER,
+        "type": "0x3::validator::StakingRequestEvent",
+        "parsedJson": { "amount": "1000000000", "validator_address": OWNER },
+        "bcs": "",
+        "timestampMs": "1700000000000",
+    })
+}
+
+/// Canned coin object `index` owned by [`OWNER`].
+pub fn object_json(index: u64) -> JsonValue {
+    json!({
+        "objectId": object_id(index),
+        "version": "42",
+        "digest": format!("object{index}"),
+        "type": "0x2::coin::Coin<0x2::sui::SUI>",
+        "owner": { "AddressOwner": OWNER },
+        "previousTransaction": transaction_digest(1, 0),
+        "storageRebate": "988000",
+        "content": {
+            "dataType": "moveObject",
+            "type": "0x2::coin::Coin<0x2::sui::SUI>",
+            "hasPublicTransfer": true,
+            "fields": { "balance": "1000", "id": { "id": object_id(index) } },
+        },
+    })
+}
+
+pub fn object_id(index: u64) -> String {
+    format!("0x{index:064x}")
+}
+
+pub fn checkpoint_digest(sequence_number: u64) -> String {
+    format!("checkpoint{sequence_number}")
+}
+
+/// Digest of the canned transaction `index` of the checkpoint `checkpoint`.
+pub fn transaction_digest(checkpoint: u64, index: u64) -> String {
+    format!("tx{checkpoint}x{index}")
+}
+
+/// Canned `suix_getDynamicFields` items of the pool [`object_id`]`(0)`: the position `7`
+/// held in the field object [`object_id`]`(10)`, and the coin object `(2)` as `vault`.
+pub fn dynamic_fields_json() -> [JsonValue; 2] {
+    [
+        json!({
+            "name": { "type": "u64", "value": "7" },
+            "bcsName": "",
+            "type": "DynamicField",
+            "objectType": "0xabc::pool::Position",
+            "objectId": object_id(10),
+            "version": "12",
+            "digest": "object10",
+        }),
+        json!({
+            "name": { "type": "0x1::string::String", "value": "vault" },
+            "bcsName": "",
+            "type": "DynamicObject",
+            "objectType": "0x2::coin::Coin<0x2::sui::SUI>",
+            "objectId": object_id(2),
+            "version": "42",
+            "digest": "object2",
+        }),
+    ]
+}
+
+/// Canned field object wrapping the position of [`dynamic_fields_json`].
+pub fn field_object_json() -> JsonValue {
+    let field_type = "0x2::dynamic_field::Field<u64, 0xabc::pool::Position>";
+
+    json!({
+        "objectId": object_id(10),
+        "version": "12",
+        "digest": "object10",
+        "type": field_type,
+        "owner": { "ObjectOwner": object_id(0) },
+        "content": {
+            "dataType": "moveObject",
+            "type": field_type,
+            "hasPublicTransfer": false,
+            "fields": {
+                "id": { "id": object_id(10) },
+                "name": "7",
+                "value": {
+                    "type": "0xabc::pool::Position",
+                    "fields": { "liquidity": "500", "ticks": ["-10", "10"] },
+                },
+            },
+        },
+    })
+}
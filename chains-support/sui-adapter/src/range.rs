@@ -0,0 +1,160 @@
+use {
+    std::ops::RangeInclusive,
+    wql_core::{ast::IndexOperator, data::Value, store::Predicate},
+};
+
+/// Checkpoints a scan is narrowed down to by the pushed down predicates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CheckpointRange {
+    lower: Option<i128>,
+    upper: Option<i128>,
+}
+
+impl CheckpointRange {
+    /// Narrows the range by every predicate on `column` compared with an integer, and
+    /// reports those predicates as handled.
+    pub fn from_predicates(column: &str, predicates: &[Predicate]) -> (Self, Vec<bool>) {
+        let mut range = Self::default();
+        let handled = predicates
+            .iter()
+            .map(|predicate| {
+                let number = match integer(&predicate.value) {
+                    Some(number) if predicate.column == column => number,
+                    _ => return false,
+                };
+
+                match predicate.op {
+                    IndexOperator::Gt => range.lower_to(number + 1),
+                    IndexOperator::GtEq => range.lower_to(number),
+                    IndexOperator::Lt => range.upper_to(number - 1),
+                    IndexOperator::LtEq => range.upper_to(number),
+                    IndexOperator::Eq => {
+                        range.lower_to(number);
+                        range.upper_to(number);
+                    }
+                }
+
+                true
+            })
+            .collect();
+
+        (range, handled)
+    }
+
+    fn lower_to(&mut self, number: i128) {
+        self.lower = Some(self.lower.map_or(number, |lower| lower.max(number)));
+    }
+
+    fn upper_to(&mut self, number: i128) {
+        self.upper = Some(self.upper.map_or(number, |upper| upper.min(number)));
+    }
+
+    /// Checkpoints to scan, capped at the `latest` checkpoint.
+    ///
+    /// A missing lower bound falls back to the `scan_depth` checkpoints ending at the upper bound.
+    pub fn resolve(&self, latest: u64, scan_depth: u64) -> RangeInclusive<u64> {
+        let end = self
+            .upper
+            .map_or(latest as i128, |upper| upper.min(latest as i128));
+        let Ok(end) = u64::try_from(end) else {
+            return RangeInclusive::new(1, 0);
+        };
+        let start = match self.lower {
+            Some(lower) => u64::try_from(lower.max(0)).unwrap_or(u64::MAX),
+            None => end.saturating_sub(scan_depth.saturating_sub(1)),
+        };
+
+        start..=end
+    }
+}
+
+fn integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::I8(v) => Some(*v as i128),
+        Value::I16(v) => Some(*v as i128),
+        Value::I32(v) => Some(*v as i128),
+        Value::I64(v) => Some(*v as i128),
+        Value::I128(v) => Some(*v),
+        Value::U8(v) => Some(*v as i128),
+        Value::U16(v) => Some(*v as i128),
+        Value::U32(v) => Some(*v as i128),
+        Value::U64(v) => Some(*v as i128),
+        Value::U128(v) => i128::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::CheckpointRange,
+        wql_core::{ast::IndexOperator, data::Value, store::Predicate},
+    };
+
+    fn predicate(column: &str, op: IndexOperator, value: Value) -> Predicate {
+        Predicate {
+            column: column.to_owned(),
+            op,
+            value,
+        }
+    }
+
+    #[test]
+    fn from_predicates() {
+        let (range, handled) = CheckpointRange::from_predicates("sequence_number", &[]);
+        assert_eq!(range.resolve(100, 10), 91..=100);
+        assert_eq!(range.resolve(100, 0), 100..=100);
+        assert_eq!(handled, Vec::<bool>::new());
+
+        let predicates = [
+            predicate(
+                "sequence_number",
+                IndexOperator::GtEq,
+                Value::I64(19_000_000),
+            ),
+            predicate(
+                "sequence_number",
+                IndexOperator::LtEq,
+                Value::I64(19_000_100),
+            ),
+            predicate("sequence_number", IndexOperator::Gt, Value::I64(18_000_000)),
+            predicate("digest", IndexOperator::Eq, Value::I64(1)),
+            predicate(
+                "sequence_number",
+                IndexOperator::Lt,
+                Value::F64(19_000_050.5),
+            ),
+        ];
+        let (range, handled) = CheckpointRange::from_predicates("sequence_number", &predicates);
+        assert_eq!(range.resolve(20_000_000, 10), 19_000_000..=19_000_100);
+        assert_eq!(handled, vec![true, true, true, false, false]);
+
+        let predicates = [predicate("checkpoint", IndexOperator::Gt, Value::U64(95))];
+        let (range, _) = CheckpointRange::from_predicates("checkpoint", &predicates);
+        assert_eq!(range.resolve(100, 10), 96..=100);
+
+        let predicates = [predicate(
+            "sequence_number",
+            IndexOperator::Lt,
+            Value::I64(50),
+        )];
+        let (range, _) = CheckpointRange::from_predicates("sequence_number", &predicates);
+        assert_eq!(range.resolve(100, 10), 40..=49);
+
+        let predicates = [predicate(
+            "sequence_number",
+            IndexOperator::Eq,
+            Value::I64(120),
+        )];
+        let (range, _) = CheckpointRange::from_predicates("sequence_number", &predicates);
+        assert!(range.resolve(100, 10).is_empty());
+
+        let predicates = [predicate(
+            "sequence_number",
+            IndexOperator::Lt,
+            Value::I64(0),
+        )];
+        let (range, _) = CheckpointRange::from_predicates("sequence_number", &predicates);
+        assert!(range.resolve(100, 10).is_empty());
+    }
+}
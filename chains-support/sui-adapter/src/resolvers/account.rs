@@ -0,0 +1,29 @@
+use {
+    super::{str_value, u128_value, u64_value},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+/// Balances of every coin type owned by `address`.
+pub async fn fetch_balances(storage: &SuiStorage, address: &str) -> Result<Vec<JsonValue>> {
+    storage
+        .request("suix_getAllBalances", json!([address]))
+        .await
+}
+
+pub fn account_row(address: &str, balance: &JsonValue) -> HashMap<String, Value> {
+    HashMap::from([
+        ("address".to_owned(), Value::Str(address.to_owned())),
+        ("coin_type".to_owned(), str_value(&balance["coinType"])),
+        (
+            "coin_object_count".to_owned(),
+            u64_value(&balance["coinObjectCount"]),
+        ),
+        (
+            "total_balance".to_owned(),
+            u128_value(&balance["totalBalance"]),
+        ),
+    ])
+}
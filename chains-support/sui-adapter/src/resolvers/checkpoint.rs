@@ -0,0 +1,61 @@
+use {
+    super::{str_value, timestamp_value, u64_of, u64_value},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{
+        data::Value,
+        error::{Error, Result},
+    },
+};
+
+pub async fn fetch_latest_checkpoint(storage: &SuiStorage) -> Result<u64> {
+    let latest: JsonValue = storage
+        .request("sui_getLatestCheckpointSequenceNumber", json!([]))
+        .await?;
+
+    u64_of(&latest)
+        .ok_or_else(|| Error::StorageMsg(format!("invalid checkpoint sequence number: {latest}")))
+}
+
+pub async fn fetch_checkpoint(
+    storage: &SuiStorage,
+    sequence_number: u64,
+) -> Result<Option<JsonValue>> {
+    let checkpoint: JsonValue = storage
+        .request("sui_getCheckpoint", json!([sequence_number.to_string()]))
+        .await?;
+
+    Ok((!checkpoint.is_null()).then_some(checkpoint))
+}
+
+pub fn checkpoint_row(checkpoint: &JsonValue) -> HashMap<String, Value> {
+    let transaction_count = checkpoint["transactions"]
+        .as_array()
+        .map_or(0, |transactions| transactions.len() as u64);
+
+    HashMap::from([
+        (
+            "sequence_number".to_owned(),
+            u64_value(&checkpoint["sequenceNumber"]),
+        ),
+        ("digest".to_owned(), str_value(&checkpoint["digest"])),
+        ("epoch".to_owned(), u64_value(&checkpoint["epoch"])),
+        (
+            "timestamp".to_owned(),
+            timestamp_value(&checkpoint["timestampMs"]),
+        ),
+        (
+            "previous_digest".to_owned(),
+            str_value(&checkpoint["previousDigest"]),
+        ),
+        (
+            "transaction_count".to_owned(),
+            Value::U64(transaction_count),
+        ),
+        (
+            "network_total_transactions".to_owned(),
+            u64_value(&checkpoint["networkTotalTransactions"]),
+        ),
+    ])
+}
@@ -0,0 +1,41 @@
+use {
+    super::{fetch_pages, move_value, str_value, u64_value},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+/// Fetches the dynamic fields of the object `parent`, without their values.
+pub async fn fetch_dynamic_fields(storage: &SuiStorage, parent: &str) -> Result<Vec<JsonValue>> {
+    fetch_pages(storage, "suix_getDynamicFields", json!([parent])).await
+}
+
+/// `object` is the one holding the field, a `0x2::dynamic_field::Field` wrapping the value
+/// for a dynamic field, and the child object itself for a dynamic object field.
+pub fn dynamic_field_row(
+    parent: &str,
+    field: &JsonValue,
+    object: Option<&JsonValue>,
+) -> HashMap<String, Value> {
+    let value = object.map_or(Value::Null, |object| {
+        let fields = &object["content"]["fields"];
+
+        match field["type"].as_str() {
+            Some("DynamicObject") => move_value(fields),
+            _ => move_value(&fields["value"]),
+        }
+    });
+
+    HashMap::from([
+        ("parent".to_owned(), Value::Str(parent.to_owned())),
+        ("name_type".to_owned(), str_value(&field["name"]["type"])),
+        ("name".to_owned(), move_value(&field["name"]["value"])),
+        ("kind".to_owned(), str_value(&field["type"])),
+        ("object_id".to_owned(), str_value(&field["objectId"])),
+        ("object_type".to_owned(), str_value(&field["objectType"])),
+        ("version".to_owned(), u64_value(&field["version"])),
+        ("digest".to_owned(), str_value(&field["digest"])),
+        ("value".to_owned(), value),
+    ])
+}
@@ -0,0 +1,33 @@
+use {
+    super::{move_value, str_value, timestamp_value, u64_value},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+/// Events emitted by the transaction `digest`.
+pub async fn fetch_events(storage: &SuiStorage, digest: &str) -> Result<Vec<JsonValue>> {
+    storage.request("sui_getEvents", json!([digest])).await
+}
+
+/// `checkpoint` is the one of the emitting transaction, as events do not carry it.
+pub fn event_row(event: &JsonValue, checkpoint: &JsonValue) -> HashMap<String, Value> {
+    HashMap::from([
+        (
+            "transaction_digest".to_owned(),
+            str_value(&event["id"]["txDigest"]),
+        ),
+        ("event_seq".to_owned(), u64_value(&event["id"]["eventSeq"])),
+        ("checkpoint".to_owned(), u64_value(checkpoint)),
+        (
+            "timestamp".to_owned(),
+            timestamp_value(&event["timestampMs"]),
+        ),
+        ("package_id".to_owned(), str_value(&event["packageId"])),
+        ("module".to_owned(), str_value(&event["transactionModule"])),
+        ("sender".to_owned(), str_value(&event["sender"])),
+        ("type".to_owned(), str_value(&event["type"])),
+        ("parsed_json".to_owned(), move_value(&event["parsedJson"])),
+    ])
+}
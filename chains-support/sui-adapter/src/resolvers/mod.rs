@@ -0,0 +1,244 @@
+mod account;
+mod checkpoint;
+mod dynamic_field;
+mod event;
+mod object;
+mod transaction;
+
+pub use {
+    account::{account_row, fetch_balances},
+    checkpoint::{checkpoint_row, fetch_checkpoint, fetch_latest_checkpoint},
+    dynamic_field::{dynamic_field_row, fetch_dynamic_fields},
+    event::{event_row, fetch_events},
+    object::{fetch_object, fetch_objects, fetch_owned_objects, object_row},
+    transaction::{fetch_transaction, fetch_transactions, transaction_row},
+};
+
+use {
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    wql_core::{
+        ast::{ColumnDef, DataType},
+        chrono::DateTime,
+        data::Value,
+        error::Result,
+    },
+};
+
+/// Items per `sui_multiGet*` request, the limit enforced by full nodes.
+const MULTI_GET_LIMIT: usize = 50;
+
+/// Items per page of the paginated `suix_*` methods.
+const PAGE_SIZE: u64 = 50;
+
+/// Sui entities served by [`crate::SuiStorage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entity {
+    Accounts,
+    Objects,
+    Transactions,
+    Checkpoints,
+    Events,
+    DynamicFields,
+}
+
+impl Entity {
+    pub const ALL: [Entity; 6] = [
+        Entity::Accounts,
+        Entity::Objects,
+        Entity::Transactions,
+        Entity::Checkpoints,
+        Entity::Events,
+        Entity::DynamicFields,
+    ];
+
+    pub fn from_table_name(table_name: &str) -> Option<Self> {
+        match table_name.to_lowercase().as_str() {
+            "accounts" => Some(Self::Accounts),
+            "objects" => Some(Self::Objects),
+            "transactions" => Some(Self::Transactions),
+            "checkpoints" => Some(Self::Checkpoints),
+            "events" => Some(Self::Events),
+            "dynamic_fields" => Some(Self::DynamicFields),
+            _ => None,
+        }
+    }
+
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            Self::Accounts => "accounts",
+            Self::Objects => "objects",
+            Self::Transactions => "transactions",
+            Self::Checkpoints => "checkpoints",
+            Self::Events => "events",
+            Self::DynamicFields => "dynamic_fields",
+        }
+    }
+
+    /// Columns of the rows produced by the resolver of the entity, in `SELECT *` order.
+    pub fn column_defs(&self) -> Vec<ColumnDef> {
+        use DataType::*;
+
+        let columns: &[(&str, DataType, bool)] = match self {
+            Self::Accounts => &[
+                ("address", Text, false),
+                ("coin_type", Text, false),
+                ("coin_object_count", Uint64, false),
+                ("total_balance", Uint128, false),
+            ],
+            Self::Objects => &[
+                ("object_id", Text, false),
+                ("version", Uint64, false),
+                ("digest", Text, false),
+                ("type", Text, true),
+                ("owner", Text, true),
+                ("previous_transaction", Text, true),
+                ("storage_rebate", Uint64, true),
+                ("fields", Map, true),
+            ],
+            Self::Transactions => &[
+                ("digest", Text, false),
+                ("checkpoint", Uint64, true),
+                ("timestamp", Timestamp, true),
+                ("sender", Text, true),
+                ("status", Boolean, true),
+                ("computation_cost", Uint64, true),
+                ("storage_cost", Uint64, true),
+                ("storage_rebate", Uint64, true),
+            ],
+            Self::Checkpoints => &[
+                ("sequence_number", Uint64, false),
+                ("digest", Text, false),
+                ("epoch", Uint64, false),
+                ("timestamp", Timestamp, false),
+                ("previous_digest", Text, true),
+                ("transaction_count", Uint64, false),
+                ("network_total_transactions", Uint64, false),
+            ],
+            Self::Events => &[
+                ("transaction_digest", Text, false),
+                ("event_seq", Uint64, false),
+                ("checkpoint", Uint64, true),
+                ("timestamp", Timestamp, true),
+                ("package_id", Text, false),
+                ("module", Text, false),
+                ("sender", Text, false),
+                ("type", Text, false),
+                ("parsed_json", Map, true),
+            ],
+            Self::DynamicFields => &[
+                ("parent", Text, false),
+                ("name_type", Text, false),
+                ("name", Text, false),
+                ("kind", Text, false),
+                ("object_id", Text, false),
+                ("object_type", Text, false),
+                ("version", Uint64, false),
+                ("digest", Text, false),
+                ("value", Map, true),
+            ],
+        };
+
+        columns
+            .iter()
+            .map(|(name, data_type, nullable)| ColumnDef {
+                name: (*name).to_owned(),
+                data_type: data_type.clone(),
+                nullable: *nullable,
+                default: None,
+                comment: None,
+            })
+            .collect()
+    }
+}
+
+pub fn str_value(json: &JsonValue) -> Value {
+    json.as_str()
+        .map(|value| Value::Str(value.to_owned()))
+        .unwrap_or(Value::Null)
+}
+
+/// Sui encodes 64-bit integers as decimal strings.
+pub fn u64_of(json: &JsonValue) -> Option<u64> {
+    match json {
+        JsonValue::String(value) => value.parse().ok(),
+        json => json.as_u64(),
+    }
+}
+
+pub fn u64_value(json: &JsonValue) -> Value {
+    u64_of(json).map(Value::U64).unwrap_or(Value::Null)
+}
+
+/// Balances beyond `u128` are kept as their decimal string rather than truncated.
+pub fn u128_value(json: &JsonValue) -> Value {
+    match json {
+        JsonValue::String(value) => value
+            .parse()
+            .map(Value::U128)
+            .unwrap_or_else(|_| Value::Str(value.clone())),
+        json => json
+            .as_u64()
+            .map(|v| Value::U128(v as u128))
+            .unwrap_or(Value::Null),
+    }
+}
+
+/// Sui timestamps are milliseconds since the epoch.
+pub fn timestamp_value(json: &JsonValue) -> Value {
+    u64_of(json)
+        .and_then(|millis| i64::try_from(millis).ok())
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|datetime| Value::Timestamp(datetime.naive_utc()))
+        .unwrap_or(Value::Null)
+}
+
+/// Converts the JSON rendering of a Move value, unwrapping the `{ type, fields }` objects
+/// nested structs are rendered as, so that struct fields are reachable by name.
+pub fn move_value(json: &JsonValue) -> Value {
+    match json {
+        JsonValue::Null => Value::Null,
+        JsonValue::Array(values) => Value::List(values.iter().map(move_value).collect()),
+        JsonValue::Object(object) => match (object.len(), object.get("type"), object.get("fields"))
+        {
+            (2, Some(JsonValue::String(_)), Some(fields @ JsonValue::Object(_))) => {
+                move_value(fields)
+            }
+            _ => Value::Map(
+                object
+                    .iter()
+                    .map(|(name, value)| (name.clone(), move_value(value)))
+                    .collect(),
+            ),
+        },
+        json => Value::try_from(json.clone()).unwrap_or(Value::Null),
+    }
+}
+
+/// Fetches every item of the paginated `method`, called with `params` followed by the cursor
+/// and the page size.
+pub async fn fetch_pages(
+    storage: &SuiStorage,
+    method: &'static str,
+    params: JsonValue,
+) -> Result<Vec<JsonValue>> {
+    let mut items = Vec::new();
+    let mut cursor = JsonValue::Null;
+    loop {
+        let mut params = params.clone();
+        if let Some(params) = params.as_array_mut() {
+            params.extend([cursor, json!(PAGE_SIZE)]);
+        }
+
+        let mut page: JsonValue = storage.request(method, params).await?;
+        if let JsonValue::Array(data) = page["data"].take() {
+            items.extend(data);
+        }
+
+        let has_next_page = page["hasNextPage"].as_bool().unwrap_or(false);
+        match page["nextCursor"].take() {
+            next_cursor if has_next_page && !next_cursor.is_null() => cursor = next_cursor,
+            _ => return Ok(items),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use {
+    super::{fetch_pages, move_value, str_value, u64_value, MULTI_GET_LIMIT},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+fn object_options() -> JsonValue {
+    json!({
+        "showType": true,
+        "showOwner": true,
+        "showPreviousTransaction": true,
+        "showStorageRebate": true,
+        "showContent": true,
+    })
+}
+
+/// Fetches the object `object_id`, `None` when it does not exist or was deleted.
+pub async fn fetch_object(storage: &SuiStorage, object_id: &str) -> Result<Option<JsonValue>> {
+    let response: JsonValue = storage
+        .request("sui_getObject", json!([object_id, object_options()]))
+        .await?;
+
+    Ok(object_data(response))
+}
+
+/// Fetches every object owned by the address `owner`.
+pub async fn fetch_owned_objects(storage: &SuiStorage, owner: &str) -> Result<Vec<JsonValue>> {
+    let query = json!({ "options": object_options() });
+    let objects = fetch_pages(storage, "suix_getOwnedObjects", json!([owner, query])).await?;
+
+    Ok(objects.into_iter().filter_map(object_data).collect())
+}
+
+/// Fetches the objects `object_ids`, in order, `None` for the missing ones.
+pub async fn fetch_objects(
+    storage: &SuiStorage,
+    object_ids: &[String],
+) -> Result<Vec<Option<JsonValue>>> {
+    let mut objects = Vec::with_capacity(object_ids.len());
+    for object_ids in object_ids.chunks(MULTI_GET_LIMIT) {
+        let responses: Vec<JsonValue> = storage
+            .request("sui_multiGetObjects", json!([object_ids, object_options()]))
+            .await?;
+
+        objects.extend(responses.into_iter().map(object_data));
+    }
+
+    Ok(objects)
+}
+
+/// Unwraps the `data` of an object response, which holds an `error` instead for missing
+/// objects.
+fn object_data(mut response: JsonValue) -> Option<JsonValue> {
+    match response["data"].take() {
+        JsonValue::Null => None,
+        data => Some(data),
+    }
+}
+
+pub fn object_row(object: &JsonValue) -> HashMap<String, Value> {
+    HashMap::from([
+        ("object_id".to_owned(), str_value(&object["objectId"])),
+        ("version".to_owned(), u64_value(&object["version"])),
+        ("digest".to_owned(), str_value(&object["digest"])),
+        ("type".to_owned(), str_value(&object["type"])),
+        ("owner".to_owned(), owner_value(&object["owner"])),
+        (
+            "previous_transaction".to_owned(),
+            str_value(&object["previousTransaction"]),
+        ),
+        (
+            "storage_rebate".to_owned(),
+            u64_value(&object["storageRebate"]),
+        ),
+        (
+            "fields".to_owned(),
+            move_value(&object["content"]["fields"]),
+        ),
+    ])
+}
+
+/// Address of the owning account or object, `shared` or `immutable` otherwise.
+fn owner_value(owner: &JsonValue) -> Value {
+    match owner {
+        JsonValue::Null => Value::Null,
+        JsonValue::String(owner) => Value::Str(owner.to_lowercase()),
+        owner => {
+            let address = owner["AddressOwner"]
+                .as_str()
+                .or_else(|| owner["ObjectOwner"].as_str());
+
+            match address {
+                Some(address) => Value::Str(address.to_owned()),
+                None if owner.get("Shared").is_some() => Value::Str("shared".to_owned()),
+                None => Value::Str(owner.to_string()),
+            }
+        }
+    }
+}
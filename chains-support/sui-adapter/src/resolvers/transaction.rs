@@ -0,0 +1,80 @@
+use {
+    super::{str_value, timestamp_value, u64_value, MULTI_GET_LIMIT},
+    crate::SuiStorage,
+    serde_json::{json, Value as JsonValue},
+    std::collections::HashMap,
+    wql_core::{data::Value, error::Result},
+};
+
+fn transaction_options() -> JsonValue {
+    json!({ "showInput": true, "showEffects": true, "showEvents": true })
+}
+
+pub async fn fetch_transaction(storage: &SuiStorage, digest: &str) -> Result<Option<JsonValue>> {
+    let transaction: JsonValue = storage
+        .request(
+            "sui_getTransactionBlock",
+            json!([digest, transaction_options()]),
+        )
+        .await?;
+
+    Ok((!transaction.is_null()).then_some(transaction))
+}
+
+/// Fetches the transactions of `digests`, in order, with their effects and events.
+pub async fn fetch_transactions(
+    storage: &SuiStorage,
+    digests: &[String],
+) -> Result<Vec<JsonValue>> {
+    let mut transactions = Vec::with_capacity(digests.len());
+    for digests in digests.chunks(MULTI_GET_LIMIT) {
+        let chunk: Vec<JsonValue> = storage
+            .request(
+                "sui_multiGetTransactionBlocks",
+                json!([digests, transaction_options()]),
+            )
+            .await?;
+
+        transactions.extend(chunk);
+    }
+
+    Ok(transactions)
+}
+
+pub fn transaction_row(transaction: &JsonValue) -> HashMap<String, Value> {
+    let effects = &transaction["effects"];
+    let gas_used = &effects["gasUsed"];
+    let status = match effects["status"]["status"].as_str() {
+        Some(status) => Value::Bool(status == "success"),
+        None => Value::Null,
+    };
+
+    HashMap::from([
+        ("digest".to_owned(), str_value(&transaction["digest"])),
+        (
+            "checkpoint".to_owned(),
+            u64_value(&transaction["checkpoint"]),
+        ),
+        (
+            "timestamp".to_owned(),
+            timestamp_value(&transaction["timestampMs"]),
+        ),
+        (
+            "sender".to_owned(),
+            str_value(&transaction["transaction"]["data"]["sender"]),
+        ),
+        ("status".to_owned(), status),
+        (
+            "computation_cost".to_owned(),
+            u64_value(&gas_used["computationCost"]),
+        ),
+        (
+            "storage_cost".to_owned(),
+            u64_value(&gas_used["storageCost"]),
+        ),
+        (
+            "storage_rebate".to_owned(),
+            u64_value(&gas_used["storageRebate"]),
+        ),
+    ])
+}
@@ -0,0 +1,38 @@
+use {
+    thiserror::Error as ThisError,
+    wql_core::error::{Error, Result},
+};
+
+#[derive(ThisError, Debug, PartialEq, Eq)]
+pub enum SuiStorageError {
+    #[error("invalid rpc url: {0}")]
+    InvalidRpcUrl(String),
+
+    #[error("entity not found: {0}")]
+    EntityNotFound(String),
+
+    #[error("unsupported key for {0}: {1}")]
+    UnsupportedKey(String, String),
+
+    #[error(
+        "scanning {0} requires an equality on {1}; in a join, {0} must come first in FROM and \
+         the column be qualified with its alias"
+    )]
+    MissingPredicate(String, String),
+}
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<SuiStorageError> for Error {
+    fn from(e: SuiStorageError) -> Error {
+        Error::StorageMsg(e.to_string())
+    }
+}
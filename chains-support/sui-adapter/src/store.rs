@@ -0,0 +1,747 @@
+use {
+    crate::{
+        range::CheckpointRange,
+        resolvers::{
+            account_row, checkpoint_row, dynamic_field_row, event_row, fetch_balances,
+            fetch_checkpoint, fetch_dynamic_fields, fetch_events, fetch_latest_checkpoint,
+            fetch_object, fetch_objects, fetch_owned_objects, fetch_transaction,
+            fetch_transactions, object_row, transaction_row, u64_of, Entity,
+        },
+        result::SuiStorageError,
+        SuiStorage,
+    },
+    async_trait::async_trait,
+    futures::{
+        stream::{self, StreamExt, TryStreamExt},
+        Stream,
+    },
+    serde_json::Value as JsonValue,
+    std::ops::RangeInclusive,
+    wql_core::{
+        ast::IndexOperator,
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{DataRow, Predicate, RowIter, Store},
+    },
+};
+
+impl SuiStorage {
+    fn entity(table_name: &str) -> Result<Entity> {
+        Entity::from_table_name(table_name)
+            .ok_or_else(|| SuiStorageError::EntityNotFound(table_name.to_owned()).into())
+    }
+
+    fn entity_schema(&self, entity: Entity) -> Schema {
+        Schema {
+            table_name: entity.table_name().to_owned(),
+            column_defs: Some(entity.column_defs()),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: Some(self.chain.clone()),
+            view: None,
+        }
+    }
+
+    fn unsupported_key(table_name: &str, key: &Key) -> SuiStorageError {
+        SuiStorageError::UnsupportedKey(table_name.to_owned(), format!("{key:?}"))
+    }
+
+    /// Checkpoints to scan, narrowed down by the predicates on the checkpoint `column`.
+    async fn checkpoint_range(
+        &self,
+        column: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RangeInclusive<u64>, Vec<bool>)> {
+        let (range, handled) = CheckpointRange::from_predicates(column, predicates);
+        let latest = fetch_latest_checkpoint(self).await?;
+
+        Ok((range.resolve(latest, self.scan_depth), handled))
+    }
+
+    fn checkpoints(
+        &self,
+        range: RangeInclusive<u64>,
+    ) -> impl Stream<Item = Result<JsonValue>> + '_ {
+        stream::iter(range)
+            .then(move |sequence_number| fetch_checkpoint(self, sequence_number))
+            .try_filter_map(|checkpoint| async move { Ok(checkpoint) })
+    }
+
+    /// Transactions of the checkpoints in `range`, in checkpoint order.
+    fn transactions(
+        &self,
+        range: RangeInclusive<u64>,
+    ) -> impl Stream<Item = Result<JsonValue>> + '_ {
+        self.checkpoints(range)
+            .and_then(move |checkpoint| async move {
+                let digests = checkpoint["transactions"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|digest| digest.as_str().map(ToOwned::to_owned))
+                    .collect::<Vec<_>>();
+
+                fetch_transactions(self, &digests).await
+            })
+            .map_ok(|transactions| stream::iter(transactions.into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+/// Value of the first equality on `column` with a string, used to look rows up rather
+/// than to scan them.
+fn lookup<'a>(column: &str, predicates: &'a [Predicate]) -> Option<&'a str> {
+    predicates.iter().find_map(|predicate| match predicate {
+        Predicate {
+            column: c,
+            op: IndexOperator::Eq,
+            value: Value::Str(value),
+        } if c == column => Some(value.as_str()),
+        _ => None,
+    })
+}
+
+fn object_key(object: &JsonValue) -> Key {
+    Key::Str(object["objectId"].as_str().unwrap_or_default().to_owned())
+}
+
+fn transaction_key(transaction: &JsonValue) -> Key {
+    Key::Str(
+        transaction["digest"]
+            .as_str()
+            .unwrap_or_default()
+            .to_owned(),
+    )
+}
+
+fn event_rows(
+    events: &JsonValue,
+    checkpoint: &JsonValue,
+) -> impl Iterator<Item = Result<(Key, DataRow)>> {
+    let rows = events
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|event| {
+            let key = Key::Str(format!(
+                "{}:{}",
+                event["id"]["txDigest"].as_str().unwrap_or_default(),
+                u64_of(&event["id"]["eventSeq"]).unwrap_or_default()
+            ));
+
+            Ok((key, DataRow::Map(event_row(event, checkpoint))))
+        })
+        .collect::<Vec<_>>();
+
+    rows.into_iter()
+}
+
+#[async_trait(?Send)]
+impl Store for SuiStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema = Entity::from_table_name(self.entity_name(table_name))
+            .map(|entity| self.entity_schema(entity));
+
+        Ok(schema)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let schemas = Entity::ALL
+            .into_iter()
+            .map(|entity| self.entity_schema(entity))
+            .collect();
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let table_name = self.entity_name(table_name);
+        let row = match (Self::entity(table_name)?, key) {
+            (Entity::Objects, Key::Str(object_id)) => fetch_object(self, object_id)
+                .await?
+                .map(|object| object_row(&object)),
+            (Entity::Transactions, Key::Str(digest)) => fetch_transaction(self, digest)
+                .await?
+                .map(|transaction| transaction_row(&transaction)),
+            (Entity::Checkpoints, Key::U64(sequence_number)) => {
+                fetch_checkpoint(self, *sequence_number)
+                    .await?
+                    .map(|checkpoint| checkpoint_row(&checkpoint))
+            }
+            (Entity::Checkpoints, Key::I64(sequence_number)) if *sequence_number >= 0 => {
+                fetch_checkpoint(self, *sequence_number as u64)
+                    .await?
+                    .map(|checkpoint| checkpoint_row(&checkpoint))
+            }
+            _ => return Err(Self::unsupported_key(table_name, key).into()),
+        };
+
+        Ok(row.map(DataRow::Map))
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        self.scan_data_with_predicates(table_name, &[])
+            .await
+            .map(|(rows, _)| rows)
+    }
+
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let table_name = self.entity_name(table_name);
+        let entity = Self::entity(table_name)?;
+        let missing_predicate = |columns: &str| {
+            SuiStorageError::MissingPredicate(table_name.to_owned(), columns.to_owned())
+        };
+
+        let (rows, handled): (RowIter, _) = match entity {
+            Entity::Accounts => {
+                let address = lookup("address", predicates)
+                    .ok_or_else(|| missing_predicate("address"))?
+                    .to_owned();
+                let rows = fetch_balances(self, &address)
+                    .await?
+                    .into_iter()
+                    .map(move |balance| {
+                        let key = Key::Str(format!(
+                            "{address}:{}",
+                            balance["coinType"].as_str().unwrap_or_default()
+                        ));
+
+                        Ok((key, DataRow::Map(account_row(&address, &balance))))
+                    });
+
+                (Box::pin(stream::iter(rows)), vec![false; predicates.len()])
+            }
+            Entity::Objects => {
+                let objects = match (lookup("object_id", predicates), lookup("owner", predicates)) {
+                    (Some(object_id), _) => {
+                        fetch_object(self, object_id).await?.into_iter().collect()
+                    }
+                    (None, Some(owner)) => fetch_owned_objects(self, owner).await?,
+                    (None, None) => return Err(missing_predicate("object_id or owner").into()),
+                };
+                let rows = objects
+                    .into_iter()
+                    .map(|object| Ok((object_key(&object), DataRow::Map(object_row(&object)))));
+
+                (Box::pin(stream::iter(rows)), vec![false; predicates.len()])
+            }
+            Entity::DynamicFields => {
+                let parent = lookup("parent", predicates)
+                    .ok_or_else(|| missing_predicate("parent"))?
+                    .to_owned();
+                let fields = fetch_dynamic_fields(self, &parent).await?;
+                let object_ids = fields
+                    .iter()
+                    .filter_map(|field| field["objectId"].as_str().map(ToOwned::to_owned))
+                    .collect::<Vec<_>>();
+                let objects = fetch_objects(self, &object_ids).await?;
+                let rows = fields.into_iter().zip(objects).map(move |(field, object)| {
+                    let row = dynamic_field_row(&parent, &field, object.as_ref());
+
+                    Ok((object_key(&field), DataRow::Map(row)))
+                });
+
+                (Box::pin(stream::iter(rows)), vec![false; predicates.len()])
+            }
+            Entity::Checkpoints => {
+                let (range, handled) = self.checkpoint_range("sequence_number", predicates).await?;
+                let rows = self.checkpoints(range).map_ok(|checkpoint| {
+                    let key = Key::U64(u64_of(&checkpoint["sequenceNumber"]).unwrap_or_default());
+
+                    (key, DataRow::Map(checkpoint_row(&checkpoint)))
+                });
+
+                (Box::pin(rows), handled)
+            }
+            Entity::Transactions => match lookup("digest", predicates) {
+                Some(digest) => {
+                    let rows =
+                        fetch_transaction(self, digest)
+                            .await?
+                            .into_iter()
+                            .map(|transaction| {
+                                let row = transaction_row(&transaction);
+
+                                Ok((transaction_key(&transaction), DataRow::Map(row)))
+                            });
+
+                    (Box::pin(stream::iter(rows)), vec![false; predicates.len()])
+                }
+                None => {
+                    let (range, handled) = self.checkpoint_range("checkpoint", predicates).await?;
+                    let rows = self.transactions(range).map_ok(|transaction| {
+                        let row = transaction_row(&transaction);
+
+                        (transaction_key(&transaction), DataRow::Map(row))
+                    });
+
+                    (Box::pin(rows), handled)
+                }
+            },
+            Entity::Events => match lookup("transaction_digest", predicates) {
+                Some(digest) => {
+                    let events = JsonValue::Array(fetch_events(self, digest).await?);
+                    let rows = event_rows(&events, &JsonValue::Null);
+
+                    (Box::pin(stream::iter(rows)), vec![false; predicates.len()])
+                }
+                None => {
+                    let (range, handled) = self.checkpoint_range("checkpoint", predicates).await?;
+                    let rows = self
+                        .transactions(range)
+                        .map_ok(|transaction| {
+                            stream::iter(event_rows(
+                                &transaction["events"],
+                                &transaction["checkpoint"],
+                            ))
+                        })
+                        .try_flatten();
+
+                    (Box::pin(rows), handled)
+                }
+            },
+        };
+
+        Ok((rows, handled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            mock_rpc::{
+                checkpoint_digest, checkpoint_json, dynamic_fields_json, event_json,
+                field_object_json, object_id, object_json, serve, transaction_digest,
+                transaction_json, RpcResult, OWNER,
+            },
+            SuiStorage,
+        },
+        futures::TryStreamExt,
+        serde_json::{json, Value as JsonValue},
+        wql_core::{
+            ast::IndexOperator,
+            data::{Key, Value},
+            executor::Payload,
+            prelude::Worm,
+            store::{DataRow, Predicate, Store},
+        },
+    };
+
+    const TRANSACTIONS_PER_CHECKPOINT: u64 = 2;
+    const OBJECTS: u64 = 3;
+
+    fn requested_transaction(digest: &JsonValue) -> Option<(u64, u64)> {
+        let (checkpoint, index) = digest.as_str()?.strip_prefix("tx")?.split_once('x')?;
+        let (checkpoint, index) = (checkpoint.parse().ok()?, index.parse().ok()?);
+
+        (checkpoint <= 100 && index < TRANSACTIONS_PER_CHECKPOINT).then_some((checkpoint, index))
+    }
+
+    fn transaction(digest: &JsonValue) -> Option<JsonValue> {
+        requested_transaction(digest)
+            .map(|(checkpoint, index)| transaction_json(checkpoint, index, index + 1))
+    }
+
+    fn rpc(method: &str, params: &JsonValue) -> RpcResult {
+        match method {
+            "sui_getLatestCheckpointSequenceNumber" => Ok(json!("100")),
+            "sui_getCheckpoint" => match params[0].as_str().and_then(|s| s.parse().ok()) {
+                Some(sequence_number) if sequence_number <= 100 => Ok(checkpoint_json(
+                    sequence_number,
+                    TRANSACTIONS_PER_CHECKPOINT,
+                )),
+                _ => Err((
+                    -32602,
+                    "could not find the referenced checkpoint".to_owned(),
+                )),
+            },
+            "sui_getTransactionBlock" => {
+                transaction(&params[0]).ok_or((-32602, "could not find transaction".to_owned()))
+            }
+            "sui_multiGetTransactionBlocks" => Ok(params[0]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter_map(transaction)
+                .collect()),
+            "sui_getEvents" => Ok(requested_transaction(&params[0])
+                .map(|(_, index)| {
+                    let digest = params[0].as_str().unwrap();
+
+                    (0..=index).map(|seq| event_json(digest, seq)).collect()
+                })
+                .unwrap_or(json!([]))),
+            "suix_getAllBalances" if params[0] == json!(OWNER) => Ok(json!([
+                {
+                    "coinType": "0x2::sui::SUI",
+                    "coinObjectCount": 3,
+                    "totalBalance": "3000",
+                    "lockedBalance": {},
+                },
+                {
+                    "coinType": "0xdba3::usdc::USDC",
+                    "coinObjectCount": 1,
+                    "totalBalance": "250000",
+                    "lockedBalance": {},
+                },
+            ])),
+            "suix_getAllBalances" => Ok(json!([])),
+            "sui_getObject" => Ok((0..OBJECTS)
+                .find(|index| params[0] == json!(object_id(*index)))
+                .map(|index| json!({ "data": object_json(index) }))
+                .unwrap_or_else(
+                    || json!({ "error": { "code": "notExists", "object_id": params[0] } }),
+                )),
+            "sui_multiGetObjects" => Ok(params[0]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(
+                    |id| match (0..OBJECTS).find(|index| *id == json!(object_id(*index))) {
+                        Some(index) => json!({ "data": object_json(index) }),
+                        None if *id == json!(object_id(10)) => {
+                            json!({ "data": field_object_json() })
+                        }
+                        None => json!({ "error": { "code": "notExists", "object_id": id } }),
+                    },
+                )
+                .collect()),
+            "suix_getDynamicFields" if params[0] == json!(object_id(0)) => {
+                let [position, vault] = dynamic_fields_json();
+
+                match &params[1] {
+                    JsonValue::Null => Ok(json!({
+                        "data": [position],
+                        "nextCursor": object_id(10),
+                        "hasNextPage": true,
+                    })),
+                    _ => Ok(json!({ "data": [vault], "nextCursor": null, "hasNextPage": false })),
+                }
+            }
+            "suix_getOwnedObjects" => {
+                let page = |objects: std::ops::Range<u64>, next_cursor: JsonValue| {
+                    json!({
+                        "data": objects
+                            .map(|index| json!({ "data": object_json(index) }))
+                            .collect::<Vec<_>>(),
+                        "nextCursor": next_cursor,
+                        "hasNextPage": !next_cursor.is_null(),
+                    })
+                };
+
+                match &params[2] {
+                    JsonValue::Null => Ok(page(0..2, json!(object_id(1)))),
+                    _ => Ok(page(2..OBJECTS, JsonValue::Null)),
+                }
+            }
+            _ => Err((-32601, format!("method not found: {method}"))),
+        }
+    }
+
+    fn storage() -> SuiStorage {
+        SuiStorage::new(&serve(rpc)).unwrap().with_scan_depth(3)
+    }
+
+    #[tokio::test]
+    async fn scan_checkpoints() {
+        let storage = storage();
+
+        let rows = storage
+            .scan_data("checkpoints")
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let keys = rows.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        assert_eq!(keys, vec![Key::U64(98), Key::U64(99), Key::U64(100)]);
+
+        let DataRow::Map(row) = &rows[2].1 else {
+            panic!("map row expected");
+        };
+        assert_eq!(row.get("sequence_number"), Some(&Value::U64(100)));
+        assert_eq!(row.get("digest"), Some(&Value::Str(checkpoint_digest(100))));
+        assert_eq!(
+            row.get("previous_digest"),
+            Some(&Value::Str(checkpoint_digest(99)))
+        );
+        assert_eq!(row.get("epoch"), Some(&Value::U64(500)));
+        assert_eq!(row.get("transaction_count"), Some(&Value::U64(2)));
+        assert!(matches!(row.get("timestamp"), Some(Value::Timestamp(_))));
+
+        let row = storage
+            .fetch_data("checkpoints", &Key::I64(42))
+            .await
+            .unwrap();
+        assert!(matches!(
+            row,
+            Some(DataRow::Map(values)) if values.get("sequence_number") == Some(&Value::U64(42))
+        ));
+        assert!(storage.scan_data("validators").await.is_err());
+        assert_eq!(storage.fetch_schema("validators").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn select_accounts() {
+        let mut worm = Worm::new(storage());
+
+        let sql =
+            format!("SELECT coin_type, total_balance FROM sui.accounts WHERE address = '{OWNER}'");
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec!["coin_type".to_owned(), "total_balance".to_owned()],
+            rows: vec![
+                vec![Value::Str("0x2::sui::SUI".to_owned()), Value::U128(3_000)],
+                vec![
+                    Value::Str("0xdba3::usdc::USDC".to_owned()),
+                    Value::U128(250_000),
+                ],
+            ],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        assert!(worm.execute("SELECT * FROM sui.accounts").await.is_err());
+
+        let sql = format!(
+            "SELECT COUNT(*) FROM sui.accounts a JOIN sui.checkpoints c WHERE a.address = '{OWNER}'"
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec!["COUNT(*)".to_owned()],
+            rows: vec![vec![Value::I64(6)]],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let sql = format!(
+            "SELECT COUNT(*) FROM sui.accounts a JOIN sui.checkpoints c WHERE address = '{OWNER}'"
+        );
+        assert!(worm.execute(&sql).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn select_objects() {
+        let mut worm = Worm::new(storage());
+
+        let sql = format!("SELECT object_id, owner FROM sui.objects WHERE owner = '{OWNER}'");
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec!["object_id".to_owned(), "owner".to_owned()],
+            rows: (0..OBJECTS)
+                .map(|index| vec![Value::Str(object_id(index)), Value::Str(OWNER.to_owned())])
+                .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let sql = format!(
+            "SELECT version, type, fields['balance'] FROM sui.objects WHERE object_id = '{}'",
+            object_id(1)
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "version".to_owned(),
+                "type".to_owned(),
+                "fields['balance']".to_owned(),
+            ],
+            rows: vec![vec![
+                Value::U64(42),
+                Value::Str("0x2::coin::Coin<0x2::sui::SUI>".to_owned()),
+                Value::Str("1000".to_owned()),
+            ]],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let storage = storage();
+        let missing = Key::Str(object_id(OBJECTS));
+        assert_eq!(storage.fetch_data("objects", &missing).await.unwrap(), None);
+        assert!(storage.scan_data("objects").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn select_transactions() {
+        let mut worm = Worm::new(storage());
+
+        let payloads = worm
+            .execute("SELECT digest, status, sender FROM sui.transactions WHERE checkpoint = 99")
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "digest".to_owned(),
+                "status".to_owned(),
+                "sender".to_owned(),
+            ],
+            rows: vec![
+                vec![
+                    Value::Str(transaction_digest(99, 0)),
+                    Value::Bool(true),
+                    Value::Str(OWNER.to_owned()),
+                ],
+                vec![
+                    Value::Str(transaction_digest(99, 1)),
+                    Value::Bool(false),
+                    Value::Str(OWNER.to_owned()),
+                ],
+            ],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let sql = format!(
+            "SELECT checkpoint, storage_rebate FROM sui.transactions WHERE digest = '{}'",
+            transaction_digest(42, 1)
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec!["checkpoint".to_owned(), "storage_rebate".to_owned()],
+            rows: vec![vec![Value::U64(42), Value::U64(978_120)]],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let payloads = worm
+            .execute("SELECT * FROM sui.transactions")
+            .await
+            .unwrap();
+        let Payload::Select { labels, rows } = &payloads[0] else {
+            panic!("select payload expected");
+        };
+        assert_eq!(labels.len(), 8);
+        assert_eq!(rows.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn select_events() {
+        let mut worm = Worm::new(storage());
+
+        let payloads = worm
+            .execute(
+                "SELECT transaction_digest, event_seq, checkpoint FROM sui.events
+                 WHERE checkpoint BETWEEN 99 AND 100",
+            )
+            .await
+            .unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "transaction_digest".to_owned(),
+                "event_seq".to_owned(),
+                "checkpoint".to_owned(),
+            ],
+            rows: [
+                (99, 0, 0),
+                (99, 1, 0),
+                (99, 1, 1),
+                (100, 0, 0),
+                (100, 1, 0),
+                (100, 1, 1),
+            ]
+            .into_iter()
+            .map(|(checkpoint, index, seq)| {
+                vec![
+                    Value::Str(transaction_digest(checkpoint, index)),
+                    Value::U64(seq),
+                    Value::U64(checkpoint),
+                ]
+            })
+            .collect(),
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let sql = format!(
+            "SELECT event_seq, parsed_json['amount'], checkpoint FROM sui.events
+             WHERE transaction_digest = '{}'",
+            transaction_digest(42, 0)
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "event_seq".to_owned(),
+                "parsed_json['amount']".to_owned(),
+                "checkpoint".to_owned(),
+            ],
+            rows: vec![vec![
+                Value::U64(0),
+                Value::Str("1000000000".to_owned()),
+                Value::Null,
+            ]],
+        };
+        assert_eq!(payloads, vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn select_dynamic_fields() {
+        let mut worm = Worm::new(storage());
+
+        let sql = format!(
+            "SELECT name, kind, value['liquidity'] FROM sui.dynamic_fields
+             WHERE parent = '{}'",
+            object_id(0)
+        );
+        let payloads = worm.execute(&sql).await.unwrap();
+        let expected = Payload::Select {
+            labels: vec![
+                "name".to_owned(),
+                "kind".to_owned(),
+                "value['liquidity']".to_owned(),
+            ],
+            rows: vec![
+                vec![
+                    Value::Str("7".to_owned()),
+                    Value::Str("DynamicField".to_owned()),
+                    Value::Str("500".to_owned()),
+                ],
+                vec![
+                    Value::Str("vault".to_owned()),
+                    Value::Str("DynamicObject".to_owned()),
+                    Value::Null,
+                ],
+            ],
+        };
+        assert_eq!(payloads, vec![expected]);
+
+        let storage = storage();
+        let rows = storage
+            .scan_data_with_predicates(
+                "dynamic_fields",
+                &[Predicate {
+                    column: "parent".to_owned(),
+                    op: IndexOperator::Eq,
+                    value: Value::Str(object_id(0)),
+                }],
+            )
+            .await
+            .unwrap()
+            .0
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let value = |i: usize| match &rows[i].1 {
+            DataRow::Map(row) => match row.get("value") {
+                Some(Value::Map(value)) => value.clone(),
+                _ => panic!("map value expected"),
+            },
+            _ => panic!("map row expected"),
+        };
+        assert_eq!(
+            value(0).get("ticks"),
+            Some(&Value::List(vec![
+                Value::Str("-10".to_owned()),
+                Value::Str("10".to_owned()),
+            ]))
+        );
+
+        let value = value(1);
+        assert_eq!(value.get("balance"), Some(&Value::Str("1000".to_owned())));
+        assert_eq!(
+            value.get("id"),
+            Some(&Value::Map(
+                [("id".to_owned(), Value::Str(object_id(2)))].into()
+            ))
+        );
+
+        assert!(storage.scan_data("dynamic_fields").await.is_err());
+    }
+}
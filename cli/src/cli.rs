@@ -14,8 +14,10 @@ use {
         path::Path,
     },
     wql_core::{
-        prelude::Worm,
+        parse_sql::parse_statements,
+        prelude::{plan, Worm},
         store::{GStore, GStoreMut},
+        translate::translate_parsed,
     },
 };
 
@@ -175,9 +177,25 @@ where
     pub fn load<P: AsRef<Path>>(&mut self, filename: P) -> Result<()> {
         let mut sqls = String::new();
         File::open(filename)?.read_to_string(&mut sqls)?;
-        for sql in sqls.split(';').filter(|sql| !sql.trim().is_empty()) {
-            match block_on(self.worm.execute(sql)) {
-                Ok(payloads) => self.print.payloads(&payloads)?,
+
+        let statements = match parse_statements(&sqls) {
+            Ok(statements) => statements,
+            Err(e) => {
+                println!("[error] {}\n", e);
+
+                return Ok(());
+            }
+        };
+
+        for statement in statements {
+            let result = block_on(async {
+                let statement = plan(&self.worm.storage, translate_parsed(&statement)?).await?;
+
+                self.worm.execute_stmt(&statement).await
+            });
+
+            match result {
+                Ok(payload) => self.print.payload(&payload)?,
                 Err(e) => {
                     println!("[error] {}\n", e);
                     break;
@@ -188,3 +206,53 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Cli,
+        crate::dump_database,
+        futures::executor::block_on,
+        std::fs,
+        wql_core::{data::Value, prelude::Payload},
+        wql_memory_storage::MemoryStorage,
+    };
+
+    #[test]
+    fn load_dump() {
+        let mut cli = Cli::new(MemoryStorage::default(), Vec::new());
+        block_on(cli.worm.execute(
+            "
+            CREATE TABLE Log (id INT, data TEXT);
+            INSERT INTO Log VALUES (1, 'a;b'), (2, '; DROP TABLE Log;');
+            CREATE VIEW Ids AS SELECT id FROM Log;
+            CREATE MATERIALIZED VIEW Data AS SELECT data FROM Log;
+            ",
+        ))
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let dump_path = dir.path().join("dump.sql");
+        dump_database(&mut cli.worm.storage, dump_path.clone()).unwrap();
+
+        let mut loaded = Cli::new(MemoryStorage::default(), Vec::new());
+        loaded.load(&dump_path).unwrap();
+
+        let reloaded_path = dir.path().join("reloaded.sql");
+        dump_database(&mut loaded.worm.storage, reloaded_path.clone()).unwrap();
+        assert_eq!(
+            fs::read_to_string(dump_path).unwrap(),
+            fs::read_to_string(reloaded_path).unwrap()
+        );
+
+        let payloads = block_on(loaded.worm.execute("SELECT data FROM Data")).unwrap();
+        let expected = Payload::Select {
+            labels: vec!["data".to_owned()],
+            rows: vec![
+                vec![Value::Str("a;b".to_owned())],
+                vec![Value::Str("; DROP TABLE Log;".to_owned())],
+            ],
+        };
+        assert_eq!(payloads, vec![expected]);
+    }
+}
@@ -7,25 +7,36 @@ mod print;
 
 use {
     crate::cli::Cli,
-    anyhow::Result,
+    anyhow::{anyhow, bail, Result},
     clap::Parser,
     futures::{
         executor::block_on,
         stream::{StreamExt, TryStreamExt},
     },
-    std::{
-        fmt::Debug,
-        fs::File,
-        io::Write,
-        path::{Path, PathBuf},
-    },
+    std::{collections::HashSet, fmt::Debug, fs::File, io::Write, path::PathBuf},
+    strum_macros::Display,
     wql_core::{
-        ast::{Expr, SetExpr, Statement, ToSql, Values},
-        data::Value,
-        store::{DataRow, GStore, GStoreMut, Store, Transaction},
+        ast::{Expr, Query, SetExpr, Statement, ToSql, Values},
+        data::{Schema, SchemaView, Value},
+        error::Result as CoreResult,
+        plan::fetch_schema_map,
+        store::{DataRow, GStore, GStoreMut, MultiChainStorage, Store, Transaction},
     },
+    wql_csv_storage::CsvStorage,
+    wql_evm_adapter::EvmStorage,
+    wql_json_storage::JsonStorage,
+    wql_memory_storage::MemoryStorage,
+    wql_parquet_storage::ParquetStorage,
+    wql_sled_storage::SledStorage,
+    wql_sui_adapter::SuiStorage,
 };
 
+/// Chain short codes served by the Sui adapter, every other chain being served by the EVM adapter.
+const SUI_CHAINS: [&str; 1] = ["sui"];
+
+/// Rows per `INSERT` statement of a dump.
+const INSERT_CHUNK_SIZE: usize = 100;
+
 #[derive(Parser, Debug)]
 #[clap(name = "sand-worm-cli", about = "Sand Worm SQL CLI", version = "1.0")]
 struct Args {
@@ -44,9 +55,19 @@ struct Args {
     /// Storage path to load
     #[clap(short, long, value_parser)]
     path: Option<PathBuf>,
+
+    /// Short code of a chain to attach, as in `eth.blocks`, paired with the --rpc at the same
+    /// position
+    #[clap(short, long, value_parser)]
+    chain: Vec<String>,
+
+    /// JSON-RPC endpoint of the --chain at the same position
+    #[clap(short, long, value_parser)]
+    rpc: Vec<String>,
 }
 
-#[derive(clap::ValueEnum, Debug, Clone)]
+#[derive(clap::ValueEnum, Debug, Clone, Display)]
+#[strum(serialize_all = "lowercase")]
 enum Storage {
     Memory,
     Sled,
@@ -58,32 +79,108 @@ enum Storage {
 
 pub fn run() -> Result<()> {
     let args = Args::parse();
-    let path = args.path.as_deref().and_then(Path::to_str);
-
-    // match (path, args.storage, args.dump) {
-    //     (None, None, _) | (None, Some(Storage::Memory), _) => {
-    //         println!("[memory-storage] initialized");
-    //         run_cli(WormStorage::memory(), args.execute);
-    //     }
-    //     (Some(_), Some(Storage::Memory), _) => {
-    //         panic!("failed to load memory-storage: it should be without path");
-    //     }
-    //     (Some(path), Some(Storage::File), _) => {
-    //         println!("[file-storage] connected to {}", path);
-    //         run_cli(WormStorage::file(path).expect("failed to load file-storage"), args.execute);
-    //     }
-    //     (Some(path), None, Some(dump_path)) => {
-    //         let mut storage = WormStorage::file(path).expect("failed to load file-storage");
-    //         dump_database(&mut storage, dump_path)?;
-    //     }
-    //     (None, Some(_), _) | (Some(_), None, None) => {
-    //         panic!("both path and storage should be specified");
-    //     }
-    // }
+    let path = args
+        .path
+        .as_deref()
+        .map(|path| {
+            path.to_str()
+                .ok_or_else(|| anyhow!("--path should be valid UTF-8: {}", path.display()))
+        })
+        .transpose()?;
+
+    if args.chain.len() != args.rpc.len() {
+        bail!("each --chain should be given along with its --rpc");
+    }
+
+    if args.dump.is_some() && path.is_none() {
+        bail!("--dump requires a --storage and a --path to read the database from");
+    }
+
+    let chains = args.chain.into_iter().zip(args.rpc).collect::<Vec<_>>();
+    let (execute, dump) = (args.execute, args.dump);
+
+    // the chain adapters send their requests on the tokio runtime
+    let runtime = tokio::runtime::Runtime::new()?;
+    let _guard = runtime.enter();
+
+    match (args.storage, path) {
+        (None | Some(Storage::Memory), None) => {
+            println!("[memory-storage] initialized");
+            start(MemoryStorage::default(), chains, execute, dump)?;
+        }
+        (Some(Storage::Memory), Some(_)) => {
+            bail!("memory storage does not take a --path");
+        }
+        (Some(Storage::Sled), Some(path)) => {
+            println!("[sled-storage] connected to {path}");
+            start(SledStorage::new(path)?, chains, execute, dump)?;
+        }
+        (Some(Storage::Json), Some(path)) => {
+            println!("[json-storage] connected to {path}");
+            start(JsonStorage::new(path)?, chains, execute, dump)?;
+        }
+        (Some(Storage::Csv), Some(path)) => {
+            println!("[csv-storage] connected to {path}");
+            start(CsvStorage::new(path)?, chains, execute, dump)?;
+        }
+        (Some(Storage::Parquet), Some(path)) => {
+            println!("[parquet-storage] connected to {path}");
+            start(ParquetStorage::new(path)?, chains, execute, dump)?;
+        }
+        (Some(Storage::File), _) => {
+            bail!("file storage is not supported, use json, csv or sled storage instead");
+        }
+        (Some(storage), None) => {
+            bail!("{storage} storage requires a --path");
+        }
+        (None, Some(_)) => {
+            bail!("--path requires a --storage");
+        }
+    }
 
     Ok(())
 }
 
+/// Dumps `storage` into `dump` when given, otherwise serves it along with `chains` to the file
+/// to `execute` or to the REPL.
+fn start<T: GStore + GStoreMut>(
+    mut storage: T,
+    chains: Vec<(String, String)>,
+    execute: Option<PathBuf>,
+    dump: Option<PathBuf>,
+) -> Result<()> {
+    if let Some(dump_path) = dump {
+        dump_database(&mut storage, dump_path)?;
+        println!("[dump] done");
+
+        return Ok(());
+    }
+
+    run_cli(attach_chains(storage, chains)?, execute);
+
+    Ok(())
+}
+
+/// Serves the tables of `chains`, given as `(short code, JSON-RPC endpoint)`, next to those of
+/// `storage`.
+fn attach_chains<T: GStore + GStoreMut>(
+    storage: T,
+    chains: Vec<(String, String)>,
+) -> Result<MultiChainStorage<T>> {
+    let mut storage = MultiChainStorage::new(storage);
+
+    for (chain, rpc_url) in chains {
+        storage = match SUI_CHAINS.contains(&chain.to_lowercase().as_str()) {
+            true => storage.with_chain(&chain, SuiStorage::new(&rpc_url)?.with_chain(&chain)),
+            false => storage.with_chain(&chain, EvmStorage::new(&rpc_url)?.with_chain(&chain)),
+        };
+
+        println!("[{chain}] attached to {rpc_url}");
+    }
+
+    Ok(storage)
+}
+
 fn run_cli<T: GStore + GStoreMut>(storage: T, input: Option<PathBuf>) {
     let output = std::io::stdout();
     let mut cli = Cli::new(storage, output);
@@ -92,6 +189,8 @@ fn run_cli<T: GStore + GStoreMut>(storage: T, input: Option<PathBuf>) {
         if let Err(e) = cli.load(path.as_path()) {
             println!("[error] {}\n", e);
         }
+
+        return;
     }
 
     if let Err(e) = cli.run() {
@@ -99,57 +198,260 @@ fn run_cli<T: GStore + GStoreMut>(storage: T, input: Option<PathBuf>) {
     }
 }
 
+/// Writes the schemas and rows of `storage` to `dump_path` as SQL statements which rebuild the
+/// database once run with `--execute`.
 pub fn dump_database<T: Store + Transaction>(storage: &mut T, dump_path: PathBuf) -> Result<()> {
-    let file = File::create(dump_path)?;
-
-    // block_on(async {
-    //     storage.begin(true).await?;
-    //     let schemas = storage.fetch_all_schemas().await?;
-    //     for schema in schemas {
-    //         writeln!(&file, "{}", schema.to_ddl())?;
-
-    //         let mut rows_list = storage
-    //             .scan_data(&schema.table_name)
-    //             .await?
-    //             .map_ok(|(_, row)| row)
-    //             .chunks(100);
-
-    //         while let Some(rows) = rows_list.next().await {
-    //             let exprs_list = rows
-    //                 .into_iter()
-    //                 .map(|result| {
-    //                     result.map(|data_row| {
-    //                         let values = match data_row {
-    //                             DataRow::Vec(values) => values,
-    //                             DataRow::Map(values) => vec![Value::Map(values)],
-    //                         };
-
-    //                         values
-    //                             .into_iter()
-    //                             .map(|value| Ok(Expr::try_from(value)?))
-    //                             .collect::<Result<Vec<_>>>()
-    //                     })?
-    //                 })
-    //                 .collect::<Result<Vec<_>, _>>()?;
-
-    //             let insert_statement = Statement::Insert {
-    //                 table_name: schema.table_name.clone(),
-    //                 columns: Vec::new(),
-    //                 source: worm_sql::ast::Query {
-    //                     body: SetExpr::Values(Values(exprs_list)),
-    //                     order_by: Vec::new(),
-    //                     limit: None,
-    //                     offset: None,
-    //                 },
-    //             }
-    //             .to_sql();
-
-    //             writeln!(&file, "{}", insert_statement)?;
-    //         }
-
-    //         writeln!(&file)?;
-    //     }
+    let mut file = File::create(dump_path)?;
+
+    block_on(async {
+        let autocommit = storage.begin(true).await?;
+        let result = dump_schemas(storage, &mut file).await;
+
+        if !autocommit {
+            return result;
+        }
+
+        match result {
+            Ok(()) => Ok(storage.commit().await?),
+            Err(error) => {
+                storage.rollback().await?;
+
+                Err(error)
+            }
+        }
+    })
+}
+
+/// Writes the tables of `storage` along with their rows, then its views, which may read them.
+async fn dump_schemas<T: Store>(storage: &T, file: &mut File) -> Result<()> {
+    let (views, tables): (Vec<_>, Vec<_>) = storage
+        .fetch_all_schemas()
+        .await?
+        .into_iter()
+        .partition(|schema| schema.view.is_some());
+
+    for schema in tables {
+        writeln!(file, "{}", schema.to_ddl())?;
+
+        let mut rows_list = storage
+            .scan_data(&schema.table_name)
+            .await?
+            .map_ok(|(_, row)| row)
+            .chunks(INSERT_CHUNK_SIZE);
+
+        while let Some(rows) = rows_list.next().await {
+            let exprs_list = rows
+                .into_iter()
+                .map(|row| {
+                    let values = match row? {
+                        DataRow::Vec(values) => values,
+                        DataRow::Map(values) => vec![Value::Map(values)],
+                    };
+
+                    values
+                        .into_iter()
+                        .map(Expr::try_from)
+                        .collect::<CoreResult<Vec<_>>>()
+                })
+                .collect::<CoreResult<Vec<_>>>()?;
+
+            let insert_statement = Statement::Insert {
+                table_name: schema.table_name.clone(),
+                columns: Vec::new(),
+                source: Query {
+                    body: SetExpr::Values(Values(exprs_list)),
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                },
+            }
+            .to_sql();
+
+            writeln!(file, "{insert_statement}")?;
+        }
+
+        writeln!(file)?;
+    }
+
+    let views = sort_views(storage, views).await?;
+    for schema in &views {
+        let Some(SchemaView {
+            query,
+            materialized,
+        }) = &schema.view
+        else {
+            continue;
+        };
+
+        let create_view = Statement::CreateView {
+            or_replace: false,
+            materialized: *materialized,
+            name: schema.table_name.clone(),
+            query: Box::new(query.clone()),
+        }
+        .to_sql();
+
+        writeln!(file, "{create_view}")?;
+    }
+
+    if !views.is_empty() {
+        writeln!(file)?;
+    }
 
     Ok(())
-    //})
+}
+
+/// Orders `views` so that each comes after the views its query reads, which it needs once
+/// replayed.
+///
+/// Views reading each other, which no statement creates, are left in the order they are.
+async fn sort_views<T: Store>(storage: &T, views: Vec<Schema>) -> Result<Vec<Schema>> {
+    let names = views
+        .iter()
+        .map(|schema| schema.table_name.clone())
+        .collect::<HashSet<_>>();
+
+    let mut pending = Vec::new();
+    for schema in views {
+        let dependencies = match &schema.view {
+            Some(SchemaView { query, .. }) => {
+                fetch_schema_map(storage, &Statement::Query(query.clone()))
+                    .await?
+                    .into_keys()
+                    .filter(|name| names.contains(name) && name != &schema.table_name)
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        pending.push((schema, dependencies));
+    }
+
+    let mut sorted = Vec::new();
+    let mut sorted_names = HashSet::new();
+    while !pending.is_empty() {
+        let (ready, rest): (Vec<_>, Vec<_>) = pending.into_iter().partition(|(_, dependencies)| {
+            dependencies.iter().all(|name| sorted_names.contains(name))
+        });
+
+        if ready.is_empty() {
+            sorted.extend(rest.into_iter().map(|(schema, _)| schema));
+            break;
+        }
+
+        for (schema, _) in ready {
+            sorted_names.insert(schema.table_name.clone());
+            sorted.push(schema);
+        }
+
+        pending = rest;
+    }
+
+    Ok(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::dump_database,
+        futures::executor::block_on,
+        std::fs,
+        wql_core::{
+            ast::{ColumnDef, DataType, Expr, OrderByExpr},
+            data::{Schema, SchemaView, Value},
+            parse_sql::parse_query,
+            store::{DataRow, IndexMut, StoreMut},
+            translate::translate_query,
+        },
+        wql_memory_storage::MemoryStorage,
+    };
+
+    #[test]
+    fn dump() {
+        let mut storage = MemoryStorage::default();
+        let column_def = |name: &str, data_type| ColumnDef {
+            name: name.to_owned(),
+            data_type,
+            nullable: true,
+            default: None,
+            comment: None,
+        };
+        let schema = Schema {
+            table_name: "Block".to_owned(),
+            column_defs: Some(vec![
+                column_def("number", DataType::Int),
+                column_def("miner", DataType::Text),
+            ]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+        let rows = (0..150)
+            .map(|n| DataRow::Vec(vec![Value::I64(n), Value::Str(format!("0x{n:02x}"))]))
+            .collect();
+        let view = |name: &str, sql: &str, materialized| Schema {
+            table_name: name.to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: Some(SchemaView {
+                query: translate_query(&parse_query(sql).unwrap()).unwrap(),
+                materialized,
+            }),
+        };
+        let number = OrderByExpr {
+            expr: Expr::Identifier("number".to_owned()),
+            asc: None,
+        };
+
+        block_on(async {
+            storage.insert_schema(&schema).await.unwrap();
+            storage
+                .insert_schema(&view("Active", "SELECT miner FROM Miners", false))
+                .await
+                .unwrap();
+            storage
+                .insert_schema(&view("Busy", "SELECT miner FROM Active", false))
+                .await
+                .unwrap();
+            storage
+                .insert_schema(&view("Miners", "SELECT miner FROM Block", true))
+                .await
+                .unwrap();
+            storage.append_data("Block", rows).await.unwrap();
+            storage
+                .create_index("Block", "idx_number", &number)
+                .await
+                .unwrap();
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let dump_path = dir.path().join("dump.sql");
+        dump_database(&mut storage, dump_path.clone()).unwrap();
+
+        let dump = fs::read_to_string(dump_path).unwrap();
+        let lines = dump.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[..2],
+            [
+                r#"CREATE TABLE "Block" ("number" INT NULL, "miner" TEXT NULL);"#,
+                r#"CREATE INDEX "idx_number" ON "Block" ("number");"#,
+            ]
+        );
+        assert!(lines[2].starts_with(r#"INSERT INTO "Block" VALUES (0, '0x00'), (1, '0x01'), "#));
+        assert!(lines[3].starts_with(r#"INSERT INTO "Block" VALUES (100, '0x64'), "#));
+        assert!(lines[3].ends_with("(149, '0x95');"));
+        assert_eq!(
+            lines[4..],
+            [
+                "",
+                r#"CREATE MATERIALIZED VIEW "Miners" AS SELECT "miner" AS "miner" FROM "Block";"#,
+                r#"CREATE VIEW "Active" AS SELECT "miner" AS "miner" FROM "Miners";"#,
+                r#"CREATE VIEW "Busy" AS SELECT "miner" AS "miner" FROM "Active";"#,
+                "",
+            ]
+        );
+    }
 }
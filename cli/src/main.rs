@@ -1,3 +1,6 @@
 fn main() {
-    sand_worm_cli::run().unwrap();
+    if let Err(e) = sand_worm_cli::run() {
+        eprintln!("[error] {e}");
+        std::process::exit(1);
+    }
 }
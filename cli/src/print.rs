@@ -9,7 +9,10 @@ use {
     },
     strum_macros::Display,
     tabled::{builder::Builder, Style, Table},
-    wql_core::prelude::{Payload, PayloadVariable},
+    wql_core::{
+        ast::{ColumnDef, ToSql},
+        prelude::{Analyzed, ExplainRow, Payload, PayloadVariable},
+    },
 };
 
 pub struct Print<W: Write> {
@@ -103,6 +106,8 @@ impl<'a, W: Write> Print<W> {
         enum Target {
             Table,
             Row,
+            Function,
+            View,
         }
         let mut affected = |n: usize, target: Target, msg: &str| -> IOResult<()> {
             let payload = format!("{n} {target}{} {msg}", if n > 1 { "s" } else { "" });
@@ -111,6 +116,21 @@ impl<'a, W: Write> Print<W> {
 
         use Target::*;
         match payload {
+            Payload::Insert(n) => affected(*n, Row, "inserted")?,
+            Payload::Update(n) => affected(*n, Row, "updated")?,
+            Payload::Delete(n) => affected(*n, Row, "deleted")?,
+            Payload::Create => self.writeln("Table created")?,
+            Payload::DropTable(n) => affected(*n, Table, "dropped")?,
+            Payload::CreateIndex => self.writeln("Index created")?,
+            Payload::DropIndex => self.writeln("Index dropped")?,
+            Payload::CreateFunction => self.writeln("Function created")?,
+            Payload::DropFunction(n) => affected(*n, Function, "dropped")?,
+            Payload::CreateView => self.writeln("View created")?,
+            Payload::DropView(n) => affected(*n, View, "dropped")?,
+            Payload::RefreshView(n) => affected(*n, Row, "refreshed")?,
+            Payload::StartTransaction => self.writeln("BEGIN")?,
+            Payload::Commit => self.writeln("COMMIT")?,
+            Payload::Rollback => self.writeln("ROLLBACK")?,
             Payload::ShowVariable(PayloadVariable::Version(v)) => {
                 self.writeln(format!("v{v}"))?;
             }
@@ -130,10 +150,32 @@ impl<'a, W: Write> Print<W> {
                 let table = self.build_table(table);
                 self.writeln(table)?;
             }
+            Payload::Explain(explain_rows) => {
+                let mut table = self.get_table(["QUERY PLAN"]);
+                for explain_row in explain_rows {
+                    table.add_record([explain_line(explain_row)]);
+                }
+                let table = self.build_table(table);
+                self.writeln(table)?;
+            }
             Payload::ShowColumns(columns) => {
-                let mut table = self.get_table(["Field", "Type"]);
-                for (field, field_type) in columns {
-                    table.add_record([field, &field_type.to_string()]);
+                let mut table = self.get_table(["Field", "Type", "Null", "Default", "Comment"]);
+                for column in columns {
+                    let ColumnDef {
+                        name,
+                        data_type,
+                        nullable,
+                        default,
+                        comment,
+                    } = column;
+
+                    table.add_record([
+                        name.to_owned(),
+                        data_type.to_string(),
+                        if *nullable { "YES" } else { "NO" }.to_owned(),
+                        default.as_ref().map(ToSql::to_sql).unwrap_or_default(),
+                        comment.to_owned().unwrap_or_default(),
+                    ]);
                 }
                 let table = self.build_table(table);
                 self.writeln(table)?;
@@ -328,5 +370,29 @@ impl<'a, W: Write> Print<W> {
     }
 }
 
+fn explain_line(explain_row: &ExplainRow) -> String {
+    let ExplainRow {
+        depth,
+        operator,
+        detail,
+        analyzed,
+    } = explain_row;
+
+    let arrow = if *depth > 0 { "-> " } else { "" };
+    let detail = match detail.is_empty() {
+        true => String::new(),
+        false => format!(": {detail}"),
+    };
+    let analyzed = analyzed
+        .map(|Analyzed { rows, elapsed }| {
+            let millis = elapsed.as_secs_f64() * 1000.0;
+
+            format!(" (rows: {rows}, time: {millis:.3} ms)")
+        })
+        .unwrap_or_default();
+
+    format!("{}{arrow}{operator}{detail}{analyzed}", "  ".repeat(*depth))
+}
+
 #[cfg(test)]
 mod tests {}
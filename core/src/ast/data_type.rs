@@ -68,3 +68,24 @@ impl FromStr for DataType {
         }
     }
 }
+
+impl DataType {
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int
+                | DataType::Int128
+                | DataType::Uint8
+                | DataType::Uint16
+                | DataType::Uint32
+                | DataType::Uint64
+                | DataType::Uint128
+                | DataType::Float32
+                | DataType::Float
+                | DataType::Decimal
+        )
+    }
+}
@@ -443,6 +443,7 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -473,6 +474,7 @@ mod tests {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -502,6 +504,7 @@ mod tests {
             Expr::Exists {
                 subquery: Box::new(Query {
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -531,6 +534,7 @@ mod tests {
             Expr::Exists {
                 subquery: Box::new(Query {
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: None,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -559,6 +563,7 @@ mod tests {
             r#"(SELECT * FROM "base.FOO")"#,
             Expr::Subquery(Box::new(Query {
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
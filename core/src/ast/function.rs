@@ -8,6 +8,11 @@ use {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum Function {
+    #[strum(to_string = "{name}")]
+    Custom {
+        name: String,
+        exprs: Vec<Expr>,
+    },
     Abs(Expr),
     AddMonth {
         expr: Expr,
@@ -211,6 +216,15 @@ pub enum Function {
 impl ToSql for Function {
     fn to_sql(&self) -> String {
         match self {
+            Function::Custom { name, exprs } => {
+                let exprs = exprs
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("{name}({exprs})")
+            }
             Function::Abs(e) => format!("ABS({})", e.to_sql()),
             Function::AddMonth { expr, size } => {
                 format!("ADD_MONTH({},{})", expr.to_sql(), size.to_sql())
@@ -485,7 +499,29 @@ impl ToSql for Function {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Aggregate {
+pub struct Aggregate {
+    pub func: AggregateFunction,
+    /// Whether duplicate values are aggregated once, as in `COUNT(DISTINCT expr)`
+    pub distinct: bool,
+}
+
+impl Aggregate {
+    pub fn new(func: AggregateFunction) -> Self {
+        Self {
+            func,
+            distinct: false,
+        }
+    }
+}
+
+impl From<AggregateFunction> for Aggregate {
+    fn from(func: AggregateFunction) -> Self {
+        Self::new(func)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AggregateFunction {
     Count(CountArgExpr),
     Sum(Expr),
     Max(Expr),
@@ -497,14 +533,17 @@ pub enum Aggregate {
 
 impl ToSql for Aggregate {
     fn to_sql(&self) -> String {
-        match self {
-            Aggregate::Count(cae) => format!("COUNT({})", cae.to_sql()),
-            Aggregate::Sum(e) => format!("SUM({})", e.to_sql()),
-            Aggregate::Max(e) => format!("MAX({})", e.to_sql()),
-            Aggregate::Min(e) => format!("MIN({})", e.to_sql()),
-            Aggregate::Avg(e) => format!("AVG({})", e.to_sql()),
-            Aggregate::Variance(e) => format!("VARIANCE({})", e.to_sql()),
-            Aggregate::Stdev(e) => format!("STDEV({})", e.to_sql()),
+        let Aggregate { func, distinct } = self;
+        let distinct = if *distinct { "DISTINCT " } else { "" };
+
+        match func {
+            AggregateFunction::Count(cae) => format!("COUNT({distinct}{})", cae.to_sql()),
+            AggregateFunction::Sum(e) => format!("SUM({distinct}{})", e.to_sql()),
+            AggregateFunction::Max(e) => format!("MAX({distinct}{})", e.to_sql()),
+            AggregateFunction::Min(e) => format!("MIN({distinct}{})", e.to_sql()),
+            AggregateFunction::Avg(e) => format!("AVG({distinct}{})", e.to_sql()),
+            AggregateFunction::Variance(e) => format!("VARIANCE({distinct}{})", e.to_sql()),
+            AggregateFunction::Stdev(e) => format!("STDEV({distinct}{})", e.to_sql()),
         }
     }
 }
@@ -528,8 +567,8 @@ impl ToSql for CountArgExpr {
 mod tests {
     use {
         crate::ast::{
-            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function, ToSql,
-            TrimWhereField,
+            Aggregate, AggregateFunction, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr,
+            Function, ToSql, TrimWhereField,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -537,6 +576,18 @@ mod tests {
 
     #[test]
     fn to_sql_function() {
+        assert_eq!(
+            r#"WEI_TO_ETHER("value", 18)"#,
+            &Expr::Function(Box::new(Function::Custom {
+                name: "WEI_TO_ETHER".to_owned(),
+                exprs: vec![
+                    Expr::Identifier("value".to_owned()),
+                    Expr::Literal(AstLiteral::Number(BigDecimal::from(18))),
+                ],
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"ABS("num")"#,
             &Expr::Function(Box::new(Function::Abs(Expr::Identifier("num".to_owned())))).to_sql()
@@ -1327,45 +1378,55 @@ mod tests {
 
     #[test]
     fn to_sql_aggregate() {
+        let aggregate = |func| Expr::Aggregate(Box::new(Aggregate::new(func)));
+
         assert_eq!(
             r#"MAX("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Max(Expr::Identifier("id".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Max(Expr::Identifier("id".to_owned()))).to_sql()
         );
 
         assert_eq!(
             "COUNT(*)",
-            Expr::Aggregate(Box::new(Aggregate::Count(CountArgExpr::Wildcard))).to_sql()
+            aggregate(AggregateFunction::Count(CountArgExpr::Wildcard)).to_sql()
         );
 
         assert_eq!(
             r#"MIN("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Min(Expr::Identifier("id".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Min(Expr::Identifier("id".to_owned()))).to_sql()
         );
 
         assert_eq!(
             r#"SUM("price")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Sum(Expr::Identifier(
-                "price".to_owned()
-            ))))
-            .to_sql()
+            aggregate(AggregateFunction::Sum(Expr::Identifier("price".to_owned()))).to_sql()
         );
 
         assert_eq!(
             r#"AVG("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Avg(Expr::Identifier("pay".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Avg(Expr::Identifier("pay".to_owned()))).to_sql()
         );
         assert_eq!(
             r#"VARIANCE("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Variance(Expr::Identifier(
+            aggregate(AggregateFunction::Variance(Expr::Identifier(
                 "pay".to_owned()
-            ))))
+            )))
             .to_sql()
         );
         assert_eq!(
             r#"STDEV("total")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Stdev(Expr::Identifier(
+            aggregate(AggregateFunction::Stdev(Expr::Identifier(
                 "total".to_owned()
-            ))))
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"COUNT(DISTINCT "from")"#,
+            Expr::Aggregate(Box::new(Aggregate {
+                func: AggregateFunction::Count(CountArgExpr::Expr(Expr::Identifier(
+                    "from".to_owned()
+                ))),
+                distinct: true,
+            }))
             .to_sql()
         );
     }
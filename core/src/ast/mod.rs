@@ -11,7 +11,7 @@ pub use {
     data_type::DataType,
     ddl::*,
     expr::Expr,
-    function::{Aggregate, CountArgExpr, Function},
+    function::{Aggregate, AggregateFunction, CountArgExpr, Function},
     operator::*,
     query::*,
 };
@@ -42,6 +42,85 @@ pub enum Statement {
 
     /// SHOW VARIABLE
     Show(Show),
+
+    /// INSERT
+    Insert {
+        table_name: String,
+        columns: Vec<String>,
+        source: Query,
+    },
+
+    /// UPDATE
+    Update {
+        table_name: String,
+        assignments: Vec<Assignment>,
+        selection: Option<Expr>,
+    },
+
+    /// DELETE
+    Delete {
+        table_name: String,
+        selection: Option<Expr>,
+    },
+
+    /// CREATE TABLE
+    CreateTable {
+        if_not_exists: bool,
+        name: String,
+        columns: Option<Vec<ColumnDef>>,
+        source: Option<Box<Query>>,
+        comment: Option<String>,
+    },
+
+    /// DROP TABLE
+    DropTable { if_exists: bool, names: Vec<String> },
+
+    /// CREATE INDEX
+    CreateIndex {
+        name: String,
+        table_name: String,
+        column: OrderByExpr,
+    },
+
+    /// DROP INDEX
+    DropIndex { name: String, table_name: String },
+
+    /// CREATE FUNCTION
+    CreateFunction {
+        or_replace: bool,
+        name: String,
+        args: Vec<OperateFunctionArg>,
+        return_: Expr,
+    },
+
+    /// DROP FUNCTION
+    DropFunction { if_exists: bool, names: Vec<String> },
+
+    /// CREATE VIEW, CREATE MATERIALIZED VIEW
+    CreateView {
+        or_replace: bool,
+        materialized: bool,
+        name: String,
+        query: Box<Query>,
+    },
+
+    /// DROP VIEW
+    DropView { if_exists: bool, names: Vec<String> },
+
+    /// REFRESH MATERIALIZED VIEW
+    RefreshView { name: String },
+
+    /// EXPLAIN [ANALYZE]
+    Explain { analyze: bool, query: Box<Query> },
+
+    /// START TRANSACTION, BEGIN
+    StartTransaction,
+
+    /// COMMIT
+    Commit,
+
+    /// ROLLBACK
+    Rollback,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -53,6 +132,10 @@ pub enum Show {
     ChainEntities {
         chain_name: String,
     },
+    /// DESCRIBE, SHOW COLUMNS FROM
+    Columns {
+        table_name: String,
+    },
     Variable(Variable),
 }
 
@@ -65,6 +148,7 @@ pub struct Assignment {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
+    Functions,
     Chains,
     Version,
 }
@@ -72,6 +156,7 @@ pub enum Variable {
 impl ToSql for Statement {
     fn to_sql(&self) -> String {
         match self {
+            Statement::Query(query) => format!("{};", query.to_sql()),
             Statement::Show(show_stmt) => match show_stmt {
                 Show::ChainEntities { chain_name } => {
                     format!("SHOW CHAIN ENTITIES FROM {chain_name};")
@@ -82,13 +167,190 @@ impl ToSql for Statement {
                 } => {
                     format!("SHOW CHAIN ENTITIES IN {entity_name} FROM {chain_name};")
                 }
+                Show::Columns { table_name } => {
+                    let table_name = table_name.replace('.', r#"".""#);
+
+                    format!(r#"SHOW COLUMNS FROM "{table_name}";"#)
+                }
                 Show::Variable(var) => match var {
                     Variable::Tables => "SHOW TABLES;".to_owned(),
+                    Variable::Functions => "SHOW FUNCTIONS;".to_owned(),
                     Variable::Chains => "SHOW CHAINS;".to_owned(),
                     Variable::Version => "SHOW VERSIONS;".to_owned(),
                 },
             },
-            _ => "(..statement..)".to_owned(),
+            Statement::Insert {
+                table_name,
+                columns,
+                source,
+            } => {
+                let columns = match columns.is_empty() {
+                    true => String::new(),
+                    false => format!(r#"("{}") "#, columns.join(r#"", ""#)),
+                };
+
+                format!(
+                    r#"INSERT INTO "{table_name}" {columns}{};"#,
+                    source.to_sql()
+                )
+            }
+            Statement::Update {
+                table_name,
+                assignments,
+                selection,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match selection {
+                    Some(expr) => format!(
+                        r#"UPDATE "{table_name}" SET {assignments} WHERE {};"#,
+                        expr.to_sql()
+                    ),
+                    None => format!(r#"UPDATE "{table_name}" SET {assignments};"#),
+                }
+            }
+            Statement::Delete {
+                table_name,
+                selection,
+            } => match selection {
+                Some(expr) => format!(r#"DELETE FROM "{table_name}" WHERE {};"#, expr.to_sql()),
+                None => format!(r#"DELETE FROM "{table_name}";"#),
+            },
+            Statement::CreateTable {
+                if_not_exists,
+                name,
+                columns,
+                source,
+                comment,
+            } => {
+                let if_not_exists = if_not_exists.then(|| "IF NOT EXISTS".to_owned());
+                let body = match (source, columns) {
+                    (Some(query), _) => Some(format!("AS {}", query.to_sql())),
+                    (None, Some(columns)) => {
+                        let columns = columns
+                            .iter()
+                            .map(ToSql::to_sql)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        Some(format!("({columns})"))
+                    }
+                    (None, None) => None,
+                };
+                let comment = comment
+                    .as_ref()
+                    .map(|comment| format!("COMMENT '{comment}'"));
+                let sql = ["CREATE TABLE".to_owned()]
+                    .into_iter()
+                    .chain(if_not_exists)
+                    .chain([format!(r#""{name}""#)])
+                    .chain(body)
+                    .chain(comment)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                format!("{sql};")
+            }
+            Statement::DropTable { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match if_exists {
+                    true => format!("DROP TABLE IF EXISTS {names};"),
+                    false => format!("DROP TABLE {names};"),
+                }
+            }
+            Statement::CreateIndex {
+                name,
+                table_name,
+                column,
+            } => format!(
+                r#"CREATE INDEX "{name}" ON "{table_name}" ({});"#,
+                column.to_sql()
+            ),
+            Statement::DropIndex { name, table_name } => {
+                format!(r#"DROP INDEX "{table_name}"."{name}";"#)
+            }
+            Statement::CreateFunction {
+                or_replace,
+                name,
+                args,
+                return_,
+            } => {
+                let or_replace = match or_replace {
+                    true => " OR REPLACE",
+                    false => "",
+                };
+                let args = args
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    r#"CREATE{or_replace} FUNCTION "{name}"({args}) RETURN {};"#,
+                    return_.to_sql()
+                )
+            }
+            Statement::DropFunction { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match if_exists {
+                    true => format!("DROP FUNCTION IF EXISTS {names};"),
+                    false => format!("DROP FUNCTION {names};"),
+                }
+            }
+            Statement::CreateView {
+                or_replace,
+                materialized,
+                name,
+                query,
+            } => {
+                let or_replace = match or_replace {
+                    true => " OR REPLACE",
+                    false => "",
+                };
+                let materialized = match materialized {
+                    true => " MATERIALIZED",
+                    false => "",
+                };
+
+                format!(
+                    r#"CREATE{or_replace}{materialized} VIEW "{name}" AS {};"#,
+                    query.to_sql()
+                )
+            }
+            Statement::DropView { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match if_exists {
+                    true => format!("DROP VIEW IF EXISTS {names};"),
+                    false => format!("DROP VIEW {names};"),
+                }
+            }
+            Statement::RefreshView { name } => format!(r#"REFRESH MATERIALIZED VIEW "{name}";"#),
+            Statement::Explain { analyze, query } => match analyze {
+                true => format!("EXPLAIN ANALYZE {};", query.to_sql()),
+                false => format!("EXPLAIN {};", query.to_sql()),
+            },
+            Statement::StartTransaction => "START TRANSACTION;".to_owned(),
+            Statement::Commit => "COMMIT;".to_owned(),
+            Statement::Rollback => "ROLLBACK;".to_owned(),
         }
     }
 }
@@ -108,7 +370,11 @@ pub struct Array {
 #[cfg(test)]
 mod tests {
     use {
-        crate::ast::{Assignment, AstLiteral, Expr, Show, Statement, ToSql, Variable},
+        crate::ast::{
+            Assignment, AstLiteral, BinaryOperator, ColumnDef, DataType, Expr, Function,
+            OperateFunctionArg, OrderByExpr, Query, Select, SelectItem, SetExpr, Show, Statement,
+            TableFactor, TableWithJoins, ToSql, Values, Variable,
+        },
         bigdecimal::BigDecimal,
         std::str::FromStr,
     };
@@ -121,7 +387,21 @@ mod tests {
                 chain_name: "base".into()
             })
             .to_sql()
-        )
+        );
+        assert_eq!(
+            r#"SHOW COLUMNS FROM "Transfers";"#,
+            Statement::Show(Show::Columns {
+                table_name: "Transfers".into()
+            })
+            .to_sql()
+        );
+        assert_eq!(
+            r#"SHOW COLUMNS FROM "eth"."blocks";"#,
+            Statement::Show(Show::Columns {
+                table_name: "eth.blocks".into()
+            })
+            .to_sql()
+        );
     }
 
     #[test]
@@ -136,6 +416,354 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_insert() {
+        let number = |n: i64| Expr::Literal(AstLiteral::Number(BigDecimal::from(n)));
+        let source = Query {
+            body: SetExpr::Values(Values(vec![
+                vec![
+                    number(1),
+                    Expr::Literal(AstLiteral::QuotedString("a".to_owned())),
+                ],
+                vec![number(2), Expr::Literal(AstLiteral::Null)],
+            ])),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+
+        assert_eq!(
+            r#"INSERT INTO "Test" VALUES (1, 'a'), (2, NULL);"#,
+            Statement::Insert {
+                table_name: "Test".to_owned(),
+                columns: Vec::new(),
+                source: source.clone(),
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"INSERT INTO "Test" ("id", "name") VALUES (1, 'a'), (2, NULL);"#,
+            Statement::Insert {
+                table_name: "Test".to_owned(),
+                columns: vec!["id".to_owned(), "name".to_owned()],
+                source,
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_update() {
+        let assignment = |id: &str, value| Assignment {
+            id: id.to_owned(),
+            value: Expr::Literal(AstLiteral::Number(BigDecimal::from(value))),
+        };
+
+        assert_eq!(
+            r#"UPDATE "Foo" SET "id" = 4, "num" = 5;"#,
+            Statement::Update {
+                table_name: "Foo".to_owned(),
+                assignments: vec![assignment("id", 4), assignment("num", 5)],
+                selection: None,
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"UPDATE "Foo" SET "id" = 4 WHERE "id" IS NULL;"#,
+            Statement::Update {
+                table_name: "Foo".to_owned(),
+                assignments: vec![assignment("id", 4)],
+                selection: Some(Expr::IsNull(Box::new(Expr::Identifier("id".to_owned())))),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_delete() {
+        assert_eq!(
+            r#"DELETE FROM "Foo";"#,
+            Statement::Delete {
+                table_name: "Foo".to_owned(),
+                selection: None,
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DELETE FROM "Foo" WHERE "id" IS NOT NULL;"#,
+            Statement::Delete {
+                table_name: "Foo".to_owned(),
+                selection: Some(Expr::IsNotNull(Box::new(Expr::Identifier("id".to_owned())))),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_table() {
+        let column_def = |name: &str, data_type, nullable| ColumnDef {
+            name: name.to_owned(),
+            data_type,
+            nullable,
+            default: None,
+            comment: None,
+        };
+
+        assert_eq!(
+            r#"CREATE TABLE IF NOT EXISTS "Foo" ("id" INT NOT NULL, "name" TEXT NULL);"#,
+            Statement::CreateTable {
+                if_not_exists: true,
+                name: "Foo".to_owned(),
+                columns: Some(vec![
+                    column_def("id", DataType::Int, false),
+                    column_def("name", DataType::Text, true),
+                ]),
+                source: None,
+                comment: None,
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"CREATE TABLE "Logs" COMMENT 'schemaless';"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                name: "Logs".to_owned(),
+                columns: None,
+                source: None,
+                comment: Some("schemaless".to_owned()),
+            }
+            .to_sql()
+        );
+
+        let source = Query {
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        chain_name: None,
+                        name: "Block".to_owned(),
+                        alias: None,
+                        existing_table: true,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+        assert_eq!(
+            r#"CREATE TABLE "Miners" AS SELECT * FROM "Block";"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                name: "Miners".to_owned(),
+                columns: None,
+                source: Some(Box::new(source)),
+                comment: None,
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_table() {
+        assert_eq!(
+            r#"DROP TABLE "Foo";"#,
+            Statement::DropTable {
+                if_exists: false,
+                names: vec!["Foo".to_owned()],
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DROP TABLE IF EXISTS "Foo", "Bar";"#,
+            Statement::DropTable {
+                if_exists: true,
+                names: vec!["Foo".to_owned(), "Bar".to_owned()],
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_and_drop_index() {
+        assert_eq!(
+            r#"CREATE INDEX "idx_number" ON "Block" ("number" DESC);"#,
+            Statement::CreateIndex {
+                name: "idx_number".to_owned(),
+                table_name: "Block".to_owned(),
+                column: OrderByExpr {
+                    expr: Expr::Identifier("number".to_owned()),
+                    asc: Some(false),
+                },
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DROP INDEX "Block"."idx_number";"#,
+            Statement::DropIndex {
+                name: "idx_number".to_owned(),
+                table_name: "Block".to_owned(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_and_drop_function() {
+        assert_eq!(
+            r#"CREATE OR REPLACE FUNCTION "wei_to_ether"("wei" DECIMAL, "decimals" INT DEFAULT 18) RETURN "wei" / POWER(10, "decimals");"#,
+            Statement::CreateFunction {
+                or_replace: true,
+                name: "wei_to_ether".to_owned(),
+                args: vec![
+                    OperateFunctionArg {
+                        name: "wei".to_owned(),
+                        data_type: DataType::Decimal,
+                        default: None,
+                    },
+                    OperateFunctionArg {
+                        name: "decimals".to_owned(),
+                        data_type: DataType::Int,
+                        default: Some(Expr::Literal(AstLiteral::Number(BigDecimal::from(18)))),
+                    },
+                ],
+                return_: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("wei".to_owned())),
+                    op: BinaryOperator::Divide,
+                    right: Box::new(Expr::Function(Box::new(Function::Power {
+                        expr: Expr::Literal(AstLiteral::Number(BigDecimal::from(10))),
+                        power: Expr::Identifier("decimals".to_owned()),
+                    }))),
+                },
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DROP FUNCTION IF EXISTS "wei_to_ether", "normalize";"#,
+            Statement::DropFunction {
+                if_exists: true,
+                names: vec!["wei_to_ether".to_owned(), "normalize".to_owned()],
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_and_drop_view() {
+        let query = Query {
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        chain_name: None,
+                        name: "Log".to_owned(),
+                        alias: None,
+                        existing_table: true,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+        assert_eq!(
+            r#"CREATE VIEW "Transfers" AS SELECT * FROM "Log";"#,
+            Statement::CreateView {
+                or_replace: false,
+                materialized: false,
+                name: "Transfers".to_owned(),
+                query: Box::new(query.clone()),
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"CREATE OR REPLACE MATERIALIZED VIEW "Transfers" AS SELECT * FROM "Log";"#,
+            Statement::CreateView {
+                or_replace: true,
+                materialized: true,
+                name: "Transfers".to_owned(),
+                query: Box::new(query),
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DROP VIEW IF EXISTS "Transfers";"#,
+            Statement::DropView {
+                if_exists: true,
+                names: vec!["Transfers".to_owned()],
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"REFRESH MATERIALIZED VIEW "Transfers";"#,
+            Statement::RefreshView {
+                name: "Transfers".to_owned(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_explain() {
+        let query = Query {
+            body: SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        chain_name: None,
+                        name: "Transfers".to_owned(),
+                        alias: None,
+                        existing_table: true,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+        assert_eq!(
+            r#"EXPLAIN SELECT * FROM "Transfers";"#,
+            Statement::Explain {
+                analyze: false,
+                query: Box::new(query.clone()),
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"EXPLAIN ANALYZE SELECT * FROM "Transfers";"#,
+            Statement::Explain {
+                analyze: true,
+                query: Box::new(query),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_transaction() {
+        assert_eq!("START TRANSACTION;", Statement::StartTransaction.to_sql());
+        assert_eq!("COMMIT;", Statement::Commit.to_sql());
+        assert_eq!("ROLLBACK;", Statement::Rollback.to_sql());
+    }
+
     #[test]
     fn to_sql_assignment() {
         assert_eq!(
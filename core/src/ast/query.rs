@@ -18,10 +18,27 @@ pub struct Query {
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    /// `left op [ALL] right`, labeled by `left`
+    SetOperation {
+        op: SetOperator,
+        /// Keeps duplicated rows
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Select {
+    pub distinct: Option<Distinct>,
     pub projection: Vec<SelectItem>,
     pub from: TableWithJoins,
     /// WHERE
@@ -30,6 +47,14 @@ pub struct Select {
     pub having: Option<Expr>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Distinct {
+    /// DISTINCT
+    Distinct,
+    /// DISTINCT ON (exprs), keeping the first row of each distinct `exprs`
+    On(Vec<Expr>),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SelectItem {
     /// An expression
@@ -212,6 +237,26 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                _,
+            ) => {
+                let operand = |set_expr: &SetExpr| match set_expr {
+                    SetExpr::SetOperation { .. } => format!("({})", set_expr.to_sql_with(quoted)),
+                    SetExpr::Select(_) | SetExpr::Values(_) => set_expr.to_sql_with(quoted),
+                };
+                let all = match all {
+                    true => " ALL",
+                    false => "",
+                };
+
+                format!("{} {op}{all} {}", operand(left), operand(right))
+            }
         }
     }
 }
@@ -236,12 +281,20 @@ impl Select {
         };
 
         let Select {
+            distinct,
             projection,
             from,
             selection,
             group_by,
             having,
         } = self;
+        let distinct = match distinct {
+            Some(Distinct::Distinct) => "DISTINCT ".to_owned(),
+            Some(Distinct::On(exprs)) => {
+                format!("DISTINCT ON ({}) ", exprs.iter().map(to_sql).join(", "))
+            }
+            None => "".to_owned(),
+        };
         let projection = projection
             .iter()
             .map(|item| item.to_sql_with(quoted))
@@ -269,10 +322,13 @@ impl Select {
             .join(" ");
 
         if condition.is_empty() {
-            format!("SELECT {projection} FROM {}", from.to_sql_with(quoted))
+            format!(
+                "SELECT {distinct}{projection} FROM {}",
+                from.to_sql_with(quoted)
+            )
         } else {
             format!(
-                "SELECT {projection} FROM {} {condition}",
+                "SELECT {distinct}{projection} FROM {} {condition}",
                 from.to_sql_with(quoted)
             )
         }
@@ -428,6 +484,14 @@ impl TableFactor {
     }
 }
 
+/// Name the storage knows a table by, `chain.name` for the entities of a chain.
+pub fn qualified_table_name(chain_name: Option<&str>, name: &str) -> String {
+    match chain_name {
+        Some(chain_name) => format!("{chain_name}.{name}"),
+        None => name.to_owned(),
+    }
+}
+
 impl ToSql for TableAlias {
     fn to_sql(&self) -> String {
         self.to_sql_with(true)
@@ -624,9 +688,9 @@ mod tests {
     use {
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                AstLiteral, BinaryOperator, Dictionary, Distinct, Expr, Join, JoinConstraint,
+                JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+                SetOperator, TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
             },
             parse_sql::parse_expr,
             translate::translate_expr,
@@ -651,6 +715,7 @@ mod tests {
             r#"SELECT * FROM "base.FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
         let expected = Query {
             body: SetExpr::Select(Box::new(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -690,6 +755,7 @@ mod tests {
         let actual = "SELECT * FROM base.FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
             body: SetExpr::Select(Box::new(Select {
+                distinct: None,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -724,6 +790,7 @@ mod tests {
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "base.FOO" AS "F" INNER JOIN "base.PlayerItem""#.to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -770,12 +837,33 @@ mod tests {
         ]))
         .to_sql();
         assert_eq!(actual, expected);
+
+        let values = |n: &str| {
+            let number = Expr::Literal(AstLiteral::Number(BigDecimal::from_str(n).unwrap()));
+
+            Box::new(SetExpr::Values(Values(vec![vec![number]])))
+        };
+        let actual = "VALUES (1) UNION ALL (VALUES (2) EXCEPT VALUES (3))".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: values("1"),
+            right: Box::new(SetExpr::SetOperation {
+                op: SetOperator::Except,
+                all: false,
+                left: values("2"),
+                right: values("3"),
+            }),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
     fn to_sql_unquoted_set_expr() {
         let actual = "SELECT * FROM base.FOO AS F INNER JOIN base.PlayerItem".to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -843,6 +931,7 @@ mod tests {
         let actual =
             r#"SELECT * FROM "base.FOO" AS "F" GROUP BY "name" HAVING "name" = 'worm'"#.to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -870,6 +959,7 @@ mod tests {
 
         let actual = r#"SELECT * FROM "base.FOO" WHERE "name" = 'worm'"#.to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -891,12 +981,41 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual =
+            r#"SELECT DISTINCT ON ("sender") "sender", "amount" FROM "Transfer""#.to_owned();
+        let expected = Select {
+            distinct: Some(Distinct::On(vec![Expr::Identifier("sender".to_owned())])),
+            projection: ["sender", "amount"]
+                .into_iter()
+                .map(|name| SelectItem::Expr {
+                    expr: Expr::Identifier(name.to_owned()),
+                    label: String::new(),
+                })
+                .collect(),
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    chain_name: None,
+                    name: "Transfer".to_owned(),
+                    alias: None,
+                    index: None,
+                    existing_table: false,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
     fn to_sql_unquoted_select() {
         let actual = "SELECT * FROM chain.FOO AS F GROUP BY name HAVING name = 'worm'".to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -924,6 +1043,7 @@ mod tests {
 
         let actual = "SELECT * FROM base.FOO WHERE name = 'worm'".to_owned();
         let expected = Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -1041,6 +1161,7 @@ mod tests {
         let expected = TableFactor::Derived {
             subquery: Query {
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1111,6 +1232,7 @@ mod tests {
         let expected = TableFactor::Derived {
             subquery: Query {
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: None,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
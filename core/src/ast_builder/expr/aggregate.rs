@@ -1,7 +1,7 @@
 use {
     super::ExprNode,
     crate::{
-        ast::{Aggregate, CountArgExpr},
+        ast::{Aggregate, AggregateFunction, CountArgExpr},
         parse_sql::parse_expr,
         result::{Error, Result},
         translate::translate_expr,
@@ -59,15 +59,18 @@ impl<'a> TryFrom<AggregateNode<'a>> for Aggregate {
     fn try_from(aggr_node: AggregateNode<'a>) -> Result<Self> {
         match aggr_node {
             AggregateNode::Count(count_arg_expr_node) => {
-                count_arg_expr_node.try_into().map(Aggregate::Count)
+                count_arg_expr_node.try_into().map(AggregateFunction::Count)
             }
-            AggregateNode::Sum(expr_node) => expr_node.try_into().map(Aggregate::Sum),
-            AggregateNode::Min(expr_node) => expr_node.try_into().map(Aggregate::Min),
-            AggregateNode::Max(expr_node) => expr_node.try_into().map(Aggregate::Max),
-            AggregateNode::Avg(expr_node) => expr_node.try_into().map(Aggregate::Avg),
-            AggregateNode::Variance(expr_node) => expr_node.try_into().map(Aggregate::Variance),
-            AggregateNode::Stdev(expr_node) => expr_node.try_into().map(Aggregate::Stdev),
+            AggregateNode::Sum(expr_node) => expr_node.try_into().map(AggregateFunction::Sum),
+            AggregateNode::Min(expr_node) => expr_node.try_into().map(AggregateFunction::Min),
+            AggregateNode::Max(expr_node) => expr_node.try_into().map(AggregateFunction::Max),
+            AggregateNode::Avg(expr_node) => expr_node.try_into().map(AggregateFunction::Avg),
+            AggregateNode::Variance(expr_node) => {
+                expr_node.try_into().map(AggregateFunction::Variance)
+            }
+            AggregateNode::Stdev(expr_node) => expr_node.try_into().map(AggregateFunction::Stdev),
         }
+        .map(Aggregate::new)
     }
 }
 
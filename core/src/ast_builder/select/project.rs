@@ -252,6 +252,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: None,
                 projection: SelectItemList::from("Player.name, PlayerItem.name")
                     .try_into()
                     .unwrap(),
@@ -151,6 +151,7 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
         };
 
         Ok(Select {
+            distinct: None,
             projection: vec![SelectItem::Wildcard],
             from,
             selection: None,
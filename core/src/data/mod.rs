@@ -19,7 +19,7 @@ pub use {
     literal::{Literal, LiteralError},
     point::Point,
     row::{Row, RowError},
-    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError, SchemaView},
     string_ext::{StringExt, StringExtError},
     table::{get_alias, get_index, TableError},
     value::{ConvertError, HashMapJsonExt, NumericBinaryOperator, Value, ValueError},
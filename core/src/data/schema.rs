@@ -1,8 +1,19 @@
 use {
-    crate::ast::{ColumnDef, Expr},
-    chrono::NaiveDateTime,
+    crate::{
+        ast::{ColumnDef, Expr, OrderByExpr, Query, ToSql},
+        parse_sql::parse,
+        result::Result,
+        translate::{
+            translate_column_def, translate_idents, translate_order_by_expr, translate_query,
+        },
+    },
+    chrono::{NaiveDateTime, Utc},
     serde::{Deserialize, Serialize},
-    std::fmt::Debug,
+    sqlparser::ast::{
+        CommentDef as SqlCommentDef, CreateIndex as SqlCreateIndex, CreateTable as SqlCreateTable,
+        Statement as SqlStatement,
+    },
+    std::{fmt::Debug, iter},
     strum_macros::Display,
     thiserror::Error as ThisError,
 };
@@ -23,6 +34,16 @@ pub struct SchemaIndex {
     pub created: NaiveDateTime,
 }
 
+/// Query a view reads its rows from.
+///
+/// A view is expanded into its query wherever it is read, while a materialized view holds a
+/// snapshot of the rows of its query, taken on creation.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaView {
+    pub query: Query,
+    pub materialized: bool,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Schema {
     pub table_name: String,
@@ -30,6 +51,9 @@ pub struct Schema {
     pub indexes: Vec<SchemaIndex>,
     pub comment: Option<String>,
     pub chain_name: Option<String>,
+    /// `None` for a table.
+    #[serde(default)]
+    pub view: Option<SchemaView>,
 }
 
 #[derive(ThisError, Debug, PartialEq, Serialize)]
@@ -38,237 +62,376 @@ pub enum SchemaParseError {
     CannotParseDDL,
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use {
-//         super::SchemaParseError,
-//         crate::{
-//             ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr},
-//             chrono::Utc,
-//             data::{Schema, SchemaIndex, SchemaIndexOrd},
-//             prelude::DataType,
-//         },
-//     };
-
-//     fn assert_schema(actual: Schema, expected: Schema) {
-//         let Schema {
-//             table_name,
-//             column_defs,
-//             indexes,
-//             engine,
-//             foreign_keys,
-//             comment,
-//         } = actual;
-
-//         let Schema {
-//             table_name: table_name_e,
-//             column_defs: column_defs_e,
-//             indexes: indexes_e,
-//             engine: engine_e,
-//             foreign_keys: foreign_keys_e,
-//             comment: comment_e,
-//         } = expected;
-
-//         assert_eq!(table_name, table_name_e);
-//         assert_eq!(column_defs, column_defs_e);
-//         assert_eq!(engine, engine_e);
-//         assert_eq!(foreign_keys, foreign_keys_e);
-//         assert_eq!(comment, comment_e);
-//         indexes
-//             .into_iter()
-//             .zip(indexes_e)
-//             .for_each(|(actual, expected)| assert_index(actual, expected));
-//     }
-
-//     fn assert_index(actual: SchemaIndex, expected: SchemaIndex) {
-//         let SchemaIndex {
-//             name, expr, order, ..
-//         } = actual;
-//         let SchemaIndex {
-//             name: name_e,
-//             expr: expr_e,
-//             order: order_e,
-//             ..
-//         } = expected;
-
-//         assert_eq!(name, name_e);
-//         assert_eq!(expr, expr_e);
-//         assert_eq!(order, order_e);
-//     }
-
-//     #[test]
-//     fn table_basic() {
-//         let schema = Schema {
-//             table_name: "User".to_owned(),
-//             column_defs: Some(vec![
-//                 ColumnDef {
-//                     name: "id".to_owned(),
-//                     data_type: DataType::Int,
-//                     nullable: false,
-//                     default: None,
-//                     unique: None,
-//                     comment: None,
-//                 },
-//                 ColumnDef {
-//                     name: "name".to_owned(),
-//                     data_type: DataType::Text,
-//                     nullable: true,
-//                     default: Some(Expr::Literal(AstLiteral::QuotedString("worm".to_owned()))),
-//                     unique: None,
-//                     comment: None,
-//                 },
-//             ]),
-//             indexes: Vec::new(),
-//             engine: None,
-//             foreign_keys: Vec::new(),
-//             comment: None,
-//         };
-
-//         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'worm');"#;
-//         assert_eq!(schema.to_ddl(), ddl);
-
-//         let actual = Schema::from_ddl(ddl).unwrap();
-//         assert_schema(actual, schema);
-
-//         let schema = Schema {
-//             table_name: "Test".to_owned(),
-//             column_defs: None,
-//             indexes: Vec::new(),
-//             engine: None,
-//             foreign_keys: Vec::new(),
-//             comment: None,
-//         };
-//         let ddl = r#"CREATE TABLE "Test";"#;
-//         assert_eq!(schema.to_ddl(), ddl);
-
-//         let actual = Schema::from_ddl(ddl).unwrap();
-//         assert_schema(actual, schema);
-//     }
-
-//     #[test]
-//     fn table_primary() {
-//         let schema = Schema {
-//             table_name: "User".to_owned(),
-//             column_defs: Some(vec![ColumnDef {
-//                 name: "id".to_owned(),
-//                 data_type: DataType::Int,
-//                 nullable: false,
-//                 default: None,
-//                 unique: Some(ColumnUniqueOption { is_primary: true }),
-//                 comment: None,
-//             }]),
-//             indexes: Vec::new(),
-//             engine: None,
-//             foreign_keys: Vec::new(),
-//             comment: None,
-//         };
-
-//         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL PRIMARY KEY);"#;
-//         assert_eq!(schema.to_ddl(), ddl);
-
-//         let actual = Schema::from_ddl(ddl).unwrap();
-//         assert_schema(actual, schema);
-//     }
-
-//     #[test]
-//     fn invalid_ddl() {
-//         // Only Statement::CreateTable is supported
-//         let invalid_ddl = r#"DROP TABLE "Users";"#;
-//         let actual = Schema::from_ddl(invalid_ddl);
-//         assert_eq!(actual, Err(SchemaParseError::CannotParseDDL.into()));
-//     }
-
-//     #[test]
-//     fn table_with_index() {
-//         let schema = Schema {
-//             table_name: "User".to_owned(),
-//             column_defs: Some(vec![
-//                 ColumnDef {
-//                     name: "id".to_owned(),
-//                     data_type: DataType::Int,
-//                     nullable: false,
-//                     default: None,
-//                     unique: None,
-//                     comment: None,
-//                 },
-//                 ColumnDef {
-//                     name: "name".to_owned(),
-//                     data_type: DataType::Text,
-//                     nullable: false,
-//                     default: None,
-//                     unique: None,
-//                     comment: None,
-//                 },
-//             ]),
-//             indexes: vec![
-//                 SchemaIndex {
-//                     name: "User_id".to_owned(),
-//                     expr: Expr::Identifier("id".to_owned()),
-//                     order: SchemaIndexOrd::Both,
-//                     created: Utc::now().naive_utc(),
-//                 },
-//                 SchemaIndex {
-//                     name: "User_name".to_owned(),
-//                     expr: Expr::Identifier("name".to_owned()),
-//                     order: SchemaIndexOrd::Both,
-//                     created: Utc::now().naive_utc(),
-//                 },
-//             ],
-//             engine: None,
-//             foreign_keys: Vec::new(),
-//             comment: None,
-//         };
-//         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
-// CREATE INDEX "User_id" ON "User" ("id");
-// CREATE INDEX "User_name" ON "User" ("name");"#;
-//         assert_eq!(schema.to_ddl(), ddl);
-
-//         let actual = Schema::from_ddl(ddl).unwrap();
-//         assert_schema(actual, schema);
-
-//         let index_should_not_be_first = r#"CREATE INDEX "User_id" ON "User" ("id");
-// CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
-//         let actual = Schema::from_ddl(index_should_not_be_first);
-//         assert_eq!(actual, Err(SchemaParseError::CannotParseDDL.into()));
-//     }
-
-//     #[test]
-//     fn non_word_identifier() {
-//         let schema = Schema {
-//             table_name: 1.to_string(),
-//             column_defs: Some(vec![
-//                 ColumnDef {
-//                     name: 2.to_string(),
-//                     data_type: DataType::Int,
-//                     nullable: true,
-//                     default: None,
-//                     unique: None,
-//                     comment: None,
-//                 },
-//                 ColumnDef {
-//                     name: ";".to_owned(),
-//                     data_type: DataType::Int,
-//                     nullable: true,
-//                     default: None,
-//                     unique: None,
-//                     comment: None,
-//                 },
-//             ]),
-//             indexes: vec![SchemaIndex {
-//                 name: ".".to_owned(),
-//                 expr: Expr::Identifier(";".to_owned()),
-//                 order: SchemaIndexOrd::Both,
-//                 created: Utc::now().naive_utc(),
-//             }],
-//             engine: None,
-//             foreign_keys: Vec::new(),
-//             comment: None,
-//         };
-//         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
-// CREATE INDEX "." ON "1" (";");"#;
-//         assert_eq!(schema.to_ddl(), ddl);
-
-//         let actual = Schema::from_ddl(ddl).unwrap();
-//         assert_schema(actual, schema);
-//     }
-// }
+impl Schema {
+    /// `CREATE TABLE` statement of the schema, followed by a `CREATE INDEX` statement per index.
+    ///
+    /// A view is written as a `CREATE VIEW` statement, while a materialized view is written as
+    /// the table holding its rows.
+    pub fn to_ddl(&self) -> String {
+        let Schema {
+            table_name,
+            column_defs,
+            indexes,
+            comment,
+            view,
+            ..
+        } = self;
+
+        if let Some(SchemaView {
+            query,
+            materialized: false,
+        }) = view
+        {
+            return format!(r#"CREATE VIEW "{table_name}" AS {};"#, query.to_sql());
+        }
+
+        let columns = column_defs
+            .as_ref()
+            .map(|column_defs| {
+                let column_defs = column_defs
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(" ({column_defs})")
+            })
+            .unwrap_or_default();
+        let comment = comment
+            .as_ref()
+            .map(|comment| format!(" COMMENT '{comment}'"))
+            .unwrap_or_default();
+        let create_table = format!(r#"CREATE TABLE "{table_name}"{columns}{comment};"#);
+
+        let create_indexes = indexes.iter().map(
+            |SchemaIndex {
+                 name, expr, order, ..
+             }| {
+                let expr = expr.to_sql();
+                let order = match order {
+                    SchemaIndexOrd::Asc => " ASC",
+                    SchemaIndexOrd::Desc => " DESC",
+                    SchemaIndexOrd::Both => "",
+                };
+
+                format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({expr}{order});"#)
+            },
+        );
+
+        iter::once(create_table)
+            .chain(create_indexes)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Parses the statements written by [`Schema::to_ddl`].
+    pub fn from_ddl(ddl: &str) -> Result<Schema> {
+        let created = Utc::now().naive_utc();
+        let statements = parse(ddl)?;
+
+        if let [SqlStatement::CreateView {
+            name,
+            query,
+            materialized: false,
+            ..
+        }] = statements.as_slice()
+        {
+            return Ok(Schema {
+                table_name: translate_idents(&name.0).join("."),
+                column_defs: None,
+                indexes: Vec::new(),
+                comment: None,
+                chain_name: None,
+                view: Some(SchemaView {
+                    query: translate_query(query)?,
+                    materialized: false,
+                }),
+            });
+        }
+
+        let Some((
+            SqlStatement::CreateTable(SqlCreateTable {
+                name,
+                columns,
+                comment,
+                ..
+            }),
+            create_indexes,
+        )) = statements.split_first()
+        else {
+            return Err(SchemaParseError::CannotParseDDL.into());
+        };
+
+        let column_defs = columns
+            .iter()
+            .map(translate_column_def)
+            .collect::<Result<Vec<_>>>()?;
+        let comment = comment.as_ref().map(|comment| match comment {
+            SqlCommentDef::WithEq(comment) | SqlCommentDef::WithoutEq(comment) => comment.clone(),
+        });
+
+        let indexes = create_indexes
+            .iter()
+            .map(|statement| match statement {
+                SqlStatement::CreateIndex(SqlCreateIndex {
+                    name: Some(name),
+                    columns,
+                    ..
+                }) if columns.len() == 1 => {
+                    let OrderByExpr { expr, asc } = translate_order_by_expr(&columns[0])?;
+                    let order = match asc {
+                        Some(true) => SchemaIndexOrd::Asc,
+                        Some(false) => SchemaIndexOrd::Desc,
+                        None => SchemaIndexOrd::Both,
+                    };
+
+                    Ok(SchemaIndex {
+                        name: translate_idents(&name.0).join("."),
+                        expr,
+                        order,
+                        created,
+                    })
+                }
+                _ => Err(SchemaParseError::CannotParseDDL.into()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Schema {
+            table_name: translate_idents(&name.0).join("."),
+            column_defs: (!column_defs.is_empty()).then_some(column_defs),
+            indexes,
+            comment,
+            chain_name: None,
+            view: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::SchemaParseError,
+        crate::{
+            ast::{AstLiteral, ColumnDef, Expr},
+            chrono::Utc,
+            data::{Schema, SchemaIndex, SchemaIndexOrd, SchemaView},
+            parse_sql::parse_query,
+            prelude::DataType,
+            translate::translate_query,
+        },
+    };
+
+    fn assert_schema(actual: Schema, expected: Schema) {
+        let Schema {
+            table_name,
+            column_defs,
+            indexes,
+            comment,
+            chain_name,
+            view,
+        } = actual;
+
+        let Schema {
+            table_name: table_name_e,
+            column_defs: column_defs_e,
+            indexes: indexes_e,
+            comment: comment_e,
+            chain_name: chain_name_e,
+            view: view_e,
+        } = expected;
+
+        assert_eq!(table_name, table_name_e);
+        assert_eq!(column_defs, column_defs_e);
+        assert_eq!(comment, comment_e);
+        assert_eq!(chain_name, chain_name_e);
+        assert_eq!(view, view_e);
+        assert_eq!(indexes.len(), indexes_e.len());
+        indexes
+            .into_iter()
+            .zip(indexes_e)
+            .for_each(|(actual, expected)| assert_index(actual, expected));
+    }
+
+    fn assert_index(actual: SchemaIndex, expected: SchemaIndex) {
+        let SchemaIndex {
+            name, expr, order, ..
+        } = actual;
+        let SchemaIndex {
+            name: name_e,
+            expr: expr_e,
+            order: order_e,
+            ..
+        } = expected;
+
+        assert_eq!(name, name_e);
+        assert_eq!(expr, expr_e);
+        assert_eq!(order, order_e);
+    }
+
+    #[test]
+    fn table_basic() {
+        let schema = Schema {
+            table_name: "User".to_owned(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: "id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    comment: None,
+                },
+                ColumnDef {
+                    name: "name".to_owned(),
+                    data_type: DataType::Text,
+                    nullable: true,
+                    default: Some(Expr::Literal(AstLiteral::QuotedString("worm".to_owned()))),
+                    comment: None,
+                },
+            ]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+
+        let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'worm');"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+
+        let schema = Schema {
+            table_name: "Test".to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            comment: Some("exported blocks".to_owned()),
+            chain_name: None,
+            view: None,
+        };
+        let ddl = r#"CREATE TABLE "Test" COMMENT 'exported blocks';"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn invalid_ddl() {
+        // Only Statement::CreateTable is supported
+        let invalid_ddl = r#"DROP TABLE "Users";"#;
+        let actual = Schema::from_ddl(invalid_ddl);
+        assert_eq!(actual, Err(SchemaParseError::CannotParseDDL.into()));
+    }
+
+    #[test]
+    fn table_with_index() {
+        let schema = Schema {
+            table_name: "User".to_owned(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: "id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    comment: None,
+                },
+                ColumnDef {
+                    name: "name".to_owned(),
+                    data_type: DataType::Text,
+                    nullable: false,
+                    default: None,
+                    comment: None,
+                },
+            ]),
+            indexes: vec![
+                SchemaIndex {
+                    name: "User_id".to_owned(),
+                    expr: Expr::Identifier("id".to_owned()),
+                    order: SchemaIndexOrd::Both,
+                    created: Utc::now().naive_utc(),
+                },
+                SchemaIndex {
+                    name: "User_name".to_owned(),
+                    expr: Expr::Identifier("name".to_owned()),
+                    order: SchemaIndexOrd::Desc,
+                    created: Utc::now().naive_utc(),
+                },
+            ],
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+        let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
+CREATE INDEX "User_id" ON "User" ("id");
+CREATE INDEX "User_name" ON "User" ("name" DESC);"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+
+        let index_should_not_be_first = r#"CREATE INDEX "User_id" ON "User" ("id");
+CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
+        let actual = Schema::from_ddl(index_should_not_be_first);
+        assert_eq!(actual, Err(SchemaParseError::CannotParseDDL.into()));
+    }
+
+    #[test]
+    fn non_word_identifier() {
+        let schema = Schema {
+            table_name: 1.to_string(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: 2.to_string(),
+                    data_type: DataType::Int,
+                    nullable: true,
+                    default: None,
+                    comment: None,
+                },
+                ColumnDef {
+                    name: ";".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: true,
+                    default: None,
+                    comment: None,
+                },
+            ]),
+            indexes: vec![SchemaIndex {
+                name: ".".to_owned(),
+                expr: Expr::Identifier(";".to_owned()),
+                order: SchemaIndexOrd::Both,
+                created: Utc::now().naive_utc(),
+            }],
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+        let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
+CREATE INDEX "." ON "1" (";");"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn view() {
+        let query = parse_query("SELECT id FROM User WHERE id > 1").unwrap();
+        let schema = Schema {
+            table_name: "Recent".to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: Some(SchemaView {
+                query: translate_query(&query).unwrap(),
+                materialized: false,
+            }),
+        };
+
+        let ddl = r#"CREATE VIEW "Recent" AS SELECT "id" AS "id" FROM "User" WHERE "id" > 1;"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+}
@@ -173,18 +173,23 @@ impl<'a, T: GStore> Aggregator<'a, T> {
     }
 
     fn check_aggregate(&self) -> bool {
-        if !self.group_by.is_empty() {
-            return true;
-        }
+        check_aggregate(self.fields, self.group_by)
+    }
+}
 
-        self.fields
-            .iter()
-            .map(|field| match field {
-                SelectItem::Expr { expr, .. } => check(expr),
-                _ => false,
-            })
-            .any(identity)
+/// Whether a `SELECT` of `fields` grouped by `group_by` aggregates its rows.
+pub fn check_aggregate(fields: &[SelectItem], group_by: &[Expr]) -> bool {
+    if !group_by.is_empty() {
+        return true;
     }
+
+    fields
+        .iter()
+        .map(|field| match field {
+            SelectItem::Expr { expr, .. } => check(expr),
+            _ => false,
+        })
+        .any(identity)
 }
 
 #[async_recursion(?Send)]
@@ -1,8 +1,8 @@
 use {
     crate::{
-        ast::{Aggregate, CountArgExpr, DataType},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, DataType},
         data::{Key, Value},
-        executor::{context::RowContext, evaluate::evaluate},
+        executor::{context::RowContext, distinct::DistinctKey, evaluate::evaluate},
         result::Result,
         store::GStore,
     },
@@ -45,28 +45,28 @@ impl AggrValue {
     fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
         let value = value.clone();
 
-        Ok(match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => AggrValue::Count {
+        Ok(match &aggr.func {
+            AggregateFunction::Count(CountArgExpr::Wildcard) => AggrValue::Count {
                 wildcard: true,
                 count: 1,
             },
-            Aggregate::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
+            AggregateFunction::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
                 wildcard: false,
                 count: i64::from(!value.is_null()),
             },
-            Aggregate::Sum(_) => AggrValue::Sum(value),
-            Aggregate::Min(_) => AggrValue::Min(value),
-            Aggregate::Max(_) => AggrValue::Max(value),
-            Aggregate::Avg(_) => AggrValue::Avg {
+            AggregateFunction::Sum(_) => AggrValue::Sum(value),
+            AggregateFunction::Min(_) => AggrValue::Min(value),
+            AggregateFunction::Max(_) => AggrValue::Max(value),
+            AggregateFunction::Avg(_) => AggrValue::Avg {
                 sum: value,
                 count: 1,
             },
-            Aggregate::Variance(_) => AggrValue::Variance {
+            AggregateFunction::Variance(_) => AggrValue::Variance {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
             },
-            Aggregate::Stdev(_) => AggrValue::Stdev {
+            AggregateFunction::Stdev(_) => AggrValue::Stdev {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
@@ -159,6 +159,8 @@ pub struct State<'a, T: GStore> {
     index: usize,
     group: Group,
     values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue)>,
+    /// Values already aggregated by each `DISTINCT` aggregate of each group.
+    distinct_values: HashMap<(Group, &'a Aggregate), HashSet<DistinctKey>>,
     groups: HashSet<Group>,
     contexts: Vector<Rc<RowContext<'a>>>,
 }
@@ -170,6 +172,7 @@ impl<'a, T: GStore> State<'a, T> {
             index: 0,
             group: Rc::new(vec![Key::None]),
             values: IndexMap::new(),
+            distinct_values: HashMap::new(),
             groups: HashSet::new(),
             contexts: Vector::new(),
         }
@@ -246,27 +249,41 @@ impl<'a, T: GStore> State<'a, T> {
         filter_context: Option<Rc<RowContext<'a>>>,
         aggr: &'a Aggregate,
     ) -> Result<State<'a, T>> {
-        let value = match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => Value::Null,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
+        let value: Value = match aggr.as_expr() {
+            Some(expr) => evaluate(self.storage, filter_context, None, expr)
                 .await?
                 .try_into()?,
+            None => Value::Null,
         };
-        let aggr_value = match self.get(aggr) {
-            Some((index, _)) if self.index <= *index => None,
+        let state = match aggr.distinct {
+            true => {
+                let key = (Rc::clone(&self.group), aggr);
+                let seen = self.distinct_values.get(&key).cloned().unwrap_or_default();
+                let distinct_key = DistinctKey::try_from(&value)?;
+
+                if seen.contains(&distinct_key) {
+                    return Ok(self);
+                }
+
+                let distinct_values = self.distinct_values.update(key, seen.update(distinct_key));
+
+                Self {
+                    distinct_values,
+                    ..self
+                }
+            }
+            false => self,
+        };
+
+        let aggr_value = match state.get(aggr) {
+            Some((index, _)) if state.index <= *index => None,
             Some((_, aggr_value)) => aggr_value.accumulate(&value)?,
             None => Some(AggrValue::new(aggr, &value)?),
         };
 
         match aggr_value {
-            Some(aggr_value) => Ok(self.update(aggr, aggr_value)),
-            None => Ok(self),
+            Some(aggr_value) => Ok(state.update(aggr, aggr_value)),
+            None => Ok(state),
         }
     }
 }
@@ -0,0 +1,52 @@
+use {serde::Serialize, std::fmt::Debug, thiserror::Error};
+
+#[derive(Error, Serialize, Debug, PartialEq, Eq)]
+pub enum AlterError {
+    #[error("table already exists: {0}")]
+    TableAlreadyExists(String),
+
+    #[error("table does not exist: {0}")]
+    TableNotFound(String),
+
+    #[error("column '{0}' is specified more than once")]
+    DuplicateColumnName(String),
+
+    #[error("column of the index does not exist: {0}")]
+    IndexColumnNotFound(String),
+
+    #[error("unsupported index expression: {0}")]
+    UnsupportedIndexExpr(String),
+
+    #[error("function already exists: {0}")]
+    FunctionAlreadyExists(String),
+
+    #[error("function does not exist: {0}")]
+    FunctionNotFound(String),
+
+    #[error("argument '{0}' is specified more than once")]
+    DuplicateArgName(String),
+
+    #[error("argument '{0}' without a default follows one with a default")]
+    RequiredArgAfterDefault(String),
+
+    #[error("identifier '{0}' is not an argument of the function")]
+    UnknownArgName(String),
+
+    #[error("function name is taken by a built-in function: {0}")]
+    BuiltinFunctionName(String),
+
+    #[error("view already exists: {0}")]
+    ViewAlreadyExists(String),
+
+    #[error("view does not exist: {0}")]
+    ViewNotFound(String),
+
+    #[error("not a view: {0}")]
+    NotAView(String),
+
+    #[error("not a table: {0}")]
+    NotATable(String),
+
+    #[error("not a materialized view: {0}")]
+    NotAMaterializedView(String),
+}
@@ -0,0 +1,126 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::{Expr, Function, OperateFunctionArg},
+        data::CustomFunction,
+        executor::evaluate_stateless,
+        parse_sql::parse_expr,
+        plan::PlanExpr,
+        result::Result,
+        store::{GStore, GStoreMut},
+        translate::translate_expr,
+    },
+    std::collections::HashSet,
+};
+
+/// Creates the function `name`, replacing an existing one when `or_replace`.
+///
+/// The default values of the arguments are evaluated upfront so that a mistyped default is
+/// reported on creation rather than on the first call. As the arguments left out of a call are
+/// the last ones, every argument following one with a default must have a default too.
+///
+/// The body may only refer to the arguments, apart from the columns of its subqueries, and the
+/// name may not be one of a built-in function, which every call to it would go to instead.
+pub async fn create_function<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    args: &[OperateFunctionArg],
+    or_replace: bool,
+    body: &Expr,
+) -> Result<()> {
+    if is_builtin_function(name) {
+        return Err(AlterError::BuiltinFunctionName(name.to_owned()).into());
+    }
+
+    let mut names = HashSet::new();
+    if let Some(arg) = args.iter().find(|arg| !names.insert(arg.name.as_str())) {
+        return Err(AlterError::DuplicateArgName(arg.name.to_owned()).into());
+    }
+
+    if let Some(arg) = args
+        .iter()
+        .skip_while(|arg| arg.default.is_none())
+        .find(|arg| arg.default.is_none())
+    {
+        return Err(AlterError::RequiredArgAfterDefault(arg.name.to_owned()).into());
+    }
+
+    if let Some(ident) = find_unknown_ident(args, body) {
+        return Err(AlterError::UnknownArgName(ident).into());
+    }
+
+    for OperateFunctionArg {
+        data_type, default, ..
+    } in args
+    {
+        if let Some(expr) = default {
+            evaluate_stateless(None, expr)
+                .await?
+                .try_into_value(data_type, true)?;
+        }
+    }
+
+    if !or_replace && storage.fetch_function(name).await?.is_some() {
+        return Err(AlterError::FunctionAlreadyExists(name.to_owned()).into());
+    }
+
+    storage
+        .insert_function(CustomFunction {
+            func_name: name.to_owned(),
+            args: args.to_vec(),
+            body: body.clone(),
+        })
+        .await
+}
+
+/// Drops the functions `names`, skipping the missing ones when `if_exists`, and returns the
+/// number of functions dropped.
+pub async fn drop_function<T: GStore + GStoreMut>(
+    storage: &mut T,
+    names: &[String],
+    if_exists: bool,
+) -> Result<usize> {
+    let mut existing = Vec::new();
+    for name in names {
+        match (storage.fetch_function(name).await?, if_exists) {
+            (Some(_), _) => existing.push(name),
+            (None, true) => {}
+            (None, false) => return Err(AlterError::FunctionNotFound(name.to_owned()).into()),
+        }
+    }
+
+    for name in &existing {
+        storage.delete_function(name).await?;
+    }
+
+    Ok(existing.len())
+}
+
+/// Whether a call to `name` translates to a built-in function rather than a user function.
+fn is_builtin_function(name: &str) -> bool {
+    let call = parse_expr(format!(r#""{name}"()"#)).and_then(|expr| translate_expr(&expr));
+
+    !matches!(
+        call,
+        Ok(Expr::Function(function)) if matches!(*function, Function::Custom { .. })
+    )
+}
+
+/// First identifier of `expr` which is none of `args`, leaving out the columns of subqueries.
+fn find_unknown_ident(args: &[OperateFunctionArg], expr: &Expr) -> Option<String> {
+    let find = |expr| find_unknown_ident(args, expr);
+
+    match PlanExpr::from(expr) {
+        PlanExpr::None | PlanExpr::Query(_) => None,
+        PlanExpr::Identifier(ident) => {
+            (!args.iter().any(|arg| arg.name == ident)).then(|| ident.to_owned())
+        }
+        PlanExpr::CompoundIdentifier { alias, ident } => Some(format!("{alias}.{ident}")),
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => find(expr),
+        PlanExpr::TwoExprs(expr, expr2) => find(expr).or_else(|| find(expr2)),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            find(expr).or_else(|| find(expr2)).or_else(|| find(expr3))
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().find_map(find),
+    }
+}
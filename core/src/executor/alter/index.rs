@@ -0,0 +1,61 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::{ColumnDef, Expr, OrderByExpr, ToSql},
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+};
+
+/// Creates the index `index_name` on `table_name` by the expression of `column`.
+///
+/// The expression may only refer to the columns of the table, unless the table is schemaless.
+pub async fn create_index<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    index_name: &str,
+    column: &OrderByExpr,
+) -> Result<()> {
+    let column_defs = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?
+        .column_defs;
+
+    if let Some(column_defs) = &column_defs {
+        validate_index_expr(column_defs, &column.expr)?;
+    }
+
+    storage.create_index(table_name, index_name, column).await
+}
+
+pub async fn drop_index<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    index_name: &str,
+) -> Result<()> {
+    if storage.fetch_schema(table_name).await?.is_none() {
+        return Err(AlterError::TableNotFound(table_name.to_owned()).into());
+    }
+
+    storage.drop_index(table_name, index_name).await
+}
+
+fn validate_index_expr(column_defs: &[ColumnDef], expr: &Expr) -> Result<()> {
+    match expr {
+        Expr::Identifier(ident) => match column_defs
+            .iter()
+            .any(|column_def| &column_def.name == ident)
+        {
+            true => Ok(()),
+            false => Err(AlterError::IndexColumnNotFound(ident.to_owned()).into()),
+        },
+        Expr::Literal(_) | Expr::TypedString { .. } => Ok(()),
+        Expr::Nested(expr) | Expr::UnaryOp { expr, .. } => validate_index_expr(column_defs, expr),
+        Expr::BinaryOp { left, right, .. } => {
+            validate_index_expr(column_defs, left)?;
+            validate_index_expr(column_defs, right)
+        }
+        _ => Err(AlterError::UnsupportedIndexExpr(expr.to_sql()).into()),
+    }
+}
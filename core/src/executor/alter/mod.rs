@@ -0,0 +1,13 @@
+mod error;
+mod function;
+mod index;
+mod table;
+mod view;
+
+pub use {
+    error::AlterError,
+    function::{create_function, drop_function},
+    index::{create_index, drop_index},
+    table::{create_table, drop_table},
+    view::{create_view, drop_view, fetch_view_columns, refresh_view},
+};
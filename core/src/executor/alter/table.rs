@@ -0,0 +1,402 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::{
+            qualified_table_name, Aggregate, AggregateFunction, BinaryOperator, ColumnDef,
+            DataType, Expr, Function, Query, Select, SelectItem, SetExpr, TableFactor,
+            UnaryOperator, Values,
+        },
+        data::{get_alias, Literal, Row, RowError, Schema, Value},
+        executor::{fetch::fetch_relation_columns, select::select_with_labels, InsertError},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    async_recursion::async_recursion,
+    futures::stream::TryStreamExt,
+    std::collections::HashSet,
+};
+
+/// Columns of a relation, typed where their type is known.
+type Columns = Vec<(String, Option<DataType>)>;
+
+/// Creates the table `name`, filled with the rows of `source` when given.
+///
+/// Without explicit `columns`, a table created from a `source` takes its columns from the
+/// labels of the query, typed after the schemas of its relations and the expressions of its
+/// projection. A column whose type does not follow from them, such as one selected from a
+/// schemaless table, is typed after its first non-null value, or as TEXT. A `source` giving
+/// no labels, such as `SELECT *` over a schemaless table, creates a schemaless table.
+pub async fn create_table<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    columns: Option<&[ColumnDef]>,
+    if_not_exists: bool,
+    source: Option<&Query>,
+    comment: Option<&str>,
+) -> Result<()> {
+    if storage.fetch_schema(name).await?.is_some() {
+        return match if_not_exists {
+            true => Ok(()),
+            false => Err(AlterError::TableAlreadyExists(name.to_owned()).into()),
+        };
+    }
+
+    let (column_defs, rows) = fetch_source(storage, columns, source).await?;
+    let schema = Schema {
+        table_name: name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        comment: comment.map(ToOwned::to_owned),
+        chain_name: None,
+        view: None,
+    };
+
+    insert_table(storage, &schema, rows).await
+}
+
+/// Columns and rows of a table created from `source`, the columns being inferred from the
+/// labels of `source` when not given.
+///
+/// Given `columns`, every row of `source` is checked to hold a value of the type of each of
+/// them, as when inserted.
+pub(super) async fn fetch_source<T: GStore>(
+    storage: &T,
+    columns: Option<&[ColumnDef]>,
+    source: Option<&Query>,
+) -> Result<(Option<Vec<ColumnDef>>, Vec<Row>)> {
+    let (labels, types, rows) = match source {
+        Some(query) => {
+            let (labels, rows) = select_with_labels(storage, query, None).await?;
+            let rows = rows.try_collect::<Vec<_>>().await?;
+            let types = match columns {
+                Some(_) => None,
+                None => fetch_query_types(storage, query).await?,
+            };
+
+            (labels, types, rows)
+        }
+        None => (None, None, Vec::new()),
+    };
+
+    let column_defs = match (columns, labels) {
+        (Some(columns), _) => {
+            validate_rows(columns, &rows)?;
+
+            Some(columns.to_vec())
+        }
+        (None, Some(labels)) => Some(infer_column_defs(labels, types, &rows)),
+        (None, None) => None,
+    };
+
+    if let Some(column_defs) = &column_defs {
+        validate_column_names(column_defs)?;
+    }
+
+    Ok((column_defs, rows))
+}
+
+pub(super) async fn insert_table<T: GStoreMut>(
+    storage: &mut T,
+    schema: &Schema,
+    rows: Vec<Row>,
+) -> Result<()> {
+    storage.insert_schema(schema).await?;
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let rows = rows.into_iter().map(DataRow::from).collect();
+
+    storage.append_data(&schema.table_name, rows).await
+}
+
+/// Drops the tables `names`, skipping the missing ones when `if_exists`, and returns the number
+/// of tables dropped.
+///
+/// Every table is looked up before any is dropped, so that a missing table leaves all of them.
+/// A view is dropped with `DROP VIEW` instead.
+pub async fn drop_table<T: GStore + GStoreMut>(
+    storage: &mut T,
+    names: &[String],
+    if_exists: bool,
+) -> Result<usize> {
+    let mut existing = Vec::new();
+    for name in names {
+        match (storage.fetch_schema(name).await?, if_exists) {
+            (Some(Schema { view: Some(_), .. }), _) => {
+                return Err(AlterError::NotATable(name.to_owned()).into());
+            }
+            (Some(_), _) => existing.push(name),
+            (None, true) => {}
+            (None, false) => return Err(AlterError::TableNotFound(name.to_owned()).into()),
+        }
+    }
+
+    for name in &existing {
+        storage.delete_schema(name).await?;
+    }
+
+    Ok(existing.len())
+}
+
+fn validate_rows(column_defs: &[ColumnDef], rows: &[Row]) -> Result<()> {
+    for row in rows {
+        let Row::Vec { values, .. } = row else {
+            return Err(RowError::ConflictOnUnexpectedMapRowFound.into());
+        };
+
+        if values.len() != column_defs.len() {
+            return Err(InsertError::WrongNumberOfValues {
+                expected: column_defs.len(),
+                found: values.len(),
+            }
+            .into());
+        }
+
+        for (value, column_def) in values.iter().zip(column_defs) {
+            value.validate_type(&column_def.data_type)?;
+            value.validate_null(column_def.nullable)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn infer_column_defs(
+    labels: Vec<String>,
+    types: Option<Vec<Option<DataType>>>,
+    rows: &[Row],
+) -> Vec<ColumnDef> {
+    let types = types
+        .filter(|types| types.len() == labels.len())
+        .unwrap_or_else(|| vec![None; labels.len()]);
+
+    labels
+        .into_iter()
+        .zip(types)
+        .enumerate()
+        .map(|(i, (name, data_type))| {
+            let data_type = data_type
+                .or_else(|| {
+                    rows.iter().find_map(|row| match row {
+                        Row::Vec { values, .. } => values.get(i).and_then(Value::get_type),
+                        Row::Map(_) => None,
+                    })
+                })
+                .unwrap_or(DataType::Text);
+
+            ColumnDef {
+                name,
+                data_type,
+                nullable: true,
+                default: None,
+                comment: None,
+            }
+        })
+        .collect()
+}
+
+fn validate_column_names(column_defs: &[ColumnDef]) -> Result<()> {
+    let mut names = HashSet::new();
+
+    match column_defs
+        .iter()
+        .find(|column_def| !names.insert(column_def.name.as_str()))
+    {
+        Some(column_def) => Err(AlterError::DuplicateColumnName(column_def.name.clone()).into()),
+        None => Ok(()),
+    }
+}
+
+/// Types of the columns `query` outputs, `None` when their number does not follow from the
+/// schemas, as for a wildcard over a schemaless table.
+#[async_recursion(?Send)]
+async fn fetch_query_types<T>(storage: &T, query: &Query) -> Result<Option<Vec<Option<DataType>>>>
+where
+    T: GStore,
+{
+    fetch_set_expr_types(storage, &query.body).await
+}
+
+#[async_recursion(?Send)]
+async fn fetch_set_expr_types<T>(
+    storage: &T,
+    set_expr: &SetExpr,
+) -> Result<Option<Vec<Option<DataType>>>>
+where
+    T: GStore,
+{
+    let select = match set_expr {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(Values(values_list)) => {
+            let types = values_list[0]
+                .iter()
+                .map(|expr| expr_type(expr, &[]))
+                .collect();
+
+            return Ok(Some(types));
+        }
+        SetExpr::SetOperation { left, .. } => return fetch_set_expr_types(storage, left).await,
+    };
+
+    let Select {
+        projection, from, ..
+    } = select.as_ref();
+
+    let mut relations = vec![(
+        get_alias(&from.relation),
+        fetch_relation_types(storage, &from.relation).await?,
+    )];
+    for join in &from.joins {
+        relations.push((
+            get_alias(&join.relation),
+            fetch_relation_types(storage, &join.relation).await?,
+        ));
+    }
+
+    let mut types = Vec::new();
+    for item in projection {
+        match item {
+            SelectItem::Expr { expr, .. } => types.push(expr_type(expr, &relations)),
+            SelectItem::Wildcard => {
+                for (_, columns) in &relations {
+                    let Some(columns) = columns else {
+                        return Ok(None);
+                    };
+
+                    types.extend(columns.iter().map(|(_, data_type)| data_type.clone()));
+                }
+            }
+            SelectItem::QualifiedWildcard(alias) => {
+                let columns = relations
+                    .iter()
+                    .find(|(relation_alias, _)| *relation_alias == alias)
+                    .and_then(|(_, columns)| columns.as_ref());
+                let Some(columns) = columns else {
+                    return Ok(None);
+                };
+
+                types.extend(columns.iter().map(|(_, data_type)| data_type.clone()));
+            }
+        }
+    }
+
+    Ok(Some(types))
+}
+
+async fn fetch_relation_types<T: GStore>(
+    storage: &T,
+    table_factor: &TableFactor,
+) -> Result<Option<Columns>> {
+    let Some(names) = fetch_relation_columns(storage, table_factor).await? else {
+        return Ok(None);
+    };
+
+    let types = match table_factor {
+        TableFactor::Table {
+            chain_name, name, ..
+        } => storage
+            .fetch_schema(&qualified_table_name(chain_name.as_deref(), name))
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .map(|column_defs| {
+                column_defs
+                    .into_iter()
+                    .map(|column_def| Some(column_def.data_type))
+                    .collect()
+            }),
+        TableFactor::Series { .. } => Some(vec![Some(DataType::Int)]),
+        TableFactor::Dictionary { .. } => None,
+        TableFactor::Derived { subquery, .. } => fetch_query_types(storage, subquery).await?,
+    };
+    let types = types
+        .filter(|types: &Vec<_>| types.len() == names.len())
+        .unwrap_or_else(|| vec![None; names.len()]);
+
+    Ok(Some(names.into_iter().zip(types).collect()))
+}
+
+/// Type of the values `expr` evaluates to over `relations`, when it follows from them.
+fn expr_type(expr: &Expr, relations: &[(&String, Option<Columns>)]) -> Option<DataType> {
+    let column_type = |columns: &Option<Columns>, ident: &str| {
+        columns
+            .iter()
+            .flatten()
+            .find(|(name, _)| name == ident)
+            .map(|(_, data_type)| data_type.clone())
+    };
+
+    match expr {
+        Expr::Identifier(ident) => relations
+            .iter()
+            .find_map(|(_, columns)| column_type(columns, ident))
+            .flatten(),
+        Expr::CompoundIdentifier { alias, ident } => relations
+            .iter()
+            .find(|(relation_alias, _)| *relation_alias == alias)
+            .and_then(|(_, columns)| column_type(columns, ident))
+            .flatten(),
+        Expr::Literal(literal) => Literal::try_from(literal)
+            .and_then(|literal| Value::try_from(&literal))
+            .ok()
+            .and_then(|value| value.get_type()),
+        Expr::TypedString { data_type, .. } => Some(data_type.clone()),
+        Expr::Function(function) => match function.as_ref() {
+            Function::Cast { data_type, .. } => Some(data_type.clone()),
+            _ => None,
+        },
+        Expr::Nested(expr) => expr_type(expr, relations),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            ..
+        } => Some(DataType::Boolean),
+        Expr::UnaryOp {
+            op: UnaryOperator::Plus | UnaryOperator::Minus,
+            expr,
+        } => expr_type(expr, relations).filter(DataType::is_numeric),
+        Expr::BinaryOp { left, op, right } => match op {
+            BinaryOperator::Plus
+            | BinaryOperator::Minus
+            | BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo => {
+                let left = expr_type(left, relations).filter(DataType::is_numeric)?;
+                let right = expr_type(right, relations)?;
+
+                (left == right).then_some(left)
+            }
+            BinaryOperator::StringConcat => Some(DataType::Text),
+            BinaryOperator::Gt
+            | BinaryOperator::Lt
+            | BinaryOperator::GtEq
+            | BinaryOperator::LtEq
+            | BinaryOperator::Eq
+            | BinaryOperator::NotEq
+            | BinaryOperator::And
+            | BinaryOperator::Or
+            | BinaryOperator::Xor => Some(DataType::Boolean),
+            _ => None,
+        },
+        Expr::IsNull(_)
+        | Expr::IsNotNull(_)
+        | Expr::InList { .. }
+        | Expr::InSubquery { .. }
+        | Expr::Between { .. }
+        | Expr::Like { .. }
+        | Expr::ILike { .. }
+        | Expr::Exists { .. } => Some(DataType::Boolean),
+        Expr::Aggregate(aggregate) => match aggregate.as_ref() {
+            Aggregate {
+                func: AggregateFunction::Count(_),
+                ..
+            } => Some(DataType::Int),
+            Aggregate {
+                func: AggregateFunction::Max(expr) | AggregateFunction::Min(expr),
+                ..
+            } => expr_type(expr, relations),
+            _ => None,
+        },
+        _ => None,
+    }
+}
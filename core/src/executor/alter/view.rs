@@ -0,0 +1,147 @@
+use {
+    super::{
+        table::{fetch_source, insert_table},
+        AlterError,
+    },
+    crate::{
+        ast::{ColumnDef, Query, Statement},
+        data::{Row, Schema, SchemaView},
+        plan::plan,
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+};
+
+/// Creates the view `name` reading its rows from `query`, replacing an existing view when
+/// `or_replace`.
+///
+/// A materialized view is filled with the rows of `query` on creation, and replacing it takes a
+/// new snapshot. The rows are fetched before the previous view is dropped, so that the query
+/// may read it.
+pub async fn create_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    query: &Query,
+    or_replace: bool,
+    materialized: bool,
+) -> Result<()> {
+    let existing = storage.fetch_schema(name).await?;
+    match &existing {
+        Some(Schema { view: None, .. }) => {
+            return Err(AlterError::TableAlreadyExists(name.to_owned()).into());
+        }
+        Some(_) if !or_replace => {
+            return Err(AlterError::ViewAlreadyExists(name.to_owned()).into());
+        }
+        _ => {}
+    }
+
+    let (column_defs, rows) = match materialized {
+        true => fetch_view_source(storage, query).await?,
+        false => (None, Vec::new()),
+    };
+
+    if existing.is_some() {
+        storage.delete_schema(name).await?;
+    }
+
+    let schema = Schema {
+        table_name: name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        comment: None,
+        chain_name: None,
+        view: Some(SchemaView {
+            query: query.clone(),
+            materialized,
+        }),
+    };
+
+    insert_table(storage, &schema, rows).await
+}
+
+/// Replaces the rows of the materialized view `name` with the rows of its query, and returns the
+/// number of rows it holds.
+pub async fn refresh_view<T: GStore + GStoreMut>(storage: &mut T, name: &str) -> Result<usize> {
+    let query = match storage.fetch_schema(name).await? {
+        Some(Schema {
+            view:
+                Some(SchemaView {
+                    query,
+                    materialized: true,
+                }),
+            ..
+        }) => query,
+        Some(_) => return Err(AlterError::NotAMaterializedView(name.to_owned()).into()),
+        None => return Err(AlterError::ViewNotFound(name.to_owned()).into()),
+    };
+
+    let (_, rows) = fetch_view_source(storage, &query).await?;
+    let keys = storage
+        .scan_data(name)
+        .await?
+        .map_ok(|(key, _)| key)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    storage.delete_data(name, keys).await?;
+
+    let num_rows = rows.len();
+    if num_rows > 0 {
+        let rows = rows.into_iter().map(DataRow::from).collect();
+
+        storage.append_data(name, rows).await?;
+    }
+
+    Ok(num_rows)
+}
+
+/// Columns of the view with the query `query`, typed as a materialized view would be, or `None`
+/// when the query reads a schemaless table.
+pub async fn fetch_view_columns<T: GStore>(
+    storage: &T,
+    query: &Query,
+) -> Result<Option<Vec<ColumnDef>>> {
+    fetch_view_source(storage, query)
+        .await
+        .map(|(column_defs, _)| column_defs)
+}
+
+/// Columns and rows of `query`, planned against the schemas as they stand, so that the views it
+/// reads are expanded.
+async fn fetch_view_source<T: GStore>(
+    storage: &T,
+    query: &Query,
+) -> Result<(Option<Vec<ColumnDef>>, Vec<Row>)> {
+    let query = match plan(storage, Statement::Query(query.clone())).await? {
+        Statement::Query(query) => query,
+        _ => unreachable!("a query is planned into a query"),
+    };
+
+    fetch_source(storage, None, Some(&query)).await
+}
+
+/// Drops the views `names`, skipping the missing ones when `if_exists`, and returns the number
+/// of views dropped.
+pub async fn drop_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    names: &[String],
+    if_exists: bool,
+) -> Result<usize> {
+    let mut existing = Vec::new();
+    for name in names {
+        match (storage.fetch_schema(name).await?, if_exists) {
+            (Some(Schema { view: Some(_), .. }), _) => existing.push(name),
+            (Some(_), _) => return Err(AlterError::NotAView(name.to_owned()).into()),
+            (None, true) => {}
+            (None, false) => return Err(AlterError::ViewNotFound(name.to_owned()).into()),
+        }
+    }
+
+    for name in &existing {
+        storage.delete_schema(name).await?;
+    }
+
+    Ok(existing.len())
+}
@@ -19,6 +19,12 @@ pub enum RowContext<'a> {
         left: Rc<RowContext<'a>>,
         right: Rc<RowContext<'a>>,
     },
+    /// Arguments of a call to the user function `name`, nested in `depth` calls including it.
+    Function {
+        name: &'a str,
+        args: Row,
+        depth: usize,
+    },
 }
 
 impl<'a> RowContext<'a> {
@@ -52,6 +58,7 @@ impl<'a> RowContext<'a> {
                 .position(|column| column == target)
                 .and_then(|index| values.get(index)),
             Self::RefMapData(values) => values.get(target),
+            Self::Function { args, .. } => args.get_value(target),
         }
     }
 
@@ -78,6 +85,9 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_value(target_table_alias, target)
                 .or_else(|| right.get_alias_value(target_table_alias, target)),
+            Self::Function { name, args, .. } if *name == target_table_alias => {
+                args.get_value(target)
+            }
             _ => None,
         }
     }
@@ -94,6 +104,9 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_entries(alias)
                 .or_else(|| right.get_alias_entries(alias)),
+            Self::Function { name, args, .. } if *name == alias => {
+                Some(args.iter().map(|(k, v)| (k, v.clone())).collect())
+            }
             _ => None,
         }
     }
@@ -115,7 +128,20 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => {
                 [left.get_all_entries(), right.get_all_entries()].concat()
             }
+            Self::Function { args, .. } => args.iter().map(|(k, v)| (k, v.clone())).collect(),
             _ => vec![],
         }
     }
+
+    /// Number of user function calls the context is evaluated in.
+    pub fn function_depth(&self) -> usize {
+        match self {
+            Self::Function { depth, .. } => *depth,
+            Self::Data {
+                next: Some(next), ..
+            } => next.function_depth(),
+            Self::Bridge { left, right } => left.function_depth().max(right.function_depth()),
+            _ => 0,
+        }
+    }
 }
@@ -0,0 +1,52 @@
+use {
+    super::{
+        fetch::{fetch, FetchError},
+        ExecuteError,
+    },
+    crate::{
+        ast::Expr,
+        data::Schema,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+    std::rc::Rc,
+};
+
+/// Deletes the rows of `table_name` satisfying `selection`, and returns the number of rows
+/// deleted. Views are not written to.
+pub async fn delete<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    selection: Option<&Expr>,
+) -> Result<usize> {
+    let Schema {
+        column_defs, view, ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| FetchError::TableNotFound(table_name.to_owned()))?;
+
+    if view.is_some() {
+        return Err(ExecuteError::NotATable(table_name.to_owned()).into());
+    }
+
+    let columns = column_defs.map(|column_defs| {
+        column_defs
+            .into_iter()
+            .map(|column_def| column_def.name)
+            .collect::<Rc<[String]>>()
+    });
+    let keys = fetch(storage, table_name, columns, selection)
+        .await?
+        .map_ok(|(key, _)| key)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let num_rows = keys.len();
+    if num_rows > 0 {
+        storage.delete_data(table_name, keys).await?;
+    }
+
+    Ok(num_rows)
+}
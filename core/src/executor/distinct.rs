@@ -0,0 +1,185 @@
+use {
+    super::{context::RowContext, evaluate::evaluate},
+    crate::{
+        ast::{Aggregate, Distinct as AstDistinct},
+        data::{Key, Point, Row, Value},
+        result::{Error, Result},
+        store::GStore,
+    },
+    futures::{
+        future,
+        stream::{self, Stream, StreamExt, TryStreamExt},
+    },
+    im_rc::HashMap,
+    itertools::Itertools,
+    ordered_float::OrderedFloat,
+    std::{
+        borrow::{Borrow, Cow},
+        collections::HashSet,
+        rc::Rc,
+    },
+};
+
+type Item<'a, R> = (
+    Option<Rc<HashMap<&'a Aggregate, Value>>>,
+    Rc<RowContext<'a>>,
+    R,
+);
+
+/// Hashable form of a value of any type, equal for equal values, by which `DISTINCT` and set
+/// operations deduplicate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DistinctKey {
+    Key(Key),
+    /// Entries sorted by name.
+    Map(Vec<(String, DistinctKey)>),
+    List(Vec<DistinctKey>),
+    Point(OrderedFloat<f64>, OrderedFloat<f64>),
+}
+
+impl TryFrom<&Value> for DistinctKey {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Self> {
+        match value {
+            Value::Map(values) => values
+                .iter()
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .map(|(name, value)| Ok((name.to_owned(), Self::try_from(value)?)))
+                .collect::<Result<_>>()
+                .map(Self::Map),
+            Value::List(values) => values
+                .iter()
+                .map(Self::try_from)
+                .collect::<Result<_>>()
+                .map(Self::List),
+            Value::Point(Point { x, y }) => Ok(Self::Point(OrderedFloat(*x), OrderedFloat(*y))),
+            value => Key::try_from(value).map(Self::Key),
+        }
+    }
+}
+
+/// Projected row, along with the key `SELECT DISTINCT` deduplicates it by.
+pub struct DistinctRow {
+    row: Row,
+    key: Option<Vec<DistinctKey>>,
+}
+
+impl Borrow<Row> for DistinctRow {
+    fn borrow(&self) -> &Row {
+        &self.row
+    }
+}
+
+pub struct Distinct<'a, T: GStore> {
+    storage: &'a T,
+    context: Option<Rc<RowContext<'a>>>,
+    distinct: Option<&'a AstDistinct>,
+}
+
+impl<'a, T: GStore> Distinct<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        context: Option<Rc<RowContext<'a>>>,
+        distinct: Option<&'a AstDistinct>,
+    ) -> Self {
+        Self {
+            storage,
+            context,
+            distinct,
+        }
+    }
+
+    /// Attaches to each projected row its key, before the rows are sorted as `DISTINCT ON` keeps
+    /// the first row of each key in the `ORDER BY` order.
+    pub fn key(
+        self,
+        rows: impl Stream<Item = Result<Item<'a, Row>>> + 'a,
+        table_alias: &'a str,
+    ) -> impl Stream<Item = Result<Item<'a, DistinctRow>>> + 'a {
+        let Self {
+            storage,
+            context,
+            distinct,
+        } = self;
+
+        rows.and_then(move |(aggregated, next, row)| {
+            let context = context.as_ref().map(Rc::clone);
+
+            async move {
+                let key = match distinct {
+                    None => None,
+                    Some(AstDistinct::Distinct) => Some(row_key(&row)?),
+                    Some(AstDistinct::On(exprs)) => {
+                        let filter_context = match context {
+                            Some(context) => Rc::new(RowContext::concat(Rc::clone(&next), context)),
+                            None => Rc::clone(&next),
+                        };
+                        let label_context =
+                            Rc::new(RowContext::new(table_alias, Cow::Borrowed(&row), None));
+                        let filter_context = Rc::new(RowContext::concat(
+                            filter_context,
+                            Rc::clone(&label_context),
+                        ));
+
+                        let keys = stream::iter(exprs)
+                            .then(|expr| {
+                                let context = Some(Rc::clone(&filter_context));
+                                let aggregated = aggregated.as_ref().map(Rc::clone);
+
+                                async move {
+                                    let value: Value = evaluate(storage, context, aggregated, expr)
+                                        .await?
+                                        .try_into()?;
+
+                                    DistinctKey::try_from(&value)
+                                }
+                            })
+                            .try_collect::<Vec<_>>()
+                            .await?;
+
+                        drop(label_context);
+                        drop(filter_context);
+
+                        Some(keys)
+                    }
+                };
+
+                Ok((aggregated, next, DistinctRow { row, key }))
+            }
+        })
+    }
+}
+
+/// Key of the whole `row`, equal for rows of the same values.
+pub fn row_key(row: &Row) -> Result<Vec<DistinctKey>> {
+    match row {
+        Row::Vec { values, .. } => values.iter().map(DistinctKey::try_from).collect(),
+        Row::Map(_) => row
+            .iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .flat_map(|(name, value)| {
+                [
+                    Ok(DistinctKey::Key(Key::Str(name.to_owned()))),
+                    DistinctKey::try_from(value),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// Keeps the first row of each key.
+pub fn dedup<'a>(
+    rows: impl Stream<Item = Result<DistinctRow>> + 'a,
+) -> impl Stream<Item = Result<Row>> + 'a {
+    let mut keys = HashSet::new();
+
+    rows.try_filter_map(move |DistinctRow { row, key }| {
+        let row = match key {
+            Some(key) => keys.insert(key).then_some(row),
+            None => Some(row),
+        };
+
+        future::ready(Ok(row))
+    })
+}
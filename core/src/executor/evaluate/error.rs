@@ -159,6 +159,9 @@ pub enum EvaluateError {
     #[error("unsupported function: {0}")]
     UnsupportedFunction(String),
 
+    #[error("function {0} is nested too deep, it may call itself")]
+    FunctionNestedTooDeep(String),
+
     #[error("The provided arguments are non-comparable: {0}")]
     NonComparableArgumentError(String),
 
@@ -8,7 +8,7 @@ use {
     super::{context::RowContext, select::select},
     crate::{
         ast::{Aggregate, Expr, Function},
-        data::{Interval, Literal, Row, Value},
+        data::{CustomFunction, Interval, Literal, Row, Value},
         mock::MockStorage,
         result::{Error, Result},
         store::GStore,
@@ -25,6 +25,10 @@ use {
 
 pub use {error::EvaluateError, evaluated::Evaluated};
 
+/// Calls of user functions nested deeper than this are taken as a function calling itself
+/// endlessly.
+const MAX_FUNCTION_DEPTH: usize = 32;
+
 #[async_recursion(?Send)]
 pub async fn evaluate<'a, 'b: 'a, 'c: 'a, T>(
     storage: &'a T,
@@ -156,7 +160,12 @@ where
             let context = context.as_ref().map(Rc::clone);
             let aggregated = aggregated.as_ref().map(Rc::clone);
 
-            evaluate_function(storage, context, aggregated, func).await
+            match func.as_ref() {
+                Function::Custom { name, exprs } => {
+                    evaluate_custom_function(storage, context, aggregated, name, exprs).await
+                }
+                _ => evaluate_function(storage, context, aggregated, func).await,
+            }
         }
         Expr::InList {
             expr,
@@ -324,6 +333,77 @@ where
     }
 }
 
+/// Evaluates the body of the user function `name` given the values of `exprs` as arguments.
+///
+/// Kept apart from [`evaluate_function`], so that a function calling itself does not go through
+/// the frame of every built-in function on each call. The body is evaluated in a context
+/// counting the calls it is nested in, so that a function calling itself endlessly fails once
+/// nested deeper than [`MAX_FUNCTION_DEPTH`].
+async fn evaluate_custom_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
+    storage: Option<&'a T>,
+    context: Option<Rc<RowContext<'b>>>,
+    aggregated: Option<Rc<HashMap<&'c Aggregate, Value>>>,
+    name: &'b str,
+    exprs: &'b [Expr],
+) -> Result<Evaluated<'a>> {
+    let eval = |expr| {
+        let context = context.as_ref().map(Rc::clone);
+        let aggregated = aggregated.as_ref().map(Rc::clone);
+
+        evaluate_inner(storage, context, aggregated, expr)
+    };
+
+    let CustomFunction {
+        func_name,
+        args,
+        body,
+    } = storage
+        .ok_or(EvaluateError::UnsupportedCustomFunction)?
+        .fetch_function(name)
+        .await?
+        .ok_or_else(|| EvaluateError::UnsupportedFunction(name.to_owned()))?;
+
+    let depth = context
+        .as_ref()
+        .map_or(0, |context| context.function_depth())
+        + 1;
+    if depth > MAX_FUNCTION_DEPTH {
+        return Err(EvaluateError::FunctionNestedTooDeep(func_name.to_owned()).into());
+    }
+
+    let min = args.iter().filter(|arg| arg.default.is_none()).count();
+    let max = args.len();
+
+    if !(min..=max).contains(&exprs.len()) {
+        return Err(EvaluateError::FunctionArgsLengthNotWithinRange {
+            name: func_name.to_owned(),
+            expected_minimum: min,
+            expected_maximum: max,
+            found: exprs.len(),
+        }
+        .into());
+    }
+
+    let defaults = args
+        .iter()
+        .skip(exprs.len())
+        .filter_map(|arg| arg.default.as_ref());
+    let mut values = HashMap::new();
+    for (arg, expr) in args.iter().zip(exprs.iter().chain(defaults)) {
+        let value = eval(expr).await?.try_into_value(&arg.data_type, true)?;
+
+        values.insert(arg.name.to_owned(), value);
+    }
+
+    let context = RowContext::Function {
+        name: func_name,
+        args: Row::Map(values.into_iter().collect()),
+        depth,
+    };
+
+    evaluate_inner(storage, Some(Rc::new(context)), None, body).await
+}
+
 async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
     storage: Option<&'a T>,
     context: Option<Rc<RowContext<'b>>>,
@@ -342,6 +422,8 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
     let name = func.to_string();
 
     let result = match func {
+        Function::Custom { .. } => unreachable!("user functions are evaluated apart"),
+
         // --- text ---
         Function::Concat(exprs) => {
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
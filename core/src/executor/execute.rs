@@ -1,11 +1,21 @@
 use {
-    super::select::select_with_labels,
+    super::{
+        alter::{
+            create_function, create_index, create_table, create_view, drop_function, drop_index,
+            drop_table, drop_view, fetch_view_columns, refresh_view,
+        },
+        delete::delete,
+        explain::{explain, ExplainRow},
+        insert::insert,
+        select::select_with_labels,
+        update::update,
+    },
     crate::{
-        ast::{DataType, Show, Statement, Variable},
-        data::Value,
+        ast::{qualified_table_name, ColumnDef, DataType, Show, Statement, Variable},
+        data::{Schema, SchemaView, Value},
         error::Error,
         result::Result,
-        store::GStore,
+        store::{GStore, GStoreMut},
     },
     futures::stream::{StreamExt, TryStreamExt},
     reqwest::Client,
@@ -30,6 +40,10 @@ struct Entity {
 pub enum ExecuteError {
     #[error("table not found: {0}")]
     TableNotFound(String),
+    #[error("not a table: {0}")]
+    NotATable(String),
+    #[error("no columns defined for schemaless table: {0}")]
+    SchemalessTable(String),
     #[error("Network error: {0}")]
     RequestFailed(String),
     #[error("Invalid HTTP response: {0}")]
@@ -42,7 +56,7 @@ pub enum ExecuteError {
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub enum Payload {
-    ShowColumns(Vec<(String, DataType)>),
+    ShowColumns(Vec<ColumnDef>),
     ShowChains(Vec<(String, String)>),
     ShowChainsEntities(Vec<(String, String)>),
     Select {
@@ -50,7 +64,23 @@ pub enum Payload {
         rows: Vec<Vec<Value>>,
     },
     SelectMap(Vec<HashMap<String, Value>>),
+    Explain(Vec<ExplainRow>),
     ShowVariable(PayloadVariable),
+    Insert(usize),
+    Update(usize),
+    Delete(usize),
+    Create,
+    DropTable(usize),
+    CreateIndex,
+    DropIndex,
+    CreateFunction,
+    DropFunction(usize),
+    CreateView,
+    DropView(usize),
+    RefreshView(usize),
+    StartTransaction,
+    Commit,
+    Rollback,
 }
 
 impl Payload {
@@ -93,24 +123,132 @@ pub enum PayloadVariable {
     Version(String),
 }
 
-pub async fn execute<T: GStore >(
+pub async fn execute<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
 ) -> Result<Payload> {
+    if matches!(
+        statement,
+        Statement::StartTransaction | Statement::Commit | Statement::Rollback
+    ) {
+        return execute_inner(storage, statement).await;
+    }
+
+    let autocommit = storage.begin(true).await?;
     let result = execute_inner(storage, statement).await;
 
+    if !autocommit {
+        return result;
+    }
+
     match result {
-        Ok(payload) => Ok(payload),
-        Err(error) => Err(error),
-        
+        Ok(payload) => storage.commit().await.map(|_| payload),
+        Err(error) => {
+            storage.rollback().await?;
+
+            Err(error)
+        }
     }
 }
 
-async fn execute_inner<T: GStore >(
+async fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
 ) -> Result<Payload> {
     match statement {
+        //- Modification
+        //-- Tables
+        Statement::CreateTable {
+            if_not_exists,
+            name,
+            columns,
+            source,
+            comment,
+        } => create_table(
+            storage,
+            name,
+            columns.as_deref(),
+            *if_not_exists,
+            source.as_deref(),
+            comment.as_deref(),
+        )
+        .await
+        .map(|_| Payload::Create),
+        Statement::DropTable { if_exists, names } => drop_table(storage, names, *if_exists)
+            .await
+            .map(Payload::DropTable),
+
+        //-- Indexes
+        Statement::CreateIndex {
+            name,
+            table_name,
+            column,
+        } => create_index(storage, table_name, name, column)
+            .await
+            .map(|_| Payload::CreateIndex),
+        Statement::DropIndex { name, table_name } => drop_index(storage, table_name, name)
+            .await
+            .map(|_| Payload::DropIndex),
+
+        //-- Functions
+        Statement::CreateFunction {
+            or_replace,
+            name,
+            args,
+            return_,
+        } => create_function(storage, name, args, *or_replace, return_)
+            .await
+            .map(|_| Payload::CreateFunction),
+        Statement::DropFunction { if_exists, names } => drop_function(storage, names, *if_exists)
+            .await
+            .map(Payload::DropFunction),
+
+        //-- Views
+        Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            query,
+        } => create_view(storage, name, query, *or_replace, *materialized)
+            .await
+            .map(|_| Payload::CreateView),
+        Statement::DropView { if_exists, names } => drop_view(storage, names, *if_exists)
+            .await
+            .map(Payload::DropView),
+        Statement::RefreshView { name } => {
+            refresh_view(storage, name).await.map(Payload::RefreshView)
+        }
+
+        //-- Rows
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+        } => insert(storage, table_name, columns, source)
+            .await
+            .map(Payload::Insert),
+        Statement::Update {
+            table_name,
+            assignments,
+            selection,
+        } => update(storage, table_name, assignments, selection.as_ref())
+            .await
+            .map(Payload::Update),
+        Statement::Delete {
+            table_name,
+            selection,
+        } => delete(storage, table_name, selection.as_ref())
+            .await
+            .map(Payload::Delete),
+
+        //- Transaction
+        Statement::StartTransaction => storage
+            .begin(false)
+            .await
+            .map(|_| Payload::StartTransaction),
+        Statement::Commit => storage.commit().await.map(|_| Payload::Commit),
+        Statement::Rollback => storage.rollback().await.map(|_| Payload::Rollback),
+
         //- Selection
         Statement::Query(query) => {
             let (labels, rows) = select_with_labels(storage, query, None).await?;
@@ -128,6 +266,9 @@ async fn execute_inner<T: GStore >(
                     .map(Payload::SelectMap),
             }
         }
+        Statement::Explain { analyze, query } => explain(storage, query, *analyze)
+            .await
+            .map(Payload::Explain),
 
         Statement::Show(show_stmt) => match show_stmt {
             Show::Variable(variable) => match variable {
@@ -140,8 +281,26 @@ async fn execute_inner<T: GStore >(
                     let chains = fetch_chains().await?;
                     Ok(Payload::ShowChains(chains))
                 }
+                Variable::Functions => {
+                    let functions = storage
+                        .fetch_all_functions()
+                        .await?
+                        .into_iter()
+                        .map(|function| function.to_str())
+                        .collect();
+
+                    Ok(Payload::ShowVariable(PayloadVariable::Functions(functions)))
+                }
                 Variable::Tables => {
-                    let tables: Vec<String> = Vec::new();
+                    let tables = storage
+                        .fetch_all_schemas()
+                        .await?
+                        .into_iter()
+                        .map(|schema| {
+                            qualified_table_name(schema.chain_name.as_deref(), &schema.table_name)
+                        })
+                        .collect();
+
                     Ok(Payload::ShowVariable(PayloadVariable::Tables(tables)))
                 }
             },
@@ -156,15 +315,43 @@ async fn execute_inner<T: GStore >(
                 entity_name,
             } => {
                 let schema_result = fetch_entity_schemas(chain_name, entity_name).await?;
-                let columns: Vec<(String, DataType)> = schema_result
+                let columns: Vec<ColumnDef> = schema_result
                     .into_iter()
                     .flat_map(|(k, v)| match DataType::from_str(&v) {
-                        Ok(data_type) => Some((k, data_type)),
+                        Ok(data_type) => Some(ColumnDef {
+                            name: k,
+                            data_type,
+                            nullable: true,
+                            default: None,
+                            comment: None,
+                        }),
                         Err(_) => None,
                     })
                     .collect();
                 Ok(Payload::ShowColumns(columns))
             }
+
+            Show::Columns { table_name } => {
+                let schema = storage
+                    .fetch_schema(table_name)
+                    .await?
+                    .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+                let column_defs = match schema {
+                    Schema {
+                        column_defs: Some(column_defs),
+                        ..
+                    } => Some(column_defs),
+                    Schema {
+                        view: Some(SchemaView { query, .. }),
+                        ..
+                    } => fetch_view_columns(storage, &query).await?,
+                    Schema { .. } => None,
+                };
+
+                column_defs
+                    .map(Payload::ShowColumns)
+                    .ok_or_else(|| ExecuteError::SchemalessTable(table_name.to_owned()).into())
+            }
         },
     }
 }
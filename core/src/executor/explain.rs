@@ -0,0 +1,563 @@
+use {
+    super::{aggregate::check_aggregate, pushdown::Pushdown, select::select_with_profile},
+    crate::{
+        ast::{
+            qualified_table_name, BinaryOperator, Distinct, Expr, IndexItem, Join, JoinConstraint,
+            JoinExecutor, JoinOperator, Query, Select, SetExpr, TableAlias, TableFactor,
+            TableWithJoins, ToSql, Values,
+        },
+        data::get_alias,
+        result::Result,
+        store::GStore,
+    },
+    async_recursion::async_recursion,
+    futures::stream::{self, Stream, TryStreamExt},
+    serde::{Deserialize, Serialize},
+    std::{
+        cell::Cell,
+        pin::Pin,
+        rc::Rc,
+        task::Poll,
+        time::{Duration, Instant},
+    },
+    strum_macros::Display,
+};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Display)]
+pub enum Operator {
+    Values,
+    SetOperation,
+    Scan,
+    Join,
+    Filter,
+    Aggregate,
+    Project,
+    Sort,
+    Distinct,
+    Limit,
+}
+
+/// Rows output by an operator, and time spent producing them, including the time spent in the
+/// operators it reads from.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Analyzed {
+    pub rows: usize,
+    pub elapsed: Duration,
+}
+
+/// Operator of the plan shown by `EXPLAIN`, followed by the operators it reads from at
+/// `depth + 1`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExplainRow {
+    pub depth: usize,
+    pub operator: Operator,
+    pub detail: String,
+    /// Filled by `EXPLAIN ANALYZE`.
+    pub analyzed: Option<Analyzed>,
+}
+
+#[derive(Default)]
+pub struct Meter {
+    rows: Cell<usize>,
+    elapsed: Cell<Duration>,
+}
+
+impl Meter {
+    /// Adds the time spent since `started`, such as the time a blocking operator spends
+    /// reading all of its input before yielding its first row.
+    pub fn add_elapsed(&self, started: Instant) {
+        self.elapsed.set(self.elapsed.get() + started.elapsed());
+    }
+
+    fn analyzed(&self) -> Analyzed {
+        Analyzed {
+            rows: self.rows.get(),
+            elapsed: self.elapsed.get(),
+        }
+    }
+}
+
+/// Counts on `meter` the rows of `rows` and the time spent polling them, `rows` being left
+/// untouched when no meter is given.
+pub fn meter<'a, T: 'a>(
+    meter: Option<Rc<Meter>>,
+    rows: impl Stream<Item = Result<T>> + 'a,
+) -> impl Stream<Item = Result<T>> + 'a {
+    let mut rows: Pin<Box<dyn Stream<Item = Result<T>> + 'a>> = Box::pin(rows);
+
+    stream::poll_fn(move |cx| {
+        let Some(meter) = &meter else {
+            return rows.as_mut().poll_next(cx);
+        };
+
+        let started = Instant::now();
+        let polled = rows.as_mut().poll_next(cx);
+        meter.add_elapsed(started);
+
+        if let Poll::Ready(Some(Ok(_))) = polled {
+            meter.rows.set(meter.rows.get() + 1);
+        }
+
+        polled
+    })
+}
+
+/// Meters of the operators of a `SELECT`, filled while it runs under `EXPLAIN ANALYZE`.
+///
+/// The operands of a set operation are measured on profiles of their own, while the operators
+/// of subqueries are left unmeasured.
+#[derive(Default)]
+pub struct Profile {
+    pub scan: Rc<Meter>,
+    pub joins: Vec<Rc<Meter>>,
+    /// Scans of the relations read by each join.
+    pub join_scans: Vec<Rc<Meter>>,
+    pub filter: Rc<Meter>,
+    pub aggregate: Rc<Meter>,
+    pub project: Rc<Meter>,
+    pub sort: Rc<Meter>,
+    pub distinct: Rc<Meter>,
+    pub limit: Rc<Meter>,
+    /// Profiles of the left and right operands of a set operation.
+    pub operands: Vec<Rc<Profile>>,
+}
+
+impl Profile {
+    fn new(set_expr: &SetExpr) -> Self {
+        let meters = |n| (0..n).map(|_| Rc::default()).collect();
+
+        match set_expr {
+            SetExpr::Select(select) => Self {
+                joins: meters(select.from.joins.len()),
+                join_scans: meters(select.from.joins.len()),
+                ..Self::default()
+            },
+            SetExpr::Values(_) => Self::default(),
+            SetExpr::SetOperation { left, right, .. } => Self {
+                operands: vec![Rc::new(Self::new(left)), Rc::new(Self::new(right))],
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// Plan of `query`, along with the rows output by and the time spent in each operator when
+/// `analyze`, for which `query` is run to completion.
+pub async fn explain<T: GStore>(
+    storage: &T,
+    query: &Query,
+    analyze: bool,
+) -> Result<Vec<ExplainRow>> {
+    let profile = match analyze {
+        true => {
+            let profile = Rc::new(Profile::new(&query.body));
+            let (_, rows) =
+                select_with_profile(storage, query, None, Some(Rc::clone(&profile))).await?;
+            rows.try_collect::<Vec<_>>().await?;
+
+            Some(profile)
+        }
+        false => None,
+    };
+
+    let mut rows = Vec::new();
+    explain_query(storage, &mut rows, query, 0, profile.as_deref()).await?;
+
+    Ok(rows)
+}
+
+fn push(
+    rows: &mut Vec<ExplainRow>,
+    depth: usize,
+    operator: Operator,
+    detail: String,
+    meter: Option<&Rc<Meter>>,
+) {
+    rows.push(ExplainRow {
+        depth,
+        operator,
+        detail,
+        analyzed: meter.map(|meter| meter.analyzed()),
+    });
+}
+
+#[async_recursion(?Send)]
+async fn explain_query<T>(
+    storage: &T,
+    rows: &mut Vec<ExplainRow>,
+    query: &Query,
+    depth: usize,
+    profile: Option<&'async_recursion Profile>,
+) -> Result<()>
+where
+    T: GStore,
+{
+    let Query {
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+    let mut depth = depth;
+
+    if limit.is_some() || offset.is_some() {
+        let detail = [("LIMIT", limit), ("OFFSET", offset)]
+            .into_iter()
+            .filter_map(|(keyword, expr)| {
+                expr.as_ref()
+                    .map(|expr| format!("{keyword} {}", expr.to_sql()))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        push(
+            rows,
+            depth,
+            Operator::Limit,
+            detail,
+            profile.map(|p| &p.limit),
+        );
+        depth += 1;
+    }
+
+    if let SetExpr::Select(select) = body {
+        let detail = match &select.distinct {
+            Some(Distinct::Distinct) => Some(String::new()),
+            Some(Distinct::On(exprs)) => {
+                let exprs = exprs
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Some(format!("ON ({exprs})"))
+            }
+            None => None,
+        };
+
+        if let Some(detail) = detail {
+            let meter = profile.map(|p| &p.distinct);
+
+            push(rows, depth, Operator::Distinct, detail, meter);
+            depth += 1;
+        }
+    }
+
+    if !order_by.is_empty() {
+        let detail = order_by
+            .iter()
+            .map(ToSql::to_sql)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        push(
+            rows,
+            depth,
+            Operator::Sort,
+            detail,
+            profile.map(|p| &p.sort),
+        );
+        depth += 1;
+    }
+
+    let select = match body {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(Values(values_list)) => {
+            let detail = format!("{} rows", values_list.len());
+
+            push(
+                rows,
+                depth,
+                Operator::Values,
+                detail,
+                profile.map(|p| &p.scan),
+            );
+            return Ok(());
+        }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let detail = match all {
+                true => format!("{op} ALL"),
+                false => op.to_string(),
+            };
+
+            push(
+                rows,
+                depth,
+                Operator::SetOperation,
+                detail,
+                profile.map(|p| &p.scan),
+            );
+
+            for (i, operand) in [left, right].into_iter().enumerate() {
+                let query = Query {
+                    body: operand.as_ref().clone(),
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                };
+                let profile = profile.and_then(|p| p.operands.get(i)).map(Rc::as_ref);
+
+                explain_query(storage, rows, &query, depth + 1, profile).await?;
+            }
+            return Ok(());
+        }
+    };
+
+    let Select {
+        distinct: _,
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+    } = select.as_ref();
+
+    let detail = projection
+        .iter()
+        .map(ToSql::to_sql)
+        .collect::<Vec<_>>()
+        .join(", ");
+    push(
+        rows,
+        depth,
+        Operator::Project,
+        detail,
+        profile.map(|p| &p.project),
+    );
+    depth += 1;
+
+    if check_aggregate(projection, group_by) || having.is_some() {
+        let group_by = (!group_by.is_empty()).then(|| {
+            let exprs = group_by
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("GROUP BY {exprs}")
+        });
+        let having = having
+            .as_ref()
+            .map(|expr| format!("HAVING {}", expr.to_sql()));
+        let detail = group_by
+            .into_iter()
+            .chain(having)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        push(
+            rows,
+            depth,
+            Operator::Aggregate,
+            detail,
+            profile.map(|p| &p.aggregate),
+        );
+        depth += 1;
+    }
+
+    if let Some(expr) = selection {
+        push(
+            rows,
+            depth,
+            Operator::Filter,
+            expr.to_sql(),
+            profile.map(|p| &p.filter),
+        );
+        depth += 1;
+    }
+
+    for (i, join) in joins.iter().enumerate().rev() {
+        let meter = profile.and_then(|p| p.joins.get(i));
+
+        push(rows, depth, Operator::Join, join_detail(join), meter);
+        depth += 1;
+    }
+
+    // Index scans take no predicates, so only full scans may show conjuncts the store handles.
+    let pushed = match (selection, relation) {
+        (
+            Some(expr),
+            TableFactor::Table {
+                chain_name,
+                name,
+                alias,
+                index: None,
+                ..
+            },
+        ) if alias.as_ref().is_none_or(|alias| alias.columns.is_empty()) => {
+            let pushdown = Pushdown::new(get_alias(relation), !joins.is_empty(), expr).await;
+
+            match pushdown.predicates().is_empty() {
+                true => Vec::new(),
+                false => {
+                    let name = qualified_table_name(chain_name.as_deref(), name);
+                    let (_, handled) = storage
+                        .scan_data_with_predicates(&name, pushdown.predicates())
+                        .await?;
+
+                    pushdown.handled(&handled)
+                }
+            }
+        }
+        _ => Vec::new(),
+    };
+    explain_table_factor(
+        storage,
+        rows,
+        relation,
+        depth,
+        &pushed,
+        profile.map(|p| &p.scan),
+    )
+    .await?;
+
+    for (i, join) in joins.iter().enumerate() {
+        let meter = profile.and_then(|p| p.join_scans.get(i));
+
+        explain_table_factor(storage, rows, &join.relation, depth, &[], meter).await?;
+        depth -= 1;
+    }
+
+    Ok(())
+}
+
+async fn explain_table_factor<T: GStore>(
+    storage: &T,
+    rows: &mut Vec<ExplainRow>,
+    table_factor: &TableFactor,
+    depth: usize,
+    pushed: &[&Expr],
+    meter: Option<&Rc<Meter>>,
+) -> Result<()> {
+    let alias = |alias: Option<&TableAlias>| {
+        alias
+            .map(|alias| format!(" {}", alias.to_sql()))
+            .unwrap_or_default()
+    };
+
+    let detail = match table_factor {
+        TableFactor::Table {
+            chain_name,
+            name,
+            alias: table_alias,
+            index,
+            ..
+        } => {
+            let chain = chain_name.as_ref().map(|chain| format!("chain: {chain}"));
+            let index = index
+                .as_ref()
+                .map(|index| format!("index: {}", index_detail(index)));
+            let pushed = (!pushed.is_empty()).then(|| {
+                let exprs = pushed
+                    .iter()
+                    .map(|expr| expr.to_sql())
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+
+                format!("pushdown: {exprs}")
+            });
+            let attributes = chain
+                .into_iter()
+                .chain(index)
+                .chain(pushed)
+                .collect::<Vec<_>>();
+            let attributes = match attributes.is_empty() {
+                true => String::new(),
+                false => format!(" ({})", attributes.join(", ")),
+            };
+
+            format!(r#""{name}"{}{attributes}"#, alias(table_alias.as_ref()))
+        }
+        TableFactor::Derived {
+            alias: table_alias, ..
+        } => format!("(subquery){}", alias(Some(table_alias))),
+        TableFactor::Series {
+            alias: table_alias,
+            size,
+        } => format!("SERIES({}){}", size.to_sql(), alias(Some(table_alias))),
+        TableFactor::Dictionary {
+            dict,
+            alias: table_alias,
+        } => format!("{dict}{}", alias(Some(table_alias))),
+    };
+
+    push(rows, depth, Operator::Scan, detail, meter);
+
+    if let TableFactor::Derived { subquery, .. } = table_factor {
+        explain_query(storage, rows, subquery, depth + 1, None).await?;
+    }
+
+    Ok(())
+}
+
+fn index_detail(index: &IndexItem) -> String {
+    match index {
+        IndexItem::PrimaryKey(expr) => format!("PRIMARY KEY = {}", expr.to_sql()),
+        IndexItem::NonClustered {
+            name,
+            asc,
+            cmp_expr,
+        } => {
+            let cmp = cmp_expr
+                .as_ref()
+                .map(|(op, expr)| {
+                    format!(
+                        " {} {}",
+                        BinaryOperator::from(op.clone()).to_sql(),
+                        expr.to_sql()
+                    )
+                })
+                .unwrap_or_default();
+            let order = match asc {
+                Some(true) => " ASC",
+                Some(false) => " DESC",
+                None => "",
+            };
+
+            format!(r#""{name}"{cmp}{order}"#)
+        }
+    }
+}
+
+fn join_detail(join: &Join) -> String {
+    let Join {
+        join_operator,
+        join_executor,
+        ..
+    } = join;
+
+    let (join_operator, constraint) = match join_operator {
+        JoinOperator::Inner(constraint) => ("INNER", constraint),
+        JoinOperator::LeftOuter(constraint) => ("LEFT OUTER", constraint),
+    };
+    let constraint = match constraint {
+        JoinConstraint::On(expr) => format!(" ON {}", expr.to_sql()),
+        JoinConstraint::None => String::new(),
+    };
+    let executor = match join_executor {
+        JoinExecutor::NestedLoop => "nested loop".to_owned(),
+        JoinExecutor::Hash {
+            key_expr,
+            value_expr,
+            where_clause,
+        } => {
+            let filter = where_clause
+                .as_ref()
+                .map(|expr| format!(", filter: {}", expr.to_sql()))
+                .unwrap_or_default();
+
+            format!(
+                "hash (key: {} = {}{filter})",
+                key_expr.to_sql(),
+                value_expr.to_sql()
+            )
+        }
+    };
+
+    format!("{join_operator}{constraint}, {executor}")
+}
@@ -1,14 +1,19 @@
 use {
-    super::{context::RowContext, evaluate::evaluate_stateless, filter::check_expr},
+    super::{
+        context::RowContext,
+        evaluate::{evaluate, evaluate_stateless},
+        filter::check_conjuncts,
+        pushdown::Pushdown,
+    },
     crate::{
         ast::{
-            Dictionary, Expr, Join, Query, Select, SelectItem, SetExpr, TableAlias, TableFactor,
-            TableWithJoins, ToSql, Values,
+            qualified_table_name, Dictionary, Expr, IndexItem, Join, Query, Select, SelectItem,
+            SetExpr, TableAlias, TableFactor, TableWithJoins, ToSql, Values,
         },
         data::{get_alias, get_index, Key, Row, Value},
         executor::select::select,
         result::Result,
-        store::{DataRow, GStore},
+        store::{DataRow, GStore, Predicate},
     },
     async_recursion::async_recursion,
     futures::stream::{self, Stream, StreamExt, TryStreamExt},
@@ -39,37 +44,65 @@ pub async fn fetch<'a, T: GStore>(
     where_clause: Option<&'a Expr>,
 ) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
     let columns = columns.unwrap_or_else(|| Rc::from([]));
-    let rows = storage
-        .scan_data(table_name)
-        .await?
-        .try_filter_map(move |(key, data_row)| {
-            let row = match data_row {
-                DataRow::Vec(values) => Row::Vec {
-                    columns: Rc::clone(&columns),
-                    values,
-                },
-                DataRow::Map(values) => Row::Map(values),
-            };
-
-            async move {
-                let expr = match where_clause {
-                    None => {
-                        return Ok(Some((key, row)));
-                    }
-                    Some(expr) => expr,
-                };
+    let pushdown = match where_clause {
+        Some(expr) => Some(Pushdown::new(table_name, false, expr).await),
+        None => None,
+    };
+    let predicates = pushdown
+        .as_ref()
+        .map(Pushdown::predicates)
+        .unwrap_or_default();
+    let (rows, handled) = storage
+        .scan_data_with_predicates(table_name, predicates)
+        .await?;
+    let conjuncts = match &pushdown {
+        Some(pushdown) => pushdown.residual(&handled),
+        None => Vec::new(),
+    };
+    let conjuncts = Rc::new(conjuncts);
+
+    let rows = rows.try_filter_map(move |(key, data_row)| {
+        let row = into_row(&columns, &columns, data_row);
+        let conjuncts = Rc::clone(&conjuncts);
+
+        async move {
+            if conjuncts.is_empty() {
+                return Ok(Some((key, row)));
+            }
 
-                let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+            let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
 
-                check_expr(storage, Some(Rc::new(context)), None, expr)
-                    .await
-                    .map(|pass| pass.then_some((key, row)))
-            }
-        });
+            check_conjuncts(storage, Some(Rc::new(context)), None, &conjuncts)
+                .await
+                .map(|pass| pass.then_some((key, row)))
+        }
+    });
 
     Ok(rows)
 }
 
+/// Converts a stored row into an executor row labeled by `columns`.
+///
+/// Map rows of a table which declares its columns are reordered into a vec row, so that
+/// schemaful stores may yield either shape. `schema_columns` are the declared column names
+/// the map keys follow, while `columns` may already be renamed by a table alias.
+fn into_row(columns: &Rc<[String]>, schema_columns: &[String], data_row: DataRow) -> Row {
+    match data_row {
+        DataRow::Vec(values) => Row::Vec {
+            columns: Rc::clone(columns),
+            values,
+        },
+        DataRow::Map(values) if schema_columns.is_empty() => Row::Map(values),
+        DataRow::Map(mut values) => Row::Vec {
+            columns: Rc::clone(columns),
+            values: schema_columns
+                .iter()
+                .map(|column| values.remove(column).unwrap_or(Value::Null))
+                .collect(),
+        },
+    }
+}
+
 #[derive(futures_enum::Stream)]
 pub enum Rows<I1, I2, I3, I4> {
     Derived(I1),
@@ -83,6 +116,23 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
     table_factor: &'a TableFactor,
     filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
+    fetch_filtered_relation_rows(storage, table_factor, filter_context, &[], None)
+        .await
+        .map(|(rows, _)| rows)
+}
+
+/// Fetches rows of `table_factor` with `predicates` pushed down to the store, along with
+/// whether each predicate is already satisfied by every fetched row.
+///
+/// A table only needs to yield `projection` of its columns when it is given.
+pub async fn fetch_filtered_relation_rows<'a, T: GStore>(
+    storage: &'a T,
+    table_factor: &'a TableFactor,
+    filter_context: &Option<Rc<RowContext<'a>>>,
+    predicates: &[Predicate],
+    projection: Option<&[String]>,
+) -> Result<(impl Stream<Item = Result<Row>> + 'a, Vec<bool>)> {
+    let unhandled = vec![false; predicates.len()];
     let columns = Rc::from(
         fetch_relation_columns(storage, table_factor)
             .await?
@@ -103,33 +153,80 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                         Row::Map(values) => Row::Map(values),
                     });
 
-            Ok(Rows::Derived(rows))
+            Ok((Rows::Derived(rows), unhandled))
         }
-        TableFactor::Table { name, .. } => {
-            let rows = {
+        TableFactor::Table {
+            chain_name, name, ..
+        } => {
+            let name = qualified_table_name(chain_name.as_deref(), name);
+            let schema_columns: Rc<[String]> =
+                Rc::from(fetch_columns(storage, &name).await?.unwrap_or_default());
+            let (rows, handled) = {
                 #[derive(futures_enum::Stream)]
-                enum Rows<I1> {
-                    FullScan(I1),
+                enum Rows<I1, I2, I3, I4> {
+                    Indexed(I1),
+                    PrimaryKey(I2),
+                    PrimaryKeyEmpty(I3),
+                    FullScan(I4),
                 }
 
                 match get_index(table_factor) {
-                    _ => {
-                        let rows = storage.scan_data(name).await?.map_ok(move |(_, data_row)| {
-                            match data_row {
-                                DataRow::Vec(values) => Row::Vec {
-                                    columns: Rc::clone(&columns),
-                                    values,
-                                },
-                                DataRow::Map(values) => Row::Map(values),
+                    Some(IndexItem::NonClustered {
+                        name: index_name,
+                        asc,
+                        cmp_expr,
+                    }) => {
+                        let cmp_value = match cmp_expr {
+                            Some((op, expr)) => {
+                                let filter_context = filter_context.as_ref().map(Rc::clone);
+                                let evaluated = evaluate(storage, filter_context, None, expr).await?;
+
+                                Some((op, Value::try_from(evaluated)?))
+                            }
+                            None => None,
+                        };
+                        let rows = storage
+                            .scan_indexed_data(&name, index_name, *asc, cmp_value)
+                            .await?
+                            .map_ok(move |(_, data_row)| {
+                                into_row(&columns, &schema_columns, data_row)
+                            });
+
+                        (Rows::Indexed(rows), unhandled)
+                    }
+                    Some(IndexItem::PrimaryKey(expr)) => {
+                        let filter_context = filter_context.as_ref().map(Rc::clone);
+                        let evaluated = evaluate(storage, filter_context, None, expr).await?;
+                        let key = Key::try_from(evaluated)?;
+
+                        match storage.fetch_data(&name, &key).await? {
+                            Some(data_row) => {
+                                let row = into_row(&columns, &schema_columns, data_row);
+
+                                (Rows::PrimaryKey(stream::once(async { Ok(row) })), unhandled)
+                            }
+                            None => (Rows::PrimaryKeyEmpty(stream::empty()), unhandled),
+                        }
+                    }
+                    None => {
+                        let (rows, handled) = match projection {
+                            Some(columns) => {
+                                storage
+                                    .scan_data_with_projection(&name, columns, predicates)
+                                    .await?
                             }
+                            None => storage.scan_data_with_predicates(&name, predicates).await?,
+                        };
+                        let rows = rows.map_ok(move |(_, data_row)| {
+                            into_row(&columns, &schema_columns, data_row)
                         });
 
-                        Rows::FullScan(rows)
+                        (Rows::FullScan(rows), handled)
                     }
                 }
             };
 
-            Ok(Rows::Table(rows))
+            Ok((Rows::Table(rows), handled))
         }
         TableFactor::Series { size, .. } => {
             let value: Value = evaluate_stateless(None, size).await?.try_into()?;
@@ -147,7 +244,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                 })
             });
 
-            Ok(Rows::Series(stream::iter(rows)))
+            Ok((Rows::Series(stream::iter(rows)), unhandled))
         }
         TableFactor::Dictionary { dict, .. } => {
             let rows = {
@@ -245,7 +342,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                 }
             };
 
-            Ok(Rows::Dictionary(rows))
+            Ok((Rows::Dictionary(rows), unhandled))
         }
     }
 }
@@ -278,19 +375,21 @@ where
     T: GStore,
 {
     match table_factor {
-        TableFactor::Table { name, alias, .. } => {
-            let columns = fetch_columns(storage, name).await?;
+        TableFactor::Table {
+            chain_name,
+            name,
+            alias,
+            ..
+        } => {
+            let name = qualified_table_name(chain_name.as_deref(), name);
+            let columns = fetch_columns(storage, &name).await?;
             match (columns, alias) {
                 (columns, None) => Ok(columns),
                 (None, Some(_)) => Ok(None),
-                (Some(columns), Some(alias)) if alias.columns.len() > columns.len() => {
-                    Err(FetchError::TooManyColumnAliases(
-                        name.to_string(),
-                        columns.len(),
-                        alias.columns.len(),
-                    )
-                    .into())
-                }
+                (Some(columns), Some(alias)) if alias.columns.len() > columns.len() => Err(
+                    FetchError::TooManyColumnAliases(name, columns.len(), alias.columns.len())
+                        .into(),
+                ),
                 (Some(columns), Some(alias)) => Ok(Some(
                     alias
                         .columns
@@ -326,59 +425,57 @@ where
                     columns: alias_columns,
                     name,
                 },
-        } => match body {
-            SetExpr::Select(statement) => {
-                let Select {
-                    from:
-                        TableWithJoins {
-                            relation, joins, ..
-                        },
-                    projection,
-                    ..
-                } = statement.as_ref();
-
-                let labels = fetch_labels(storage, relation, joins, projection).await?;
-                match labels {
-                    None => Ok(None),
-                    Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
-                    Some(labels) if alias_columns.len() > labels.len() => {
-                        Err(FetchError::TooManyColumnAliases(
-                            name.to_string(),
-                            labels.len(),
-                            alias_columns.len(),
-                        )
-                        .into())
-                    }
-                    Some(labels) => Ok(Some(
-                        alias_columns
-                            .iter()
-                            .cloned()
-                            .chain(labels[alias_columns.len()..labels.len()].to_vec())
-                            .collect(),
-                    )),
-                }
-            }
-            SetExpr::Values(Values(values_list)) => {
-                let total_len = values_list[0].len();
-                let alias_len = alias_columns.len();
-                if alias_len > total_len {
-                    return Err(FetchError::TooManyColumnAliases(
-                        name.into(),
-                        total_len,
-                        alias_len,
+        } => {
+            let labels = fetch_set_expr_labels(storage, body).await?;
+            match labels {
+                None => Ok(None),
+                Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
+                Some(labels) if alias_columns.len() > labels.len() => {
+                    Err(FetchError::TooManyColumnAliases(
+                        name.to_string(),
+                        labels.len(),
+                        alias_columns.len(),
                     )
-                    .into());
+                    .into())
                 }
-                let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
-                let labels = alias_columns
-                    .iter()
-                    .cloned()
-                    .chain(labels)
-                    .collect::<Vec<_>>();
-
-                Ok(Some(labels))
+                Some(labels) => Ok(Some(
+                    alias_columns
+                        .iter()
+                        .cloned()
+                        .chain(labels[alias_columns.len()..labels.len()].to_vec())
+                        .collect(),
+                )),
             }
-        },
+        }
+    }
+}
+
+/// Labels of the rows `set_expr` outputs, those of its left operand for a set operation.
+#[async_recursion(?Send)]
+async fn fetch_set_expr_labels<T>(storage: &T, set_expr: &SetExpr) -> Result<Option<Vec<String>>>
+where
+    T: GStore,
+{
+    match set_expr {
+        SetExpr::Select(statement) => {
+            let Select {
+                from: TableWithJoins {
+                    relation, joins, ..
+                },
+                projection,
+                ..
+            } = statement.as_ref();
+
+            fetch_labels(storage, relation, joins, projection).await
+        }
+        SetExpr::Values(Values(values_list)) => {
+            let labels = (1..=values_list[0].len())
+                .map(|i| format!("column{}", i))
+                .collect();
+
+            Ok(Some(labels))
+        }
+        SetExpr::SetOperation { left, .. } => fetch_set_expr_labels(storage, left).await,
     }
 }
 
@@ -12,7 +12,7 @@ use {
 
 pub struct Filter<'a, T: GStore> {
     storage: &'a T,
-    where_clause: Option<&'a Expr>,
+    conjuncts: Vec<&'a Expr>,
     context: Option<Rc<RowContext<'a>>>,
     aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
 }
@@ -20,37 +20,51 @@ pub struct Filter<'a, T: GStore> {
 impl<'a, T: GStore> Filter<'a, T> {
     pub fn new(
         storage: &'a T,
-        where_clause: Option<&'a Expr>,
+        conjuncts: Vec<&'a Expr>,
         context: Option<Rc<RowContext<'a>>>,
         aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
     ) -> Self {
         Self {
             storage,
-            where_clause,
+            conjuncts,
             context,
             aggregated,
         }
     }
 
     pub async fn check(&self, project_context: Rc<RowContext<'a>>) -> Result<bool> {
-        match self.where_clause {
-            Some(expr) => {
-                let context = match &self.context {
-                    Some(context) => {
-                        Rc::new(RowContext::concat(project_context, Rc::clone(context)))
-                    }
-                    None => project_context,
-                };
-                let context = Some(context);
-                let aggregated = self.aggregated.as_ref().map(Rc::clone);
-
-                check_expr(self.storage, context, aggregated, expr).await
-            }
-            None => Ok(true),
+        if self.conjuncts.is_empty() {
+            return Ok(true);
         }
+
+        let context = match &self.context {
+            Some(context) => Rc::new(RowContext::concat(project_context, Rc::clone(context))),
+            None => project_context,
+        };
+        let aggregated = self.aggregated.as_ref().map(Rc::clone);
+
+        check_conjuncts(self.storage, Some(context), aggregated, &self.conjuncts).await
     }
 }
 
+/// Checks every conjunct, the way `AND` of them would be evaluated.
+pub async fn check_conjuncts<'a, T: GStore>(
+    storage: &'a T,
+    context: Option<Rc<RowContext<'a>>>,
+    aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
+    conjuncts: &[&'a Expr],
+) -> Result<bool> {
+    let mut pass = true;
+    for expr in conjuncts {
+        let context = context.as_ref().map(Rc::clone);
+        let aggregated = aggregated.as_ref().map(Rc::clone);
+
+        pass &= check_expr(storage, context, aggregated, expr).await?;
+    }
+
+    Ok(pass)
+}
+
 pub async fn check_expr<'a, T: GStore>(
     storage: &'a T,
     context: Option<Rc<RowContext<'a>>>,
@@ -0,0 +1,241 @@
+use {
+    super::{evaluate::evaluate_stateless, select::select, ExecuteError},
+    crate::{
+        ast::{ColumnDef, Query, SetExpr, Values},
+        data::{Row, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+    serde::Serialize,
+    std::{collections::HashMap, fmt::Debug},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum InsertError {
+    #[error("table not found: {0}")]
+    TableNotFound(String),
+
+    #[error("column not found: {0}")]
+    ColumnNotFound(String),
+
+    #[error("column '{0}' is specified more than once")]
+    DuplicateColumn(String),
+
+    #[error("wrong number of values, expected {expected} but found {found}")]
+    WrongNumberOfValues { expected: usize, found: usize },
+
+    #[error("lack of required column: {0}")]
+    LackOfRequiredColumn(String),
+
+    #[error("only a single value is allowed for a schemaless row")]
+    OnlySingleValueAllowed,
+}
+
+/// Inserts the rows of `source` into `table_name`, and returns the number of rows inserted.
+///
+/// `columns` name the columns the values of each row go to, every column when empty. The
+/// columns left out take their `DEFAULT` value, or `NULL` when nullable. Views are not written
+/// to.
+pub async fn insert<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    columns: &[String],
+    source: &Query,
+) -> Result<usize> {
+    let Schema {
+        column_defs, view, ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
+
+    if view.is_some() {
+        return Err(ExecuteError::NotATable(table_name.to_owned()).into());
+    }
+
+    let rows = match column_defs {
+        Some(column_defs) => fetch_vec_rows(storage, &column_defs, columns, source).await?,
+        None => fetch_map_rows(storage, source).await?,
+    };
+    let num_rows = rows.len();
+
+    if num_rows > 0 {
+        storage.append_data(table_name, rows).await?;
+    }
+
+    Ok(num_rows)
+}
+
+async fn fetch_vec_rows<T: GStore>(
+    storage: &T,
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    source: &Query,
+) -> Result<Vec<DataRow>> {
+    validate_columns(column_defs, columns)?;
+
+    let mut rows = Vec::new();
+    match &source.body {
+        SetExpr::Values(Values(values_list)) => {
+            for exprs in values_list {
+                let positions = positions(column_defs, columns, exprs.len())?;
+                let mut values = Vec::with_capacity(column_defs.len());
+
+                for (column_def, position) in column_defs.iter().zip(positions) {
+                    let value = match position {
+                        Some(i) => {
+                            let ColumnDef {
+                                data_type,
+                                nullable,
+                                ..
+                            } = column_def;
+
+                            evaluate_stateless(None, &exprs[i])
+                                .await?
+                                .try_into_value(data_type, *nullable)?
+                        }
+                        None => default_value(column_def).await?,
+                    };
+
+                    value.validate_type(&column_def.data_type)?;
+                    values.push(value);
+                }
+
+                rows.push(DataRow::Vec(values));
+            }
+        }
+        _ => {
+            let source_rows = select(storage, source, None)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            for row in source_rows {
+                let source_values = row.try_into_vec()?;
+                let positions = positions(column_defs, columns, source_values.len())?;
+                let mut values = Vec::with_capacity(column_defs.len());
+
+                for (column_def, position) in column_defs.iter().zip(positions) {
+                    let value = match position {
+                        Some(i) => source_values[i].clone(),
+                        None => default_value(column_def).await?,
+                    };
+
+                    value.validate_type(&column_def.data_type)?;
+                    value.validate_null(column_def.nullable)?;
+                    values.push(value);
+                }
+
+                rows.push(DataRow::Vec(values));
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<DataRow>> {
+    let mut rows = Vec::new();
+    match &source.body {
+        SetExpr::Values(Values(values_list)) => {
+            for exprs in values_list {
+                let [expr] = exprs.as_slice() else {
+                    return Err(InsertError::OnlySingleValueAllowed.into());
+                };
+                let values = evaluate_stateless(None, expr).await?.try_into()?;
+
+                rows.push(DataRow::Map(values));
+            }
+        }
+        _ => {
+            let source_rows = select(storage, source, None)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            for row in source_rows {
+                let values = match row {
+                    Row::Map(values) => values,
+                    Row::Vec { columns, values } => columns
+                        .iter()
+                        .cloned()
+                        .zip(values)
+                        .collect::<HashMap<_, _>>(),
+                };
+
+                rows.push(DataRow::Map(values));
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+fn validate_columns(column_defs: &[ColumnDef], columns: &[String]) -> Result<()> {
+    for (i, column) in columns.iter().enumerate() {
+        if !column_defs
+            .iter()
+            .any(|column_def| &column_def.name == column)
+        {
+            return Err(InsertError::ColumnNotFound(column.clone()).into());
+        }
+
+        if columns[..i].contains(column) {
+            return Err(InsertError::DuplicateColumn(column.clone()).into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Position among `num_values` values of the value of each column of `column_defs`, `None` for
+/// the columns given no value.
+fn positions(
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    num_values: usize,
+) -> Result<Vec<Option<usize>>> {
+    let expected = match columns.len() {
+        0 => column_defs.len(),
+        n => n,
+    };
+
+    if num_values > expected || (!columns.is_empty() && num_values != expected) {
+        return Err(InsertError::WrongNumberOfValues {
+            expected,
+            found: num_values,
+        }
+        .into());
+    }
+
+    let positions = column_defs
+        .iter()
+        .enumerate()
+        .map(|(i, column_def)| match columns {
+            [] => (i < num_values).then_some(i),
+            _ => columns.iter().position(|column| column == &column_def.name),
+        })
+        .collect();
+
+    Ok(positions)
+}
+
+async fn default_value(column_def: &ColumnDef) -> Result<Value> {
+    let ColumnDef {
+        name,
+        data_type,
+        nullable,
+        default,
+        ..
+    } = column_def;
+
+    match default {
+        Some(expr) => evaluate_stateless(None, expr)
+            .await?
+            .try_into_value(data_type, *nullable),
+        None if *nullable => Ok(Value::Null),
+        None => Err(InsertError::LackOfRequiredColumn(name.clone()).into()),
+    }
+}
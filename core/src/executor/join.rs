@@ -6,7 +6,12 @@ use {
             JoinOperator as AstJoinOperator, TableFactor,
         },
         data::{get_alias, Key, Row, Value},
-        executor::{context::RowContext, evaluate::evaluate, filter::check_expr},
+        executor::{
+            context::RowContext,
+            evaluate::evaluate,
+            explain::{meter, Meter},
+            filter::check_expr,
+        },
         result::Result,
         store::GStore,
     },
@@ -15,7 +20,7 @@ use {
         stream::{self, empty, once, Stream, StreamExt, TryStreamExt},
     },
     itertools::Itertools,
-    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc},
+    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc, time::Instant},
     utils::OrStream,
 };
 
@@ -23,6 +28,10 @@ pub struct Join<'a, T: GStore> {
     storage: &'a T,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Rc<RowContext<'a>>>,
+    /// Meters of each join clause under `EXPLAIN ANALYZE`, empty otherwise.
+    meters: Vec<Rc<Meter>>,
+    /// Meters of the relation scanned by each join clause under `EXPLAIN ANALYZE`.
+    scan_meters: Vec<Rc<Meter>>,
 }
 
 type JoinItem<'a> = Rc<RowContext<'a>>;
@@ -33,11 +42,15 @@ impl<'a, T: GStore> Join<'a, T> {
         storage: &'a T,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Rc<RowContext<'a>>>,
+        meters: Vec<Rc<Meter>>,
+        scan_meters: Vec<Rc<Meter>>,
     ) -> Self {
         Self {
             storage,
             join_clauses,
             filter_context,
+            meters,
+            scan_meters,
         }
     }
 
@@ -47,12 +60,27 @@ impl<'a, T: GStore> Join<'a, T> {
     ) -> Result<Joined<'a>> {
         let init_rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
 
-        stream::iter(self.join_clauses)
+        stream::iter(self.join_clauses.iter().enumerate())
             .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
+            .try_fold(init_rows, |rows, (i, join_clause)| {
                 let filter_context = self.filter_context.as_ref().map(Rc::clone);
+                let join_meter = self.meters.get(i).map(Rc::clone);
+                let scan_meter = self.scan_meters.get(i).map(Rc::clone);
+
+                async move {
+                    let started = Instant::now();
+                    let rows =
+                        join(self.storage, filter_context, join_clause, rows, scan_meter).await?;
+
+                    let Some(join_meter) = join_meter else {
+                        return Ok(rows);
+                    };
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
+                    join_meter.add_elapsed(started);
+                    let rows: Joined = Box::pin(meter(Some(join_meter), rows));
+
+                    Ok(rows)
+                }
             })
             .await
     }
@@ -63,6 +91,7 @@ async fn join<'a, T: GStore>(
     filter_context: Option<Rc<RowContext<'a>>>,
     ast_join: &'a AstJoin,
     left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
+    scan_meter: Option<Rc<Meter>>,
 ) -> Result<Joined<'a>> {
     let AstJoin {
         relation,
@@ -76,6 +105,7 @@ async fn join<'a, T: GStore>(
         relation,
         filter_context.as_ref().map(Rc::clone),
         join_executor,
+        scan_meter.as_ref().map(Rc::clone),
     )
     .await
     .map(Rc::new)?;
@@ -112,6 +142,7 @@ async fn join<'a, T: GStore>(
         };
         let filter_context = filter_context.as_ref().map(Rc::clone);
         let join_executor = Rc::clone(&join_executor);
+        let scan_meter = scan_meter.as_ref().map(Rc::clone);
 
         async move {
             let filter_context = match filter_context {
@@ -131,8 +162,8 @@ async fn join<'a, T: GStore>(
             }
             let rows = match join_executor.as_ref() {
                 JoinExecutor::NestedLoop => {
-                    let rows = fetch_relation_rows(storage, relation, &filter_context)
-                        .await?
+                    let rows = fetch_relation_rows(storage, relation, &filter_context).await?;
+                    let rows = meter(scan_meter, rows)
                         .and_then(|row| future::ok(Cow::Owned(row)))
                         .try_filter_map(move |row| {
                             check_where_clause(
@@ -226,6 +257,7 @@ impl<'a> JoinExecutor<'a> {
         relation: &TableFactor,
         filter_context: Option<Rc<RowContext<'a>>>,
         ast_join_executor: &'a AstJoinExecutor,
+        scan_meter: Option<Rc<Meter>>,
     ) -> Result<JoinExecutor<'a>> {
         let (key_expr, value_expr, where_clause) = match ast_join_executor {
             AstJoinExecutor::NestedLoop => return Ok(Self::NestedLoop),
@@ -236,8 +268,8 @@ impl<'a> JoinExecutor<'a> {
             } => (key_expr, value_expr, where_clause),
         };
 
-        let rows_map = fetch_relation_rows(storage, relation, &filter_context)
-            .await?
+        let rows = fetch_relation_rows(storage, relation, &filter_context).await?;
+        let rows_map = meter(scan_meter, rows)
             .try_filter_map(|row| {
                 let filter_context = filter_context.as_ref().map(Rc::clone);
 
@@ -1,20 +1,32 @@
 mod aggregate;
+mod alter;
 mod context;
+mod delete;
+mod distinct;
 mod evaluate;
 mod execute;
+mod explain;
 mod fetch;
 mod filter;
+mod insert;
 mod join;
 mod limit;
+mod projection;
+mod pushdown;
 mod select;
 mod sort;
+mod update;
 
 pub use {
     aggregate::AggregateError,
+    alter::AlterError,
     context::RowContext,
     evaluate::{evaluate_stateless, EvaluateError},
     execute::{execute, ExecuteError, Payload, PayloadVariable},
+    explain::{Analyzed, ExplainRow, Operator},
     fetch::FetchError,
+    insert::InsertError,
     select::SelectError,
     sort::SortError,
+    update::UpdateError,
 };
@@ -0,0 +1,210 @@
+use {
+    crate::{
+        ast::{
+            Distinct, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, SelectItem,
+            SetExpr, TableFactor,
+        },
+        plan::PlanExpr,
+    },
+    std::collections::BTreeSet,
+};
+
+/// Columns `query` reads, handed to the store scanning its table so that it may skip the
+/// others.
+///
+/// Every identifier of the query counts, whichever table it belongs to, so the columns are a
+/// superset of those of the scanned table. `None` when a wildcard selects every column.
+pub fn projected_columns(query: &Query) -> Option<Vec<String>> {
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+
+    let wildcard = select
+        .projection
+        .iter()
+        .any(|item| !matches!(item, SelectItem::Expr { .. }));
+    if wildcard {
+        return None;
+    }
+
+    let mut columns = BTreeSet::new();
+    collect_query(&mut columns, query);
+
+    Some(columns.into_iter().collect())
+}
+
+fn collect_query(columns: &mut BTreeSet<String>, query: &Query) {
+    collect_set_expr(columns, &query.body);
+
+    let exprs = query
+        .order_by
+        .iter()
+        .map(|order_by| &order_by.expr)
+        .chain(query.limit.iter())
+        .chain(query.offset.iter());
+
+    for expr in exprs {
+        collect_expr(columns, expr);
+    }
+}
+
+fn collect_set_expr<'a>(columns: &mut BTreeSet<String>, set_expr: &'a SetExpr) {
+    let exprs: Vec<&'a Expr> = match set_expr {
+        SetExpr::Select(select) => {
+            collect_table_factor(columns, &select.from.relation);
+            for join in select.from.joins.iter() {
+                collect_join(columns, join);
+            }
+
+            let distinct_on = match &select.distinct {
+                Some(Distinct::On(exprs)) => exprs.as_slice(),
+                Some(Distinct::Distinct) | None => &[],
+            };
+
+            select
+                .projection
+                .iter()
+                .filter_map(|item| match item {
+                    SelectItem::Expr { expr, .. } => Some(expr),
+                    SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+                })
+                .chain(distinct_on)
+                .chain(select.selection.iter())
+                .chain(select.group_by.iter())
+                .chain(select.having.iter())
+                .collect()
+        }
+        SetExpr::Values(values) => values.0.iter().flatten().collect(),
+        SetExpr::SetOperation { left, right, .. } => {
+            collect_set_expr(columns, left);
+            collect_set_expr(columns, right);
+
+            return;
+        }
+    };
+
+    for expr in exprs {
+        collect_expr(columns, expr);
+    }
+}
+
+fn collect_table_factor(columns: &mut BTreeSet<String>, table_factor: &TableFactor) {
+    match table_factor {
+        TableFactor::Derived { subquery, .. } => collect_query(columns, subquery),
+        TableFactor::Series { size, .. } => collect_expr(columns, size),
+        TableFactor::Table { .. } | TableFactor::Dictionary { .. } => {}
+    }
+}
+
+fn collect_join(columns: &mut BTreeSet<String>, join: &Join) {
+    collect_table_factor(columns, &join.relation);
+
+    if let JoinOperator::Inner(JoinConstraint::On(expr))
+    | JoinOperator::LeftOuter(JoinConstraint::On(expr)) = &join.join_operator
+    {
+        collect_expr(columns, expr);
+    }
+
+    if let JoinExecutor::Hash {
+        key_expr,
+        value_expr,
+        where_clause,
+    } = &join.join_executor
+    {
+        collect_expr(columns, key_expr);
+        collect_expr(columns, value_expr);
+        where_clause
+            .iter()
+            .for_each(|expr| collect_expr(columns, expr));
+    }
+}
+
+fn collect_expr(columns: &mut BTreeSet<String>, expr: &Expr) {
+    match PlanExpr::from(expr) {
+        PlanExpr::None => {}
+        PlanExpr::Identifier(ident) | PlanExpr::CompoundIdentifier { ident, .. } => {
+            columns.insert(ident.to_owned());
+        }
+        PlanExpr::Expr(expr) => collect_expr(columns, expr),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            collect_expr(columns, expr);
+            collect_expr(columns, expr2);
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            collect_expr(columns, expr);
+            collect_expr(columns, expr2);
+            collect_expr(columns, expr3);
+        }
+        PlanExpr::MultiExprs(exprs) => exprs
+            .into_iter()
+            .for_each(|expr| collect_expr(columns, expr)),
+        PlanExpr::Query(query) => collect_query(columns, query),
+        PlanExpr::QueryAndExpr { query, expr } => {
+            collect_query(columns, query);
+            collect_expr(columns, expr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::projected_columns,
+        crate::{ast::Statement, parse_sql::parse, translate::translate},
+    };
+
+    fn test(sql: &str, expected: Option<&[&str]>) {
+        let parsed = parse(sql).unwrap();
+        let Statement::Query(query) = translate(&parsed[0]).unwrap() else {
+            panic!("query expected");
+        };
+        let expected =
+            expected.map(|columns| columns.iter().map(|&column| column.to_owned()).collect());
+
+        assert_eq!(projected_columns(&query), expected, "{sql}");
+    }
+
+    #[test]
+    fn projected() {
+        test("SELECT * FROM Blocks", None);
+        test("SELECT b.*, number FROM Blocks b", None);
+        test("SELECT COUNT(*) FROM Blocks", Some(&[]));
+        test(
+            "SELECT number, SUM(gas_used) AS gas FROM Blocks b
+             WHERE b.miner = '0xaa' AND timestamp BETWEEN 1 AND 2
+             GROUP BY number HAVING MAX(base_fee) > 1
+             ORDER BY gas DESC",
+            Some(&[
+                "base_fee",
+                "gas",
+                "gas_used",
+                "miner",
+                "number",
+                "timestamp",
+            ]),
+        );
+        test(
+            "SELECT hash FROM Blocks b
+             JOIN Transactions t ON t.block_number = b.number
+             WHERE EXISTS (SELECT * FROM Logs l WHERE l.tx_hash = t.hash)
+             AND number IN (SELECT block FROM (SELECT block, value FROM Rewards) r)",
+            Some(&[
+                "block",
+                "block_number",
+                "hash",
+                "number",
+                "tx_hash",
+                "value",
+            ]),
+        );
+        test(
+            "SELECT hash FROM Transactions
+             WHERE sender IN (SELECT miner FROM Blocks UNION SELECT validator FROM Rewards)",
+            Some(&["hash", "miner", "sender", "validator"]),
+        );
+        test(
+            "SELECT DISTINCT ON (sender) hash FROM Transactions",
+            Some(&["hash", "sender"]),
+        );
+    }
+}
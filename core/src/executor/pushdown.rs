@@ -0,0 +1,273 @@
+use {
+    super::evaluate::evaluate_stateless,
+    crate::{
+        ast::{BinaryOperator, Expr, IndexOperator},
+        data::Value,
+        plan::is_stateless,
+        store::Predicate,
+    },
+    std::ops::Range,
+};
+
+/// Conjuncts of a `WHERE` clause, with the [`Predicate`]s of those which can be handed to
+/// the store scanning the table.
+pub struct Pushdown<'a> {
+    where_clause: &'a Expr,
+    conjuncts: Vec<(&'a Expr, Option<Range<usize>>)>,
+    predicates: Vec<Predicate>,
+}
+
+impl<'a> Pushdown<'a> {
+    /// `alias` is the name columns of the scanned table are qualified with. When the table is
+    /// `joined` to others, only columns qualified with `alias` are taken, as an unqualified one
+    /// may belong to any of them.
+    pub async fn new(alias: &str, joined: bool, where_clause: &'a Expr) -> Pushdown<'a> {
+        let mut conjuncts = Vec::new();
+        let mut predicates = Vec::new();
+
+        for expr in split_conjuncts(where_clause) {
+            let range = match extract_predicates(alias, joined, expr).await {
+                Some(extracted) => {
+                    let start = predicates.len();
+                    predicates.extend(extracted);
+
+                    Some(start..predicates.len())
+                }
+                None => None,
+            };
+
+            conjuncts.push((expr, range));
+        }
+
+        Self {
+            where_clause,
+            conjuncts,
+            predicates,
+        }
+    }
+
+    pub fn predicates(&self) -> &[Predicate] {
+        &self.predicates
+    }
+
+    /// Conjuncts whose predicates were all handled by the store.
+    pub fn handled(&self, handled: &[bool]) -> Vec<&'a Expr> {
+        self.conjuncts
+            .iter()
+            .filter_map(|(expr, range)| is_handled(range, handled).then_some(*expr))
+            .collect()
+    }
+
+    /// Conjuncts the executor still has to check, given which predicates the store handled.
+    ///
+    /// When the store handled nothing, the `WHERE` clause is returned untouched.
+    pub fn residual(&self, handled: &[bool]) -> Vec<&'a Expr> {
+        if !self
+            .conjuncts
+            .iter()
+            .any(|(_, range)| is_handled(range, handled))
+        {
+            return vec![self.where_clause];
+        }
+
+        self.conjuncts
+            .iter()
+            .filter_map(|(expr, range)| (!is_handled(range, handled)).then_some(*expr))
+            .collect()
+    }
+}
+
+fn is_handled(range: &Option<Range<usize>>, handled: &[bool]) -> bool {
+    range
+        .clone()
+        .is_some_and(|range| range.into_iter().all(|i| handled.get(i) == Some(&true)))
+}
+
+fn split_conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::Nested(expr) => split_conjuncts(expr),
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjuncts(left);
+            conjuncts.extend(split_conjuncts(right));
+
+            conjuncts
+        }
+        _ => vec![expr],
+    }
+}
+
+async fn extract_predicates(alias: &str, joined: bool, expr: &Expr) -> Option<Vec<Predicate>> {
+    match expr {
+        Expr::BinaryOp { left, op, right } => {
+            let op = match op {
+                BinaryOperator::Gt => IndexOperator::Gt,
+                BinaryOperator::Lt => IndexOperator::Lt,
+                BinaryOperator::GtEq => IndexOperator::GtEq,
+                BinaryOperator::LtEq => IndexOperator::LtEq,
+                BinaryOperator::Eq => IndexOperator::Eq,
+                _ => return None,
+            };
+
+            let predicate = match (
+                column_name(alias, joined, left),
+                column_name(alias, joined, right),
+            ) {
+                (Some(column), None) => Predicate {
+                    column,
+                    op,
+                    value: constant(right).await?,
+                },
+                (None, Some(column)) => Predicate {
+                    column,
+                    op: op.reverse(),
+                    value: constant(left).await?,
+                },
+                _ => return None,
+            };
+
+            Some(vec![predicate])
+        }
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } => {
+            let column = column_name(alias, joined, expr)?;
+            let low = constant(low).await?;
+            let high = constant(high).await?;
+
+            Some(vec![
+                Predicate {
+                    column: column.clone(),
+                    op: IndexOperator::GtEq,
+                    value: low,
+                },
+                Predicate {
+                    column,
+                    op: IndexOperator::LtEq,
+                    value: high,
+                },
+            ])
+        }
+        _ => None,
+    }
+}
+
+fn column_name(alias: &str, joined: bool, expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Nested(expr) => column_name(alias, joined, expr),
+        Expr::Identifier(ident) if !joined => Some(ident.to_owned()),
+        Expr::CompoundIdentifier {
+            alias: target,
+            ident,
+        } if target == alias => Some(ident.to_owned()),
+        _ => None,
+    }
+}
+
+async fn constant(expr: &Expr) -> Option<Value> {
+    if !is_stateless(expr) {
+        return None;
+    }
+
+    let value = evaluate_stateless(None, expr).await.ok()?;
+
+    Value::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Pushdown,
+        crate::{
+            ast::{Expr, IndexOperator, SetExpr, Statement},
+            data::Value,
+            parse_sql::parse,
+            store::Predicate,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn selection(sql: &str) -> Expr {
+        let parsed = parse(format!("SELECT * FROM Blocks b WHERE {sql}")).unwrap();
+        let Statement::Query(query) = translate(&parsed[0]).unwrap() else {
+            panic!("query expected");
+        };
+        let SetExpr::Select(select) = query.body else {
+            panic!("select expected");
+        };
+
+        select.selection.unwrap()
+    }
+
+    fn predicate(op: IndexOperator, value: i64) -> Predicate {
+        Predicate {
+            column: "number".to_owned(),
+            op,
+            value: Value::I64(value),
+        }
+    }
+
+    #[test]
+    fn pushdown() {
+        let expr = selection("number > 10 OR number < 5");
+        let pushdown = block_on(Pushdown::new("b", false, &expr));
+        assert_eq!(pushdown.predicates(), &[]);
+        assert_eq!(pushdown.residual(&[]), vec![&expr]);
+
+        let expr = selection(
+            "number BETWEEN 10 AND 20 AND (5 + 1 < b.number) AND o.number = 1 AND number > id",
+        );
+        let pushdown = block_on(Pushdown::new("b", false, &expr));
+        assert_eq!(
+            pushdown.predicates(),
+            &[
+                predicate(IndexOperator::GtEq, 10),
+                predicate(IndexOperator::LtEq, 20),
+                predicate(IndexOperator::Gt, 6),
+            ]
+        );
+        assert_eq!(pushdown.residual(&[false, false, false]), vec![&expr]);
+        assert_eq!(
+            pushdown.handled(&[false, false, false]),
+            Vec::<&Expr>::new()
+        );
+
+        let residual = pushdown.residual(&[true, true, false]);
+        let expected = [
+            selection("5 + 1 < b.number"),
+            selection("o.number = 1"),
+            selection("number > id"),
+        ];
+        assert_eq!(residual, expected.iter().collect::<Vec<_>>());
+        assert_eq!(
+            pushdown.handled(&[true, true, false]),
+            vec![&selection("number BETWEEN 10 AND 20")]
+        );
+
+        let residual = pushdown.residual(&[true, false, true]);
+        let expected = [
+            selection("number BETWEEN 10 AND 20"),
+            selection("o.number = 1"),
+            selection("number > id"),
+        ];
+        assert_eq!(residual, expected.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn pushdown_joined() {
+        let expr = selection("number = 1 AND b.number > 2 AND o.number < 3");
+        let pushdown = block_on(Pushdown::new("b", true, &expr));
+        assert_eq!(pushdown.predicates(), &[predicate(IndexOperator::Gt, 2)]);
+
+        let residual = pushdown.residual(&[true]);
+        let expected = [selection("number = 1"), selection("o.number < 3")];
+        assert_eq!(residual, expected.iter().collect::<Vec<_>>());
+    }
+}
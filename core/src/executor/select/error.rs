@@ -1,7 +1,16 @@
-use {serde::Serialize, std::fmt::Debug, thiserror::Error};
+use {crate::ast::DataType, serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error("each {0} query must have the same number of columns: {1} and {2} found")]
+    NumberOfColumnsDifferent(String, usize, usize),
+
+    #[error("{0} cannot combine rows of unknown columns with rows of known columns")]
+    ColumnsUnknown(String),
+
+    #[error("each {0} query must have compatible types for column {1}: {2} and {3} found")]
+    ColumnTypesDifferent(String, String, DataType, DataType),
 }
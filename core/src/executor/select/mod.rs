@@ -1,29 +1,34 @@
 mod error;
 mod project;
+mod set_operation;
 
 pub use error::SelectError;
 
 use {
-    self::project::Project,
+    self::{project::Project, set_operation::combine},
     super::{
         aggregate::Aggregator,
         context::{AggregateContext, RowContext},
+        distinct::{dedup, Distinct},
         evaluate::evaluate_stateless,
-        fetch::{fetch_labels, fetch_relation_rows},
+        explain::{meter, Meter, Profile},
+        fetch::{fetch_filtered_relation_rows, fetch_labels},
         filter::Filter,
         join::Join,
         limit::Limit,
-        sort::Sort,
+        projection::projected_columns,
+        pushdown::Pushdown,
+        sort::{column_index_value, Sort},
     },
     crate::{
-        ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
+        ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableFactor, TableWithJoins, Values},
         data::{get_alias, Key, Row, Value},
         result::Result,
         store::GStore,
     },
     async_recursion::async_recursion,
     futures::stream::{self, Stream, StreamExt, TryStreamExt},
-    std::{borrow::Cow, rc::Rc},
+    std::{borrow::Cow, rc::Rc, time::Instant},
     utils::Vector,
 };
 
@@ -74,14 +79,17 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
         .then(|row| async move {
             stream::iter(order_by)
                 .then(|OrderByExpr { expr, asc }| {
-                    let row = Some(&row);
+                    let row = &row;
 
                     async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)
-                            .map(|key| (key, *asc))
+                        let value = match column_index_value(expr, row)? {
+                            Some(value) => value,
+                            None => evaluate_stateless(Some(row.as_context()), expr)
+                                .await
+                                .and_then(Value::try_from)?,
+                        };
+
+                        Key::try_from(value).map(|key| (key, *asc))
                     }
                 })
                 .try_collect::<Vec<_>>()
@@ -99,22 +107,37 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     Ok(sorted)
 }
 
+pub async fn select_with_labels<'a, T: GStore>(
+    storage: &'a T,
+    query: &'a Query,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
+    select_with_profile(storage, query, filter_context, None).await
+}
+
+/// Runs `query` as [`select_with_labels`] does, measuring its operators on `profile` when
+/// given.
 #[async_recursion(?Send)]
-pub async fn select_with_labels<'a, T>(
+pub async fn select_with_profile<'a, T>(
     storage: &'a T,
     query: &'a Query,
     filter_context: Option<Rc<RowContext<'a>>>,
+    profile: Option<Rc<Profile>>,
 ) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)>
 where
     T: GStore,
 {
+    let meter_of = |f: fn(&Profile) -> &Rc<Meter>| profile.as_deref().map(|p| Rc::clone(f(p)));
+
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        SetOperation(S3),
     }
 
     let Select {
+        distinct,
         from: table_with_joins,
         selection: where_clause,
         projection,
@@ -127,23 +150,80 @@ where
             let (rows, labels) = rows_with_labels(values_list).await?;
             let rows = sort_stateless(rows, &query.order_by).await?;
             let rows = stream::iter(rows.into_iter().map(Ok));
-            let rows = limit.apply(rows);
+            let rows = meter(meter_of(|p| &p.scan), limit.apply(rows));
 
             return Ok((Some(labels), Row::Values(rows)));
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let operands = profile.as_ref().map(|p| p.operands.clone());
+            let (rows, labels) =
+                combine(storage, *op, *all, left, right, filter_context, operands).await?;
+            let rows = sort_stateless(rows, &query.order_by).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = meter(meter_of(|p| &p.scan), limit.apply(rows));
+
+            return Ok((labels, Row::SetOperation(rows)));
+        }
     };
 
     let TableWithJoins { relation, joins } = &table_with_joins;
-    let rows = fetch_relation_rows(storage, relation, &None)
-        .await?
-        .map(move |row| {
-            let row = row?;
-            let alias = get_alias(relation);
+    let pushdown = match (where_clause, relation) {
+        (Some(expr), TableFactor::Table { alias, .. })
+            if alias.as_ref().is_none_or(|alias| alias.columns.is_empty()) =>
+        {
+            Some(Pushdown::new(get_alias(relation), !joins.is_empty(), expr).await)
+        }
+        _ => None,
+    };
+    let predicates = pushdown
+        .as_ref()
+        .map(Pushdown::predicates)
+        .unwrap_or_default();
+    let projected_columns = match relation {
+        TableFactor::Table { alias, .. }
+            if alias.as_ref().is_none_or(|alias| alias.columns.is_empty()) =>
+        {
+            projected_columns(query)
+        }
+        _ => None,
+    };
+    let (rows, handled) = fetch_filtered_relation_rows(
+        storage,
+        relation,
+        &None,
+        predicates,
+        projected_columns.as_deref(),
+    )
+    .await?;
+    let conjuncts = match &pushdown {
+        Some(pushdown) => pushdown.residual(&handled),
+        None => where_clause.iter().collect(),
+    };
+    let rows = rows.map(move |row| {
+        let row = row?;
+        let alias = get_alias(relation);
 
-            Ok(RowContext::new(alias, Cow::Owned(row), None))
-        });
+        Ok(RowContext::new(alias, Cow::Owned(row), None))
+    });
+    let rows = meter(meter_of(|p| &p.scan), rows);
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Rc::clone));
+    let (join_meters, join_scan_meters) = profile
+        .as_ref()
+        .map(|profile| (profile.joins.clone(), profile.join_scans.clone()))
+        .unwrap_or_default();
+    let join = Join::new(
+        storage,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+        join_meters,
+        join_scan_meters,
+    );
     let aggregate = Aggregator::new(
         storage,
         projection,
@@ -153,11 +233,16 @@ where
     );
     let filter = Rc::new(Filter::new(
         storage,
-        where_clause.as_ref(),
+        conjuncts,
         filter_context.as_ref().map(Rc::clone),
         None,
     ));
     let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+    let distinct = Distinct::new(
+        storage,
+        filter_context.as_ref().map(Rc::clone),
+        distinct.as_ref(),
+    );
     let sort = Sort::new(
         storage,
         filter_context.as_ref().map(Rc::clone),
@@ -175,8 +260,15 @@ where
                 .map(|pass| pass.then_some(project_context))
         }
     });
+    let rows = meter(meter_of(|p| &p.filter), rows);
 
+    let aggregate_meter = meter_of(|p| &p.aggregate);
+    let started = Instant::now();
     let rows = aggregate.apply(rows).await?;
+    if let Some(aggregate_meter) = &aggregate_meter {
+        aggregate_meter.add_elapsed(started);
+    }
+    let rows = meter(aggregate_meter, rows);
 
     let labels = fetch_labels(storage, relation, joins, projection)
         .await?
@@ -198,9 +290,18 @@ where
             Ok((aggregated, next, row))
         }
     });
+    let rows = meter(meter_of(|p| &p.project), rows);
+    let rows = distinct.key(rows, get_alias(relation));
 
+    let sort_meter = meter_of(|p| &p.sort);
+    let started = Instant::now();
     let rows = sort.apply(rows, get_alias(relation)).await?;
-    let rows = limit.apply(rows);
+    if let Some(sort_meter) = &sort_meter {
+        sort_meter.add_elapsed(started);
+    }
+    let rows = meter(sort_meter, rows);
+    let rows = meter(meter_of(|p| &p.distinct), dedup(rows));
+    let rows = meter(meter_of(|p| &p.limit), limit.apply(rows));
     let labels = labels.map(|labels| labels.iter().cloned().collect());
 
     Ok((labels, Row::Select(rows)))
@@ -0,0 +1,168 @@
+use {
+    super::{select_with_profile, SelectError},
+    crate::{
+        ast::{DataType, Query, SetExpr, SetOperator},
+        data::Row,
+        executor::{context::RowContext, distinct::row_key, explain::Profile},
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::TryStreamExt,
+    std::{
+        collections::{HashMap, HashSet},
+        rc::Rc,
+    },
+};
+
+/// Rows of `left op [ALL] right`, labeled by `left`.
+///
+/// Without `ALL`, duplicated rows are removed. With it, `INTERSECT ALL` keeps a row as many
+/// times as it appears in both operands and `EXCEPT ALL` as many times more as it appears in
+/// `left` than in `right`. Each column must hold values of the same type in both operands, or
+/// numeric values in both.
+///
+/// Under `EXPLAIN ANALYZE`, each operand is measured on the profile of the same position in
+/// `profiles`.
+pub async fn combine<'a, T: GStore>(
+    storage: &'a T,
+    op: SetOperator,
+    all: bool,
+    left: &'a SetExpr,
+    right: &'a SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    profiles: Option<Vec<Rc<Profile>>>,
+) -> Result<(Vec<Row>, Option<Vec<String>>)> {
+    let profile = |i: usize| profiles.as_ref().and_then(|p| p.get(i)).map(Rc::clone);
+    let (left, labels) = operand(
+        storage,
+        left,
+        filter_context.as_ref().map(Rc::clone),
+        profile(0),
+    )
+    .await?;
+    let (right, right_labels) = operand(storage, right, filter_context, profile(1)).await?;
+
+    let columns: Option<Rc<[String]>> = match (&labels, right_labels) {
+        (Some(labels), Some(right_labels)) if labels.len() != right_labels.len() => {
+            return Err(SelectError::NumberOfColumnsDifferent(
+                op.to_string(),
+                labels.len(),
+                right_labels.len(),
+            )
+            .into());
+        }
+        (Some(labels), Some(_)) => {
+            let types = column_types(&left, labels.len())
+                .into_iter()
+                .zip(column_types(&right, labels.len()));
+
+            for (label, types) in labels.iter().zip(types) {
+                if let (Some(left_type), Some(right_type)) = types {
+                    let compatible = left_type == right_type
+                        || (left_type.is_numeric() && right_type.is_numeric());
+
+                    if !compatible {
+                        return Err(SelectError::ColumnTypesDifferent(
+                            op.to_string(),
+                            label.to_owned(),
+                            left_type,
+                            right_type,
+                        )
+                        .into());
+                    }
+                }
+            }
+
+            Some(Rc::from(labels.as_slice()))
+        }
+        (None, None) => None,
+        (Some(_), None) | (None, Some(_)) => {
+            return Err(SelectError::ColumnsUnknown(op.to_string()).into());
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut keys = HashSet::new();
+
+    match op {
+        SetOperator::Union => {
+            let right = right.into_iter().map(|row| match (row, &columns) {
+                (Row::Vec { values, .. }, Some(columns)) => Row::Vec {
+                    columns: Rc::clone(columns),
+                    values,
+                },
+                (row, _) => row,
+            });
+
+            for row in left.into_iter().chain(right) {
+                if all || keys.insert(row_key(&row)?) {
+                    rows.push(row);
+                }
+            }
+        }
+        SetOperator::Intersect | SetOperator::Except => {
+            let mut counts = HashMap::new();
+            for row in &right {
+                *counts.entry(row_key(row)?).or_insert(0) += 1;
+            }
+
+            for row in left {
+                let key = row_key(&row)?;
+                let matched = match counts.get_mut(&key) {
+                    Some(count) if *count > 0 => {
+                        if all {
+                            *count -= 1;
+                        }
+
+                        true
+                    }
+                    _ => false,
+                };
+
+                let kept = matched == (op == SetOperator::Intersect);
+                if kept && (all || keys.insert(key)) {
+                    rows.push(row);
+                }
+            }
+        }
+    }
+
+    Ok((rows, labels))
+}
+
+/// Type of each of the `len` columns of `rows`, taken from their first non-null value.
+fn column_types(rows: &[Row], len: usize) -> Vec<Option<DataType>> {
+    let mut types = vec![None; len];
+
+    for row in rows {
+        let Row::Vec { values, .. } = row else {
+            continue;
+        };
+
+        for (data_type, value) in types.iter_mut().zip(values) {
+            if data_type.is_none() {
+                *data_type = value.get_type();
+            }
+        }
+    }
+
+    types
+}
+
+async fn operand<'a, T: GStore>(
+    storage: &'a T,
+    set_expr: &'a SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    profile: Option<Rc<Profile>>,
+) -> Result<(Vec<Row>, Option<Vec<String>>)> {
+    let query = Query {
+        body: set_expr.clone(),
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    };
+    let (labels, rows) = select_with_profile(storage, &query, filter_context, profile).await?;
+    let rows = rows.try_collect().await?;
+
+    Ok((rows, labels))
+}
@@ -10,7 +10,12 @@ use {
     futures::stream::{self, Stream, StreamExt, TryStreamExt},
     im_rc::HashMap,
     serde::Serialize,
-    std::{borrow::Cow, cmp::Ordering, fmt::Debug, rc::Rc},
+    std::{
+        borrow::{Borrow, Cow},
+        cmp::Ordering,
+        fmt::Debug,
+        rc::Rc,
+    },
     thiserror::Error as ThisError,
     utils::Vector,
 };
@@ -42,17 +47,17 @@ impl<'a, T: GStore> Sort<'a, T> {
         }
     }
 
-    pub async fn apply(
+    pub async fn apply<R: Borrow<Row> + 'a>(
         &self,
         rows: impl Stream<
                 Item = Result<(
                     Option<Rc<HashMap<&'a Aggregate, Value>>>,
                     Rc<RowContext<'a>>,
-                    Row,
+                    R,
                 )>,
             > + 'a,
         table_alias: &'a str,
-    ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
+    ) -> Result<impl Stream<Item = Result<R>> + 'a> {
         #[derive(futures_enum::Stream)]
         enum Rows<I1, I2> {
             NonOrderBy(I1),
@@ -76,33 +81,9 @@ impl<'a, T: GStore> Sort<'a, T> {
                 let order_by = order_by
                     .iter()
                     .map(|OrderByExpr { expr, asc }| -> Result<_> {
-                        let big_decimal = match expr {
-                            Expr::Literal(AstLiteral::Number(n)) => Some(n),
-                            Expr::UnaryOp {
-                                op: UnaryOperator::Plus,
-                                expr,
-                            } => match expr.as_ref() {
-                                Expr::Literal(AstLiteral::Number(n)) => Some(n),
-                                _ => None,
-                            },
-                            _ => None,
-                        };
-
-                        match (big_decimal, &row) {
-                            (Some(n), Row::Vec { values, .. }) => {
-                                let index = n
-                                    .to_usize()
-                                    .ok_or_else(|| -> Error { SortError::Unreachable.into() })?;
-                                let zero_based = index.checked_sub(1).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-                                let value = values.get(zero_based).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-
-                                Ok((SortType::Value(value.clone()), *asc))
-                            }
-                            _ => Ok((SortType::Expr(expr), *asc)),
+                        match column_index_value(expr, row.borrow())? {
+                            Some(value) => Ok((SortType::Value(value), *asc)),
+                            None => Ok((SortType::Expr(expr), *asc)),
                         }
                     })
                     .collect::<Result<Vec<_>>>();
@@ -115,7 +96,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                 };
 
                 async move {
-                    let context = RowContext::new(table_alias, Cow::Borrowed(&row), None);
+                    let context = RowContext::new(table_alias, Cow::Borrowed(row.borrow()), None);
                     let label_context = Rc::new(context);
                     let filter_context = Rc::new(RowContext::concat(
                         filter_context,
@@ -150,7 +131,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                     Ok((keys, row))
                 }
             })
-            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, Row)>>()
+            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, R)>>()
             .await
             .map(Vector::from)?
             .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
@@ -161,6 +142,39 @@ impl<'a, T: GStore> Sort<'a, T> {
     }
 }
 
+/// Value of `row` at the column index `expr` names, as in `ORDER BY 1`, or `None` when `expr`
+/// is not a column index.
+pub fn column_index_value(expr: &Expr, row: &Row) -> Result<Option<Value>> {
+    let big_decimal = match expr {
+        Expr::Literal(AstLiteral::Number(n)) => Some(n),
+        Expr::UnaryOp {
+            op: UnaryOperator::Plus,
+            expr,
+        } => match expr.as_ref() {
+            Expr::Literal(AstLiteral::Number(n)) => Some(n),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match (big_decimal, row) {
+        (Some(n), Row::Vec { values, .. }) => {
+            let index = n
+                .to_usize()
+                .ok_or_else(|| -> Error { SortError::Unreachable.into() })?;
+            let zero_based = index
+                .checked_sub(1)
+                .ok_or_else(|| -> Error { SortError::ColumnIndexOutOfRange(index).into() })?;
+            let value = values
+                .get(zero_based)
+                .ok_or_else(|| -> Error { SortError::ColumnIndexOutOfRange(index).into() })?;
+
+            Ok(Some(value.clone()))
+        }
+        _ => Ok(None),
+    }
+}
+
 pub fn sort_by(keys_a: &[(Key, Option<bool>)], keys_b: &[(Key, Option<bool>)]) -> Ordering {
     let pairs = keys_a
         .iter()
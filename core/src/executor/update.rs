@@ -0,0 +1,122 @@
+use {
+    super::{context::RowContext, evaluate::evaluate, fetch::fetch, ExecuteError},
+    crate::{
+        ast::{Assignment, ColumnDef, Expr},
+        data::{Row, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+    serde::Serialize,
+    std::{borrow::Cow, fmt::Debug, rc::Rc},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum UpdateError {
+    #[error("table not found: {0}")]
+    TableNotFound(String),
+
+    #[error("column not found: {0}")]
+    ColumnNotFound(String),
+}
+
+/// Sets the `assignments` on the rows of `table_name` satisfying `selection`, and returns the
+/// number of rows updated.
+///
+/// Every assignment is evaluated against the row before the update. Views are not written to.
+pub async fn update<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    assignments: &[Assignment],
+    selection: Option<&Expr>,
+) -> Result<usize> {
+    let Schema {
+        column_defs, view, ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| UpdateError::TableNotFound(table_name.to_owned()))?;
+
+    if view.is_some() {
+        return Err(ExecuteError::NotATable(table_name.to_owned()).into());
+    }
+
+    if let Some(column_defs) = &column_defs {
+        if let Some(Assignment { id, .. }) = assignments.iter().find(|Assignment { id, .. }| {
+            !column_defs.iter().any(|column_def| &column_def.name == id)
+        }) {
+            return Err(UpdateError::ColumnNotFound(id.clone()).into());
+        }
+    }
+
+    let columns = column_defs.as_ref().map(|column_defs| {
+        column_defs
+            .iter()
+            .map(|column_def| column_def.name.clone())
+            .collect::<Rc<[String]>>()
+    });
+    let rows = fetch(storage, table_name, columns, selection)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut updated = Vec::with_capacity(rows.len());
+    for (key, row) in rows {
+        let context = Rc::new(RowContext::new(table_name, Cow::Borrowed(&row), None));
+
+        let mut values = Vec::with_capacity(assignments.len());
+        for Assignment { id, value } in assignments {
+            let evaluated = evaluate(storage, Some(Rc::clone(&context)), None, value).await?;
+            let column_def = column_defs
+                .iter()
+                .flatten()
+                .find(|column_def| &column_def.name == id);
+            let value = match column_def {
+                Some(ColumnDef {
+                    data_type,
+                    nullable,
+                    ..
+                }) => {
+                    let value = evaluated.try_into_value(data_type, *nullable)?;
+                    value.validate_type(data_type)?;
+
+                    value
+                }
+                None => Value::try_from(evaluated)?,
+            };
+
+            values.push((id, value));
+        }
+        drop(context);
+
+        let data_row = match row {
+            Row::Vec {
+                columns,
+                values: mut row_values,
+            } => {
+                for (id, value) in values {
+                    if let Some(i) = columns.iter().position(|column| column == id) {
+                        row_values[i] = value;
+                    }
+                }
+
+                DataRow::Vec(row_values)
+            }
+            Row::Map(mut row_values) => {
+                row_values.extend(values.into_iter().map(|(id, value)| (id.clone(), value)));
+
+                DataRow::Map(row_values)
+            }
+        };
+
+        updated.push((key, data_row));
+    }
+
+    let num_rows = updated.len();
+    if num_rows > 0 {
+        storage.insert_data(table_name, updated).await?;
+    }
+
+    Ok(num_rows)
+}
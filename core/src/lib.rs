@@ -21,7 +21,7 @@ pub mod prelude {
     pub use crate::{
         ast::DataType,
         data::{Key, Row, Value},
-        executor::{execute, Payload, PayloadVariable},
+        executor::{execute, Analyzed, ExplainRow, Payload, PayloadVariable},
         parse_sql::parse,
         plan::plan,
         result::{Error, Result},
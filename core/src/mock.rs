@@ -2,7 +2,10 @@ use {
     crate::{
         data::{Key, Schema},
         result::{Error, Result},
-        store::{DataRow, Metadata, RowIter, Store, StoreMut, Transaction},
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, RowIter, Store,
+            StoreMut, Transaction,
+        },
     },
     async_trait::async_trait,
     std::collections::HashMap,
@@ -78,7 +81,11 @@ impl StoreMut for MockStorage {
     }
 }
 
+impl Index for MockStorage {}
+impl IndexMut for MockStorage {}
 impl Transaction for MockStorage {}
+impl CustomFunction for MockStorage {}
+impl CustomFunctionMut for MockStorage {}
 // impl Metadata for MockStorage {}
 
 #[cfg(test)]
@@ -3,12 +3,14 @@ use {
     sqlparser::{
         ast::{
             Assignment as SqlAssignment, ColumnDef as SqlColumnDef, DataType as SqlDataType,
-            Expr as SqlExpr, Ident as SqlIdent, OrderByExpr as SqlOrderByExpr, Query as SqlQuery,
-            SelectItem as SqlSelectItem, Statement as SqlStatement,
+            Expr as SqlExpr, Ident as SqlIdent, ObjectName as SqlObjectName,
+            OrderByExpr as SqlOrderByExpr, Query as SqlQuery, SelectItem as SqlSelectItem,
+            Statement as SqlStatement,
         },
         dialect::PostgreSqlDialect,
-        parser::Parser,
-        tokenizer::Tokenizer,
+        keywords::Keyword,
+        parser::{Parser, ParserError},
+        tokenizer::{Token, Tokenizer},
     },
 };
 
@@ -18,6 +20,44 @@ pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
     Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
 }
 
+/// Statement parsed by [`parse_statements`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedStatement {
+    Sql(Box<SqlStatement>),
+    /// `REFRESH MATERIALIZED VIEW name`, which sqlparser does not support.
+    RefreshView(SqlObjectName),
+}
+
+/// Parses `sql` like [`parse`], along with the statements sqlparser does not support.
+pub fn parse_statements<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<ParsedStatement>> {
+    parse_statements_inner(sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
+}
+
+fn parse_statements_inner(sql: &str) -> std::result::Result<Vec<ParsedStatement>, ParserError> {
+    let mut parser = Parser::new(&DIALECT).try_with_sql(sql)?;
+    let mut statements = Vec::new();
+
+    loop {
+        while parser.consume_token(&Token::SemiColon) {}
+
+        let statement = match parser.peek_token().token {
+            Token::EOF => return Ok(statements),
+            Token::Word(word) if word.value.eq_ignore_ascii_case("REFRESH") => {
+                parser.next_token();
+                parser.expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+
+                ParsedStatement::RefreshView(parser.parse_object_name(false)?)
+            }
+            _ => ParsedStatement::Sql(Box::new(parser.parse_statement()?)),
+        };
+        statements.push(statement);
+
+        if !parser.consume_token(&Token::SemiColon) && parser.peek_token().token != Token::EOF {
+            return parser.expected("end of statement", parser.peek_token());
+        }
+    }
+}
+
 macro_rules! generate_parse_fn {
     ($fn_name: ident, $output_type: ty) => {
         pub fn $fn_name<Sql: AsRef<str>>(sql_expr: Sql) -> Result<$output_type> {
@@ -1,8 +1,8 @@
 use {
     super::{context::Context, expr::PlanExpr},
     crate::ast::{
-        Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr, TableAlias,
-        TableFactor, TableWithJoins, Values,
+        Distinct, Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr,
+        TableAlias, TableFactor, TableWithJoins, Values,
     },
     std::{convert::identity, rc::Rc},
 };
@@ -46,16 +46,7 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
         offset,
     } = query;
 
-    let body = match body {
-        SetExpr::Select(select) => check_select(context.as_ref().map(Rc::clone), select),
-        SetExpr::Values(Values(rows)) => rows
-            .iter()
-            .flatten()
-            .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
-            .all(identity),
-    };
-
-    if !body {
+    if !check_set_expr(context.as_ref().map(Rc::clone), body) {
         return false;
     }
 
@@ -75,8 +66,23 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
         .all(identity)
 }
 
+fn check_set_expr(context: Option<Rc<Context<'_>>>, set_expr: &SetExpr) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => check_select(context, select),
+        SetExpr::Values(Values(rows)) => rows
+            .iter()
+            .flatten()
+            .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
+            .all(identity),
+        SetExpr::SetOperation { left, right, .. } => {
+            check_set_expr(context.as_ref().map(Rc::clone), left) && check_set_expr(context, right)
+        }
+    }
+}
+
 fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
     let Select {
+        distinct,
         projection,
         from,
         selection,
@@ -128,8 +134,14 @@ fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
         return false;
     }
 
-    selection
+    let distinct_on = match distinct {
+        Some(Distinct::On(exprs)) => exprs.as_slice(),
+        Some(Distinct::Distinct) | None => &[],
+    };
+
+    distinct_on
         .iter()
+        .chain(selection.iter())
         .chain(group_by.iter())
         .chain(having.iter())
         .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
@@ -1,8 +1,9 @@
 use {
     crate::{
         ast::{
-            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, OrderByExpr,
-            Query, Select, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            qualified_table_name, AstLiteral, BinaryOperator, Expr, Function, IndexItem,
+            IndexOperator, OrderByExpr, Query, Select, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins,
         },
         data::{Schema, SchemaIndex, SchemaIndexOrd, TableError},
         result::{Error, Result},
@@ -14,6 +15,32 @@ use {
 pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Result<Statement> {
     match statement {
         Statement::Query(query) => plan_query(schema_map, query).map(Statement::Query),
+        Statement::Explain { analyze, query } => Ok(Statement::Explain {
+            analyze,
+            query: Box::new(plan_query(schema_map, *query)?),
+        }),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+        } => Ok(Statement::Insert {
+            table_name,
+            columns,
+            source: plan_query(schema_map, source)?,
+        }),
+        Statement::CreateTable {
+            source: Some(source),
+            if_not_exists,
+            name,
+            columns,
+            comment,
+        } => Ok(Statement::CreateTable {
+            source: Some(Box::new(plan_query(schema_map, *source)?)),
+            if_not_exists,
+            name,
+            columns,
+            comment,
+        }),
         _ => Ok(statement),
     }
 }
@@ -66,11 +93,42 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
                 offset,
             });
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let plan_operand = |operand: Box<SetExpr>| -> Result<Box<SetExpr>> {
+                let query = Query {
+                    body: *operand,
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                };
+
+                plan_query(schema_map, query).map(|query| Box::new(query.body))
+            };
+
+            return Ok(Query {
+                body: SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: plan_operand(left)?,
+                    right: plan_operand(right)?,
+                },
+                order_by,
+                limit,
+                offset,
+            });
+        }
     };
 
     let TableWithJoins { relation, .. } = &select.from;
     let table_name = match relation {
-        TableFactor::Table { name, .. } => name,
+        TableFactor::Table {
+            chain_name, name, ..
+        } => qualified_table_name(chain_name.as_deref(), name),
         TableFactor::Derived { .. } => {
             return Ok(Query {
                 body: SetExpr::Select(select),
@@ -82,14 +140,14 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         TableFactor::Series {
             alias: TableAlias { name, .. },
             ..
-        } => name,
+        } => name.to_owned(),
         TableFactor::Dictionary {
             alias: TableAlias { name, .. },
             ..
-        } => name,
+        } => name.to_owned(),
     };
 
-    let indexes = match schema_map.get(table_name) {
+    let indexes = match schema_map.get(&table_name) {
         Some(Schema { indexes, .. }) => Indexes(indexes.clone()),
         None => {
             return Ok(Query {
@@ -114,6 +172,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
     match index {
         index if index.is_some() => {
             let Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -149,6 +208,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             let select = Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -184,6 +244,7 @@ fn plan_select(
     select: Select,
 ) -> Result<Select> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
@@ -195,6 +256,7 @@ fn plan_select(
         Some(expr) => expr,
         None => {
             return Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -206,6 +268,7 @@ fn plan_select(
 
     match plan_index(schema_map, indexes, selection)? {
         Planned::Expr(selection) => Ok(Select {
+            distinct,
             projection,
             from,
             selection: Some(selection),
@@ -251,6 +314,7 @@ fn plan_select(
             };
 
             Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -457,7 +521,7 @@ fn search_index_op(
     }
 }
 
-fn is_stateless(expr: &Expr) -> bool {
+pub(crate) fn is_stateless(expr: &Expr) -> bool {
     match expr {
         Expr::Literal(AstLiteral::Null) => false,
         Expr::Literal(_) => true,
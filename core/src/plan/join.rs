@@ -20,6 +20,44 @@ pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> State
 
             Statement::Query(query)
         }
+        Statement::Explain { analyze, query } => {
+            let query = planner.query(None, *query);
+
+            Statement::Explain {
+                analyze,
+                query: Box::new(query),
+            }
+        }
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+        } => {
+            let source = planner.query(None, source);
+
+            Statement::Insert {
+                table_name,
+                columns,
+                source,
+            }
+        }
+        Statement::CreateTable {
+            source: Some(source),
+            if_not_exists,
+            name,
+            columns,
+            comment,
+        } => {
+            let source = planner.query(None, *source);
+
+            Statement::CreateTable {
+                source: Some(Box::new(source)),
+                if_not_exists,
+                name,
+                columns,
+                comment,
+            }
+        }
         _ => statement,
     }
 }
@@ -37,14 +75,7 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
             offset,
         } = query;
 
-        let body = match body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => body,
-        };
+        let body = self.set_expr(outer_context, body);
 
         Query {
             body,
@@ -60,8 +91,36 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 }
 
 impl<'a> JoinPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left = self.set_expr(outer_context.as_ref().map(Rc::clone), *left);
+                let right = self.set_expr(outer_context, *right);
+
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+        }
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
         let Select {
+            distinct,
             projection,
             from,
             selection,
@@ -73,6 +132,7 @@ impl<'a> JoinPlanner<'a> {
         let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
 
         Select {
+            distinct,
             projection,
             from,
             selection,
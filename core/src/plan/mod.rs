@@ -7,16 +7,19 @@ mod join;
 mod planner;
 mod schema;
 mod validate;
+mod view;
 
 use crate::{ast::Statement, result::Result, store::Store};
 
 pub use {
     self::validate::validate, error::*, index::plan as plan_index, join::plan as plan_join,
-    schema::fetch_schema_map,
+    schema::fetch_schema_map, view::plan as plan_view,
 };
 
+pub(crate) use {expr::PlanExpr, index::is_stateless};
+
 pub async fn plan<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
-    let schema_map = fetch_schema_map(storage, &statement).await?;
+    let (statement, schema_map) = plan_view(storage, statement).await?;
     validate(&schema_map, &statement)?;
     let statement = plan_index(&schema_map, statement)?;
     let statement = plan_join(&schema_map, statement);
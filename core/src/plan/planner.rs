@@ -1,7 +1,7 @@
 use {
     super::context::Context,
     crate::{
-        ast::{ColumnDef, Expr, Function, Query, TableAlias, TableFactor},
+        ast::{qualified_table_name, ColumnDef, Expr, Function, Query, TableAlias, TableFactor},
         data::Schema,
     },
     std::rc::Rc,
@@ -189,18 +189,24 @@ pub trait Planner<'a> {
         next: Option<Rc<Context<'a>>>,
         table_factor: &TableFactor,
     ) -> Option<Rc<Context<'a>>> {
-        let (name, alias) = match table_factor {
-            TableFactor::Table { name, alias, .. } => {
+        let (name, table_name, alias) = match table_factor {
+            TableFactor::Table {
+                chain_name,
+                name,
+                alias,
+                ..
+            } => {
+                let table_name = qualified_table_name(chain_name.as_deref(), name);
                 let alias = alias.as_ref().map(|TableAlias { name, .. }| name.clone());
 
-                (name, alias)
+                (name, table_name, alias)
             }
             TableFactor::Derived { .. }
             | TableFactor::Series { .. }
             | TableFactor::Dictionary { .. } => return next,
         };
 
-        let column_defs = match self.get_schema(name) {
+        let column_defs = match self.get_schema(&table_name) {
             Some(Schema { column_defs, .. }) => column_defs,
             None => return next,
         };
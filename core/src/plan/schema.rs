@@ -2,8 +2,8 @@ use {
     super::expr::PlanExpr,
     crate::{
         ast::{
-            Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr,
-            Statement, TableFactor, TableWithJoins,
+            qualified_table_name, Distinct, Expr, Join, JoinConstraint, JoinOperator, Query,
+            Select, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins,
         },
         data::Schema,
         result::Result,
@@ -20,10 +20,46 @@ pub async fn fetch_schema_map<T: Store>(
 ) -> Result<HashMap<String, Schema>> {
     match statement {
         Statement::Query(query) => scan_query(storage, query).await,
+        Statement::Insert {
+            table_name, source, ..
+        } => {
+            let table_schema = scan_table(storage, table_name).await?;
+            let source_schema_list = scan_query(storage, source).await?;
+
+            Ok(table_schema.into_iter().chain(source_schema_list).collect())
+        }
+        Statement::CreateTable { name, source, .. } => {
+            let table_schema = scan_table(storage, name).await?;
+            let source_schema_list = match source {
+                Some(source) => scan_query(storage, source).await?,
+                None => HashMap::new(),
+            };
+
+            Ok(table_schema.into_iter().chain(source_schema_list).collect())
+        }
+        Statement::CreateView { query, .. } | Statement::Explain { query, .. } => {
+            scan_query(storage, query).await
+        }
+        Statement::DropTable { names, .. } => {
+            stream::iter(names)
+                .then(|name| scan_table(storage, name))
+                .try_fold(HashMap::new(), |mut schema_map, table_schema| async move {
+                    schema_map.extend(table_schema);
+
+                    Ok(schema_map)
+                })
+                .await
+        }
         _ => Ok(HashMap::new()),
     }
 }
 
+async fn scan_table<T: Store>(storage: &T, table_name: &str) -> Result<Option<(String, Schema)>> {
+    let schema = storage.fetch_schema(table_name).await?;
+
+    Ok(schema.map(|schema| (table_name.to_owned(), schema)))
+}
+
 async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<String, Schema>> {
     let Query {
         body,
@@ -32,10 +68,7 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
         ..
     } = query;
 
-    let schema_list = match body {
-        SetExpr::Select(select) => scan_select(storage, select).await?,
-        SetExpr::Values(_) => HashMap::new(),
-    };
+    let schema_list = scan_set_expr(storage, body).await?;
 
     let schema_list = match (limit, offset) {
         (Some(limit), Some(offset)) => schema_list
@@ -53,8 +86,26 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
     Ok(schema_list)
 }
 
+#[async_recursion(?Send)]
+async fn scan_set_expr<T>(storage: &T, set_expr: &SetExpr) -> Result<HashMap<String, Schema>>
+where
+    T: Store,
+{
+    match set_expr {
+        SetExpr::Select(select) => scan_select(storage, select).await,
+        SetExpr::Values(_) => Ok(HashMap::new()),
+        SetExpr::SetOperation { left, right, .. } => {
+            let left = scan_set_expr(storage, left).await?;
+            let right = scan_set_expr(storage, right).await?;
+
+            Ok(left.into_iter().chain(right).collect())
+        }
+    }
+}
+
 async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<String, Schema>> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
@@ -76,7 +127,15 @@ async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<S
 
     let from = scan_table_with_joins(storage, from).await?;
 
-    let exprs = selection.iter().chain(group_by.iter()).chain(having.iter());
+    let distinct_on = match distinct {
+        Some(Distinct::On(exprs)) => exprs.as_slice(),
+        Some(Distinct::Distinct) | None => &[],
+    };
+    let exprs = distinct_on
+        .iter()
+        .chain(selection.iter())
+        .chain(group_by.iter())
+        .chain(having.iter());
 
     Ok(stream::iter(exprs)
         .then(|expr| scan_expr(storage, expr))
@@ -137,11 +196,13 @@ where
     T: Store,
 {
     match table_factor {
-        TableFactor::Table { name, .. } => {
-            let schema = storage.fetch_schema(name).await?;
-            let schema_list: HashMap<String, Schema> = schema.map_or_else(HashMap::new, |schema| {
-                HashMap::from([(name.to_owned(), schema)])
-            });
+        TableFactor::Table {
+            chain_name, name, ..
+        } => {
+            let name = qualified_table_name(chain_name.as_deref(), name);
+            let schema = storage.fetch_schema(&name).await?;
+            let schema_list: HashMap<String, Schema> =
+                schema.map_or_else(HashMap::new, |schema| HashMap::from([(name, schema)]));
 
             Ok(schema_list)
         }
@@ -1,7 +1,10 @@
 use {
     super::PlanError,
     crate::{
-        ast::{Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins},
+        ast::{
+            qualified_table_name, Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor,
+            TableWithJoins,
+        },
         data::Schema,
         result::Result,
     },
@@ -13,6 +16,10 @@ type SchemaMap = HashMap<String, Schema>;
 pub fn validate(schema_map: &SchemaMap, statement: &Statement) -> Result<()> {
     let query = match statement {
         Statement::Query(query) => Some(query),
+        Statement::Insert { source, .. } => Some(source),
+        Statement::CreateTable { source, .. } => source.as_deref(),
+        Statement::CreateView { query, .. } => Some(query.as_ref()),
+        Statement::Explain { query, .. } => Some(query.as_ref()),
         _ => None,
     };
 
@@ -120,7 +127,7 @@ fn contextualize_query<'a>(schema_map: &'a SchemaMap, query: &'a Query) -> Optio
 
             Context::concat(by_table, by_joins)
         }
-        SetExpr::Values(_) => None,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
     }
 }
 
@@ -129,8 +136,10 @@ fn contextualize_table_factor<'a>(
     table_factor: &'a TableFactor,
 ) -> Option<Rc<Context<'a>>> {
     match table_factor {
-        TableFactor::Table { name, .. } => {
-            let schema = schema_map.get(name);
+        TableFactor::Table {
+            chain_name, name, ..
+        } => {
+            let schema = schema_map.get(&qualified_table_name(chain_name.as_deref(), name));
             schema.map(|schema| Rc::from(Context::new(get_labels(schema), None)))
         }
         TableFactor::Derived { subquery, .. } => contextualize_query(schema_map, subquery),
@@ -0,0 +1,262 @@
+use {
+    super::{context::Context, error::PlanError, planner::Planner, schema::fetch_schema_map},
+    crate::{
+        ast::{
+            qualified_table_name, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem,
+            SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+        },
+        data::{Schema, SchemaView},
+        result::Result,
+        store::Store,
+    },
+    std::{cell::Cell, collections::HashMap, rc::Rc},
+};
+
+/// Views nested deeper than this are taken as a view reading itself.
+const MAX_VIEW_DEPTH: usize = 32;
+
+/// Replaces every view read by `statement` with its query, and returns the statement along with
+/// the schemas of the tables it reads.
+///
+/// Materialized views are read as tables. The query of a view being created is left as is, so
+/// that the views it reads are expanded as they stand when it is read.
+pub async fn plan<T: Store>(
+    storage: &T,
+    statement: Statement,
+) -> Result<(Statement, HashMap<String, Schema>)> {
+    let mut statement = statement;
+    let mut expanded = String::new();
+
+    for _ in 0..=MAX_VIEW_DEPTH {
+        let schema_map = fetch_schema_map(storage, &statement).await?;
+        let planner = ViewPlanner {
+            schema_map: &schema_map,
+            expanded: Cell::new(None),
+        };
+        statement = planner.statement(statement);
+
+        match planner.expanded.take() {
+            Some(name) => expanded = name,
+            None => return Ok((statement, schema_map)),
+        }
+    }
+
+    Err(PlanError::ViewNestedTooDeep(expanded).into())
+}
+
+struct ViewPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+    /// Name of the last view expanded.
+    expanded: Cell<Option<String>>,
+}
+
+impl<'a> Planner<'a> for ViewPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let body = self.set_expr(outer_context, body);
+
+        Query {
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a> ViewPlanner<'a> {
+    fn statement(&self, statement: Statement) -> Statement {
+        match statement {
+            Statement::Query(query) => Statement::Query(self.query(None, query)),
+            Statement::Insert {
+                table_name,
+                columns,
+                source,
+            } => Statement::Insert {
+                table_name,
+                columns,
+                source: self.query(None, source),
+            },
+            Statement::CreateTable {
+                if_not_exists,
+                name,
+                columns,
+                source: Some(source),
+                comment,
+            } => Statement::CreateTable {
+                if_not_exists,
+                name,
+                columns,
+                source: Some(Box::new(self.query(None, *source))),
+                comment,
+            },
+            Statement::Explain { analyze, query } => Statement::Explain {
+                analyze,
+                query: Box::new(self.query(None, *query)),
+            },
+            _ => statement,
+        }
+    }
+
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left = self.set_expr(outer_context.as_ref().map(Rc::clone), *left);
+                let right = self.set_expr(outer_context, *right);
+
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+        }
+    }
+
+    fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
+        let Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let projection = projection
+            .into_iter()
+            .map(|select_item| match select_item {
+                SelectItem::Expr { expr, label } => SelectItem::Expr {
+                    expr: self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr),
+                    label,
+                },
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => select_item,
+            })
+            .collect();
+        let from = self.table_with_joins(outer_context.as_ref().map(Rc::clone), from);
+        let selection =
+            selection.map(|expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr));
+        let having = having.map(|expr| self.subquery_expr(outer_context, expr));
+
+        Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        }
+    }
+
+    fn table_with_joins(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        table_with_joins: TableWithJoins,
+    ) -> TableWithJoins {
+        let TableWithJoins { relation, joins } = table_with_joins;
+
+        let relation = self.table_factor(relation);
+        let joins = joins
+            .into_iter()
+            .map(|join| {
+                let Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                } = join;
+
+                let relation = self.table_factor(relation);
+                let join_operator = match join_operator {
+                    JoinOperator::Inner(JoinConstraint::On(expr)) => {
+                        JoinOperator::Inner(JoinConstraint::On(
+                            self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr),
+                        ))
+                    }
+                    JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+                        JoinOperator::LeftOuter(JoinConstraint::On(
+                            self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr),
+                        ))
+                    }
+                    JoinOperator::Inner(JoinConstraint::None)
+                    | JoinOperator::LeftOuter(JoinConstraint::None) => join_operator,
+                };
+
+                Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                }
+            })
+            .collect();
+
+        TableWithJoins { relation, joins }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        match table_factor {
+            TableFactor::Table {
+                chain_name,
+                name,
+                alias,
+                existing_table,
+                index,
+            } => {
+                let table_name = qualified_table_name(chain_name.as_deref(), &name);
+                let Some(Schema {
+                    view:
+                        Some(SchemaView {
+                            query,
+                            materialized: false,
+                        }),
+                    ..
+                }) = self.get_schema(&table_name)
+                else {
+                    return TableFactor::Table {
+                        chain_name,
+                        name,
+                        alias,
+                        existing_table,
+                        index,
+                    };
+                };
+
+                self.expanded.set(Some(table_name));
+
+                TableFactor::Derived {
+                    subquery: query.clone(),
+                    alias: alias.unwrap_or(TableAlias {
+                        name,
+                        columns: Vec::new(),
+                    }),
+                }
+            }
+            TableFactor::Derived { subquery, alias } => TableFactor::Derived {
+                subquery: self.query(None, subquery),
+                alias,
+            },
+            TableFactor::Series { .. } | TableFactor::Dictionary { .. } => table_factor,
+        }
+    }
+}
@@ -7,8 +7,12 @@ pub use crate::{
         ConvertError, IntervalError, KeyError, LiteralError, RowError, SchemaParseError,
         StringExtError, TableError, ValueError,
     },
-    executor::{AggregateError, EvaluateError, ExecuteError, FetchError, SelectError, SortError},
+    executor::{
+        AggregateError, AlterError, EvaluateError, ExecuteError, FetchError, InsertError,
+        SelectError, SortError, UpdateError,
+    },
     plan::PlanError,
+    store::IndexError,
     translate::TranslateError,
 };
 
@@ -29,6 +33,15 @@ pub enum Error {
     #[error("data: {0}")]
     AdapterError(#[from] AdapterError),
 
+    #[error("alter: {0}")]
+    Alter(#[from] AlterError),
+
+    #[error("insert: {0}")]
+    Insert(#[from] InsertError),
+
+    #[error("update: {0}")]
+    Update(#[from] UpdateError),
+
     #[error("execute: {0}")]
     Execute(#[from] ExecuteError),
 
@@ -47,6 +60,9 @@ pub enum Error {
     #[error("sort: {0}")]
     Sort(#[from] SortError),
 
+    #[error("index: {0}")]
+    Index(#[from] IndexError),
+
     #[error("table: {0}")]
     Table(#[from] TableError),
 
@@ -1,8 +1,9 @@
 use {
-    super::RowIter,
+    super::{DataRow, RowIter},
     crate::{
         ast::{IndexOperator, OrderByExpr},
-        data::Value,
+        data::{Schema, SchemaIndex, Value},
+        executor::{evaluate_stateless, RowContext},
         result::{Error, Result},
     },
     async_trait::async_trait,
@@ -38,6 +39,28 @@ pub enum IndexError {
     ConflictOnIndexDataDeleteSync,
 }
 
+/// Evaluates the expression of `index` against `row` of a table with `schema`, giving the
+/// value the row is indexed by.
+pub async fn index_value(schema: &Schema, index: &SchemaIndex, row: &DataRow) -> Result<Value> {
+    let columns = schema
+        .column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.clone())
+        .collect::<Vec<_>>();
+    let context = match row {
+        DataRow::Vec(values) => RowContext::RefVecData {
+            columns: &columns,
+            values,
+        },
+        DataRow::Map(values) => RowContext::RefMapData(values),
+    };
+
+    evaluate_stateless(Some(context), &index.expr)
+        .await?
+        .try_into()
+}
+
 #[async_trait(?Send)]
 pub trait Index {
     async fn scan_indexed_data(
@@ -2,19 +2,23 @@ mod data_row;
 mod function;
 mod index;
 mod metadata;
+mod multi_chain;
+mod predicate;
 mod transaction;
 
-pub trait GStore: Store + Metadata {}
-impl<S: Store + Metadata> GStore for S {}
+pub trait GStore: Store + Index + Metadata + CustomFunction {}
+impl<S: Store + Index + Metadata + CustomFunction> GStore for S {}
 
-pub trait GStoreMut: IndexMut + Transaction + CustomFunction + CustomFunctionMut {}
-impl<S: IndexMut + Transaction + CustomFunction + CustomFunctionMut> GStoreMut for S {}
+pub trait GStoreMut: StoreMut + IndexMut + Transaction + CustomFunctionMut {}
+impl<S: StoreMut + IndexMut + Transaction + CustomFunctionMut> GStoreMut for S {}
 
 pub use {
     data_row::DataRow,
     function::{CustomFunction, CustomFunctionMut},
-    index::{Index, IndexError, IndexMut},
+    index::{index_value, Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
+    multi_chain::MultiChainStorage,
+    predicate::Predicate,
     transaction::Transaction,
 };
 
@@ -40,6 +44,33 @@ pub trait Store {
     async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>>;
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>>;
+
+    /// Scans `table_name` with the `predicates` of the `WHERE` clause pushed down, so that
+    /// remote stores only fetch the matching range.
+    ///
+    /// Along with the rows, returns for each predicate whether every returned row is
+    /// guaranteed to satisfy it. The executor re-checks only the predicates reported `false`.
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let rows = self.scan_data(table_name).await?;
+
+        Ok((rows, vec![false; predicates.len()]))
+    }
+
+    /// Scans like [`Store::scan_data_with_predicates`], the store being free to read only
+    /// `columns` of the rows. Values of the other columns may be left `NULL`, or missing from
+    /// map rows.
+    async fn scan_data_with_projection(
+        &self,
+        table_name: &str,
+        _columns: &[String],
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        self.scan_data_with_predicates(table_name, predicates).await
+    }
 }
 
 /// By implementing `StoreMut` trait,
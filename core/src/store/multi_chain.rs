@@ -0,0 +1,449 @@
+use {
+    super::{
+        CustomFunction, CustomFunctionMut, DataRow, GStore, Index, IndexMut, MetaIter, Metadata,
+        Predicate, RowIter, Store, StoreMut, Transaction,
+    },
+    crate::{
+        ast::{IndexOperator, OrderByExpr},
+        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+    std::collections::HashMap,
+};
+
+/// Storage composed of a local store and one read-only store per chain.
+///
+/// Tables named `<chain>.<entity>`, where `<chain>` is a registered short code as listed by
+/// `SHOW CHAINS`, are served by the store of that chain under the bare entity name. Any other
+/// table is served by the local store, so a single query can join `eth.blocks` with
+/// `base.blocks` or with a local table.
+pub struct MultiChainStorage<T> {
+    pub local: T,
+    chains: HashMap<String, Box<dyn GStore>>,
+}
+
+impl<T> MultiChainStorage<T> {
+    pub fn new(local: T) -> Self {
+        Self {
+            local,
+            chains: HashMap::new(),
+        }
+    }
+
+    /// Serves the tables prefixed with the `short_code` of a chain from `storage`.
+    pub fn with_chain<S: GStore + 'static>(mut self, short_code: &str, storage: S) -> Self {
+        self.chains
+            .insert(short_code.to_lowercase(), Box::new(storage));
+
+        self
+    }
+
+    /// Short codes of the registered chains.
+    pub fn chains(&self) -> impl Iterator<Item = &str> {
+        self.chains.keys().map(String::as_str)
+    }
+
+    /// Store of the chain `table_name` is prefixed with, along with the entity name.
+    fn chain<'a>(&self, table_name: &'a str) -> Option<(&str, &dyn GStore, &'a str)> {
+        let (chain, name) = table_name.split_once('.')?;
+
+        self.chains
+            .get_key_value(&chain.to_lowercase())
+            .map(|(chain, storage)| (chain.as_str(), storage.as_ref(), name))
+    }
+
+    fn read_only(&self, table_name: &str) -> Result<()> {
+        match self.chain(table_name) {
+            Some((chain, ..)) => Err(Error::StorageMsg(format!(
+                "[MultiChainStorage] tables of the chain {chain} are read-only"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Store> Store for MultiChainStorage<T> {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let Some((chain, storage, name)) = self.chain(table_name) else {
+            return self.local.fetch_schema(table_name).await;
+        };
+
+        let schema = storage.fetch_schema(name).await?.map(|schema| Schema {
+            chain_name: Some(chain.to_owned()),
+            ..schema
+        });
+
+        Ok(schema)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut schemas = self.local.fetch_all_schemas().await?;
+        for (chain, storage) in self.chains.iter() {
+            let chain_schemas = storage.fetch_all_schemas().await?;
+
+            schemas.extend(chain_schemas.into_iter().map(|schema| Schema {
+                chain_name: Some(chain.clone()),
+                ..schema
+            }));
+        }
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        match self.chain(table_name) {
+            Some((_, storage, name)) => storage.fetch_data(name, key).await,
+            None => self.local.fetch_data(table_name, key).await,
+        }
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        match self.chain(table_name) {
+            Some((_, storage, name)) => storage.scan_data(name).await,
+            None => self.local.scan_data(table_name).await,
+        }
+    }
+
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        match self.chain(table_name) {
+            Some((_, storage, name)) => storage.scan_data_with_predicates(name, predicates).await,
+            None => {
+                self.local
+                    .scan_data_with_predicates(table_name, predicates)
+                    .await
+            }
+        }
+    }
+
+    async fn scan_data_with_projection(
+        &self,
+        table_name: &str,
+        columns: &[String],
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        match self.chain(table_name) {
+            Some((_, storage, name)) => {
+                storage
+                    .scan_data_with_projection(name, columns, predicates)
+                    .await
+            }
+            None => {
+                self.local
+                    .scan_data_with_projection(table_name, columns, predicates)
+                    .await
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Metadata> Metadata for MultiChainStorage<T> {
+    async fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.local.scan_table_meta().await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Index> Index for MultiChainStorage<T> {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        self.read_only(table_name)?;
+
+        self.local
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: IndexMut> IndexMut for MultiChainStorage<T> {
+    async fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local
+            .create_index(table_name, index_name, column)
+            .await
+    }
+
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local.drop_index(table_name, index_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: StoreMut> StoreMut for MultiChainStorage<T> {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.read_only(&schema.table_name)?;
+
+        self.local.insert_schema(schema).await
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local.delete_schema(table_name).await
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local.append_data(table_name, rows).await
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local.insert_data(table_name, rows).await
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        self.read_only(table_name)?;
+
+        self.local.delete_data(table_name, keys).await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Transaction> Transaction for MultiChainStorage<T> {
+    async fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        self.local.begin(autocommit).await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        self.local.rollback().await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        self.local.commit().await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: CustomFunction> CustomFunction for MultiChainStorage<T> {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        self.local.fetch_function(func_name).await
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.local.fetch_all_functions().await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: CustomFunctionMut> CustomFunctionMut for MultiChainStorage<T> {
+    async fn insert_function(&mut self, func: StructCustomFunction) -> Result<()> {
+        self.local.insert_function(func).await
+    }
+
+    async fn delete_function(&mut self, func_name: &str) -> Result<()> {
+        self.local.delete_function(func_name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::MultiChainStorage,
+        crate::{
+            ast::{ColumnDef, DataType},
+            data::{Key, Schema, Value},
+            executor::{Payload, PayloadVariable},
+            result::{Error, Result},
+            store::{
+                CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, RowIter,
+                Store, StoreMut, Transaction,
+            },
+            worm::Worm,
+        },
+        async_trait::async_trait,
+        futures::{executor::block_on, stream},
+    };
+
+    /// Serves a `blocks` table with the block numbers up to the wrapped one.
+    struct Blocks(u64);
+
+    #[async_trait(?Send)]
+    impl Store for Blocks {
+        async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+            let schema = (table_name == "blocks").then(|| Schema {
+                table_name: "blocks".to_owned(),
+                column_defs: Some(vec![ColumnDef {
+                    name: "number".to_owned(),
+                    data_type: DataType::Uint64,
+                    nullable: false,
+                    default: None,
+                    comment: None,
+                }]),
+                indexes: Vec::new(),
+                comment: None,
+                chain_name: None,
+                view: None,
+            });
+
+            Ok(schema)
+        }
+
+        async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+            self.fetch_schema("blocks")
+                .await
+                .map(|schema| schema.into_iter().collect())
+        }
+
+        async fn fetch_data(&self, _table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+            let row = match key {
+                Key::U64(number) if (1..=self.0).contains(number) => {
+                    Some(DataRow::Vec(vec![Value::U64(*number)]))
+                }
+                _ => None,
+            };
+
+            Ok(row)
+        }
+
+        async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+            if table_name != "blocks" {
+                return Err(Error::StorageMsg(format!("unknown table {table_name}")));
+            }
+
+            let rows = (1..=self.0)
+                .map(|number| Ok((Key::U64(number), DataRow::Vec(vec![Value::U64(number)]))));
+
+            Ok(Box::pin(stream::iter(rows)))
+        }
+    }
+
+    impl StoreMut for Blocks {}
+    impl Metadata for Blocks {}
+    impl Index for Blocks {}
+    impl IndexMut for Blocks {}
+    impl Transaction for Blocks {}
+    impl CustomFunction for Blocks {}
+    impl CustomFunctionMut for Blocks {}
+
+    fn worm() -> Worm<MultiChainStorage<Blocks>> {
+        let storage = MultiChainStorage::new(Blocks(2))
+            .with_chain("eth", Blocks(3))
+            .with_chain("BASE", Blocks(5));
+
+        Worm::new(storage)
+    }
+
+    fn numbers(payload: &Payload) -> Vec<Vec<Value>> {
+        match payload {
+            Payload::Select { rows, .. } => rows.clone(),
+            payload => panic!("unexpected payload {payload:?}"),
+        }
+    }
+
+    #[test]
+    fn route() {
+        let storage = worm().storage;
+
+        let schema = block_on(storage.fetch_schema("eth.blocks"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(schema.table_name, "blocks");
+        assert_eq!(schema.chain_name.as_deref(), Some("eth"));
+        assert_eq!(
+            block_on(storage.fetch_schema("blocks"))
+                .unwrap()
+                .unwrap()
+                .chain_name,
+            None
+        );
+        assert_eq!(block_on(storage.fetch_schema("sui.blocks")).unwrap(), None);
+
+        assert_eq!(
+            block_on(storage.fetch_data("Base.blocks", &Key::U64(5))).unwrap(),
+            Some(DataRow::Vec(vec![Value::U64(5)]))
+        );
+        assert_eq!(
+            block_on(storage.fetch_data("blocks", &Key::U64(5))).unwrap(),
+            None
+        );
+
+        let chains = block_on(storage.fetch_all_schemas())
+            .unwrap()
+            .into_iter()
+            .map(|schema| schema.chain_name)
+            .filter(Option::is_some)
+            .count();
+        assert_eq!(chains, 2);
+    }
+
+    #[test]
+    fn select() {
+        let mut worm = worm();
+        let select = |worm: &mut Worm<_>, sql| numbers(&block_on(worm.execute(sql)).unwrap()[0]);
+
+        assert_eq!(
+            select(&mut worm, "SELECT number FROM base.blocks WHERE number > 3"),
+            vec![vec![Value::U64(4)], vec![Value::U64(5)]]
+        );
+        assert_eq!(
+            select(&mut worm, "SELECT number FROM blocks"),
+            vec![vec![Value::U64(1)], vec![Value::U64(2)]]
+        );
+        assert_eq!(
+            select(
+                &mut worm,
+                "SELECT e.number, b.number FROM eth.blocks AS e
+                 JOIN base.blocks AS b ON e.number + 2 = b.number"
+            ),
+            vec![
+                vec![Value::U64(1), Value::U64(3)],
+                vec![Value::U64(2), Value::U64(4)],
+                vec![Value::U64(3), Value::U64(5)],
+            ]
+        );
+        assert_eq!(
+            select(
+                &mut worm,
+                "SELECT COUNT(*) FROM eth.blocks AS e JOIN blocks AS l ON e.number = l.number"
+            ),
+            vec![vec![Value::I64(2)]]
+        );
+    }
+
+    #[test]
+    fn show_tables_and_columns() {
+        let mut worm = worm();
+
+        let Payload::ShowVariable(PayloadVariable::Tables(mut tables)) =
+            block_on(worm.execute("SHOW TABLES")).unwrap().remove(0)
+        else {
+            panic!("expected SHOW TABLES payload");
+        };
+        tables.sort();
+        assert_eq!(tables, vec!["base.blocks", "blocks", "eth.blocks"]);
+
+        assert_eq!(
+            block_on(worm.execute("DESCRIBE eth.blocks")),
+            Ok(vec![Payload::ShowColumns(vec![ColumnDef {
+                name: "number".to_owned(),
+                data_type: DataType::Uint64,
+                nullable: false,
+                default: None,
+                comment: None,
+            }])])
+        );
+    }
+}
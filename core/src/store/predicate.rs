@@ -0,0 +1,75 @@
+use {
+    crate::{ast::IndexOperator, data::Value},
+    serde::Serialize,
+    std::cmp::Ordering,
+};
+
+/// Comparison between a column and a constant, taken from a conjunct of the `WHERE` clause
+/// and handed to [`Store::scan_data_with_predicates`](super::Store::scan_data_with_predicates).
+///
+/// `BETWEEN` is split into a `GtEq` and a `LtEq` predicate.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Predicate {
+    pub column: String,
+    pub op: IndexOperator,
+    pub value: Value,
+}
+
+impl Predicate {
+    /// Returns whether `value` of the column satisfies the predicate,
+    /// `None` when the two values are not comparable.
+    pub fn check(&self, value: &Value) -> Option<bool> {
+        let ordering = value.evaluate_cmp(&self.value)?;
+
+        Some(match self.op {
+            IndexOperator::Gt => ordering == Ordering::Greater,
+            IndexOperator::Lt => ordering == Ordering::Less,
+            IndexOperator::GtEq => ordering != Ordering::Less,
+            IndexOperator::LtEq => ordering != Ordering::Greater,
+            IndexOperator::Eq => ordering == Ordering::Equal,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Predicate,
+        crate::{ast::IndexOperator, data::Value},
+    };
+
+    #[test]
+    fn check() {
+        let predicate = |op, value| Predicate {
+            column: "number".to_owned(),
+            op,
+            value,
+        };
+
+        assert_eq!(
+            predicate(IndexOperator::Gt, Value::I64(10)).check(&Value::U64(11)),
+            Some(true)
+        );
+        assert_eq!(
+            predicate(IndexOperator::GtEq, Value::I64(10)).check(&Value::U64(10)),
+            Some(true)
+        );
+        assert_eq!(
+            predicate(IndexOperator::Lt, Value::I64(10)).check(&Value::U64(10)),
+            Some(false)
+        );
+        assert_eq!(
+            predicate(IndexOperator::LtEq, Value::I64(10)).check(&Value::I8(3)),
+            Some(true)
+        );
+        assert_eq!(
+            predicate(IndexOperator::Eq, Value::Str("a".to_owned()))
+                .check(&Value::Str("b".to_owned())),
+            Some(false)
+        );
+        assert_eq!(
+            predicate(IndexOperator::Eq, Value::Str("a".to_owned())).check(&Value::I64(1)),
+            None
+        );
+    }
+}
@@ -5,8 +5,8 @@ pub enum TranslateError {
     #[error("unimplemented - select on two or more than tables are not supported")]
     TooManyTables,
 
-    #[error("unimplemented - select distinct is not supported")]
-    SelectDistinctNotSupported,
+    #[error("unimplemented - distinct is not supported in function: {0}")]
+    DistinctNotSupportedInFunction(String),
 
     #[error("unimplemented - composite index is not supported")]
     CompositeIndexNotSupported,
@@ -132,6 +132,9 @@ pub enum TranslateError {
     #[error("unsupported query set expr: {0}")]
     UnsupportedQuerySetExpr(String),
 
+    #[error("unsupported ORDER BY, LIMIT or OFFSET in set operation operand: {0}")]
+    UnsupportedSetOperandOrderByOrLimit(String),
+
     #[error("unsupported query table factor: {0}")]
     UnsupportedQueryTableFactor(String),
 
@@ -174,6 +177,9 @@ pub enum TranslateError {
     #[error("cannot drop primary index")]
     CannotDropPrimary,
 
+    #[error("unimplemented - view column list is not supported: {0}")]
+    ViewColumnsNotSupported(String),
+
     #[error("unreachable - empty columns")]
     UnreachableForeignKeyColumns(String),
 
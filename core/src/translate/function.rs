@@ -5,14 +5,15 @@ use {
         translate_data_type, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, Expr, Function},
         result::Result,
     },
     sqlparser::ast::{
         CastFormat as SqlCastFormat, CastKind as SqlCastKind, DataType as SqlDataType,
-        DateTimeField as SqlDateTimeField, Expr as SqlExpr, Function as SqlFunction,
-        FunctionArg as SqlFunctionArg, FunctionArgExpr as SqlFunctionArgExpr,
-        FunctionArguments as SqlFunctionArguments, TrimWhereField as SqlTrimWhereField,
+        DateTimeField as SqlDateTimeField, DuplicateTreatment as SqlDuplicateTreatment,
+        Expr as SqlExpr, Function as SqlFunction, FunctionArg as SqlFunctionArg,
+        FunctionArgExpr as SqlFunctionArgExpr, FunctionArguments as SqlFunctionArguments,
+        TrimWhereField as SqlTrimWhereField,
     },
 };
 
@@ -145,15 +146,17 @@ fn translate_function_one_arg<T: FnOnce(Expr) -> Function>(
         .map(Expr::Function)
 }
 
-fn translate_aggregate_one_arg<T: FnOnce(Expr) -> Aggregate>(
+fn translate_aggregate_one_arg<T: FnOnce(Expr) -> AggregateFunction>(
     func: T,
     args: Vec<&SqlExpr>,
     name: String,
+    distinct: bool,
 ) -> Result<Expr> {
     check_len(name, args.len(), 1)?;
 
     translate_expr(args[0])
         .map(func)
+        .map(|func| Aggregate { func, distinct })
         .map(Box::new)
         .map(Expr::Aggregate)
 }
@@ -194,12 +197,18 @@ pub fn translate_function_arg_exprs(
 pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
     let SqlFunction { name, args, .. } = sql_function;
     let name = translate_object_name(name)?.to_uppercase();
-    let args = match args {
-        SqlFunctionArguments::None => Vec::new(),
+    let (args, distinct) = match args {
+        SqlFunctionArguments::None => (Vec::new(), false),
         SqlFunctionArguments::Subquery(_) => {
             return Err(TranslateError::UnreachableSubqueryFunctionArgNotSupported.into())
         }
-        SqlFunctionArguments::List(list) => list.args.iter().collect(),
+        SqlFunctionArguments::List(list) => (
+            list.args.iter().collect(),
+            matches!(
+                list.duplicate_treatment,
+                Some(SqlDuplicateTreatment::Distinct)
+            ),
+        ),
     };
 
     let function_arg_exprs = args
@@ -213,7 +222,7 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         .collect::<Result<Vec<_>>>()?;
 
     if name.as_str() == "COUNT" {
-        check_len(name, args.len(), 1)?;
+        check_len(name.clone(), args.len(), 1)?;
 
         let count_arg = match function_arg_exprs[0] {
             SqlFunctionArgExpr::Expr(expr) => CountArgExpr::Expr(translate_expr(expr)?),
@@ -226,18 +235,28 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             SqlFunctionArgExpr::Wildcard => CountArgExpr::Wildcard,
         };
 
-        return Ok(Expr::Aggregate(Box::new(Aggregate::Count(count_arg))));
+        if distinct && count_arg == CountArgExpr::Wildcard {
+            return Err(TranslateError::DistinctNotSupportedInFunction(name).into());
+        }
+
+        return Ok(Expr::Aggregate(Box::new(Aggregate {
+            func: AggregateFunction::Count(count_arg),
+            distinct,
+        })));
     }
 
     let args = translate_function_arg_exprs(function_arg_exprs)?;
 
     match name.as_str() {
-        "SUM" => translate_aggregate_one_arg(Aggregate::Sum, args, name),
-        "MIN" => translate_aggregate_one_arg(Aggregate::Min, args, name),
-        "MAX" => translate_aggregate_one_arg(Aggregate::Max, args, name),
-        "AVG" => translate_aggregate_one_arg(Aggregate::Avg, args, name),
-        "VARIANCE" => translate_aggregate_one_arg(Aggregate::Variance, args, name),
-        "STDEV" => translate_aggregate_one_arg(Aggregate::Stdev, args, name),
+        "SUM" => translate_aggregate_one_arg(AggregateFunction::Sum, args, name, distinct),
+        "MIN" => translate_aggregate_one_arg(AggregateFunction::Min, args, name, distinct),
+        "MAX" => translate_aggregate_one_arg(AggregateFunction::Max, args, name, distinct),
+        "AVG" => translate_aggregate_one_arg(AggregateFunction::Avg, args, name, distinct),
+        "VARIANCE" => {
+            translate_aggregate_one_arg(AggregateFunction::Variance, args, name, distinct)
+        }
+        "STDEV" => translate_aggregate_one_arg(AggregateFunction::Stdev, args, name, distinct),
+        _ if distinct => Err(TranslateError::DistinctNotSupportedInFunction(name).into()),
         "COALESCE" => {
             let exprs = args
                 .into_iter()
@@ -687,7 +706,14 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let list = translate_expr(args[0])?;
             Ok(Expr::Function(Box::new(Function::Dedup(list))))
         }
-        _ => Err(TranslateError::UnsupportedFunction(name).into()),
+        _ => {
+            let exprs = args
+                .into_iter()
+                .map(translate_expr)
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Expr::Function(Box::new(Function::Custom { name, exprs })))
+        }
     }
 }
 
@@ -724,4 +750,33 @@ mod tests {
         let expected = Err(TranslateError::SafeCastNotSupported.into());
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn distinct() {
+        let expr = |sql| parse_expr(sql).and_then(|parsed| translate_expr(&parsed));
+
+        let actual = expr("COUNT(DISTINCT id)");
+        let expected = Ok(Expr::Aggregate(Box::new(Aggregate {
+            func: AggregateFunction::Count(CountArgExpr::Expr(Expr::Identifier("id".to_owned()))),
+            distinct: true,
+        })));
+        assert_eq!(actual, expected);
+
+        let actual = expr("SUM(DISTINCT amount)");
+        let expected = Ok(Expr::Aggregate(Box::new(Aggregate {
+            func: AggregateFunction::Sum(Expr::Identifier("amount".to_owned())),
+            distinct: true,
+        })));
+        assert_eq!(actual, expected);
+
+        let actual = expr("COUNT(DISTINCT *)");
+        let expected =
+            Err(TranslateError::DistinctNotSupportedInFunction("COUNT".to_owned()).into());
+        assert_eq!(actual, expected);
+
+        let actual = expr("UPPER(DISTINCT name)");
+        let expected =
+            Err(TranslateError::DistinctNotSupportedInFunction("UPPER".to_owned()).into());
+        assert_eq!(actual, expected);
+    }
 }
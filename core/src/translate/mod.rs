@@ -20,12 +20,19 @@ pub use self::{
 use {
     crate::{
         ast::{Assignment, ReferentialAction, Statement, Variable},
+        parse_sql::ParsedStatement,
         result::Result,
     },
     sqlparser::ast::{
         Assignment as SqlAssignment, AssignmentTarget as SqlAssignmentTarget,
-        Ident as SqlIdent, ObjectName as SqlObjectName,
-        ReferentialAction as SqlReferentialAction, Statement as SqlStatement,TableFactor, TableWithJoins,
+        CommentDef as SqlCommentDef, CreateFunctionBody as SqlCreateFunctionBody,
+        CreateIndex as SqlCreateIndex,
+        CreateTable as SqlCreateTable, Delete as SqlDelete,
+        FromTable as SqlFromTable, FunctionDesc as SqlFunctionDesc, Ident as SqlIdent,
+        Insert as SqlInsert,
+        ObjectName as SqlObjectName, ObjectType as SqlObjectType,
+        ReferentialAction as SqlReferentialAction, Statement as SqlStatement, TableFactor,
+        TableWithJoins,
     },
 };
 
@@ -37,11 +44,247 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             db_name: None,
             ..
         } => Ok(Statement::Show(Show::Variable(Variable::Tables))),
+        SqlStatement::ShowFunctions { filter: None } => {
+            Ok(Statement::Show(Show::Variable(Variable::Functions)))
+        }
         SqlStatement::ShowVariable { variable } => translate_show_variable(variable, sql_statement),
+        SqlStatement::ShowColumns {
+            table_name,
+            filter: None,
+            ..
+        }
+        | SqlStatement::ExplainTable { table_name, .. } => {
+            Ok(Statement::Show(Show::Columns {
+                table_name: translate_idents(&table_name.0).join("."),
+            }))
+        }
+        SqlStatement::Insert(SqlInsert {
+            table_name,
+            columns,
+            source,
+            ..
+        }) => {
+            let table_name = translate_idents(&table_name.0).join(".");
+            let source = source.as_deref().ok_or_else(|| {
+                TranslateError::DefaultValuesOnInsertNotSupported(table_name.clone())
+            })?;
+
+            Ok(Statement::Insert {
+                table_name,
+                columns: translate_idents(columns),
+                source: translate_query(source)?,
+            })
+        }
+        SqlStatement::Update {
+            table,
+            assignments,
+            selection,
+            ..
+        } => Ok(Statement::Update {
+            table_name: translate_table_with_join(table)?,
+            assignments: assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+            selection: selection.as_ref().map(translate_expr).transpose()?,
+        }),
+        SqlStatement::Delete(SqlDelete {
+            from, selection, ..
+        }) => {
+            let from = match from {
+                SqlFromTable::WithFromKeyword(from) | SqlFromTable::WithoutKeyword(from) => from,
+            };
+            let table = from
+                .first()
+                .ok_or(TranslateError::UnreachableOmittingFromInDelete)?;
+
+            Ok(Statement::Delete {
+                table_name: translate_table_with_join(table)?,
+                selection: selection.as_ref().map(translate_expr).transpose()?,
+            })
+        }
+        SqlStatement::CreateTable(SqlCreateTable {
+            if_not_exists,
+            name,
+            columns,
+            query,
+            comment,
+            ..
+        }) => {
+            let columns = columns
+                .iter()
+                .map(translate_column_def)
+                .collect::<Result<Vec<_>>>()?;
+            let source = query
+                .as_deref()
+                .map(translate_query)
+                .transpose()?
+                .map(Box::new);
+            let comment = comment.as_ref().map(|comment| match comment {
+                SqlCommentDef::WithEq(comment) | SqlCommentDef::WithoutEq(comment) => {
+                    comment.clone()
+                }
+            });
+
+            Ok(Statement::CreateTable {
+                if_not_exists: *if_not_exists,
+                name: translate_idents(&name.0).join("."),
+                columns: (!columns.is_empty()).then_some(columns),
+                source,
+                comment,
+            })
+        }
+        SqlStatement::Drop {
+            object_type: SqlObjectType::Table,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropTable {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(|name| translate_idents(&name.0).join("."))
+                .collect(),
+        }),
+        SqlStatement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            ..
+        } => {
+            if !columns.is_empty() {
+                return Err(TranslateError::ViewColumnsNotSupported(name.to_string()).into());
+            }
+
+            Ok(Statement::CreateView {
+                or_replace: *or_replace,
+                materialized: *materialized,
+                name: translate_idents(&name.0).join("."),
+                query: Box::new(translate_query(query)?),
+            })
+        }
+        SqlStatement::Drop {
+            object_type: SqlObjectType::View,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropView {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(|name| translate_idents(&name.0).join("."))
+                .collect(),
+        }),
+        SqlStatement::Explain {
+            analyze, statement, ..
+        } => match statement.as_ref() {
+            SqlStatement::Query(query) => Ok(Statement::Explain {
+                analyze: *analyze,
+                query: Box::new(translate_query(query)?),
+            }),
+            _ => Err(TranslateError::UnsupportedStatement(sql_statement.to_string()).into()),
+        },
+        SqlStatement::CreateFunction {
+            or_replace,
+            name,
+            args,
+            function_body: Some(SqlCreateFunctionBody::Return(return_)),
+            ..
+        } => {
+            let args = args
+                .iter()
+                .flatten()
+                .map(translate_operate_function_arg)
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Statement::CreateFunction {
+                or_replace: *or_replace,
+                name: translate_object_name(name)?,
+                args,
+                return_: translate_expr(return_)?,
+            })
+        }
+        SqlStatement::DropFunction {
+            if_exists,
+            func_desc,
+            ..
+        } => Ok(Statement::DropFunction {
+            if_exists: *if_exists,
+            names: func_desc
+                .iter()
+                .map(|SqlFunctionDesc { name, .. }| translate_object_name(name))
+                .collect::<Result<_>>()?,
+        }),
+        SqlStatement::StartTransaction { .. } => Ok(Statement::StartTransaction),
+        SqlStatement::Commit { .. } => Ok(Statement::Commit),
+        SqlStatement::Rollback {
+            savepoint: None, ..
+        } => Ok(Statement::Rollback),
+        SqlStatement::CreateIndex(SqlCreateIndex {
+            name,
+            table_name,
+            columns,
+            ..
+        }) => {
+            let [column] = columns.as_slice() else {
+                return Err(TranslateError::CompositeIndexNotSupported.into());
+            };
+            let name = name
+                .as_ref()
+                .ok_or(TranslateError::UnsupportedUnnamedIndex)
+                .map(translate_object_name)??;
+
+            if name.eq_ignore_ascii_case("PRIMARY") {
+                return Err(TranslateError::ReservedIndexName(name).into());
+            }
+
+            Ok(Statement::CreateIndex {
+                name,
+                table_name: translate_idents(&table_name.0).join("."),
+                column: translate_order_by_expr(column)?,
+            })
+        }
+        SqlStatement::Drop {
+            object_type: SqlObjectType::Index,
+            names,
+            ..
+        } => {
+            let [object_name] = names.as_slice() else {
+                return Err(TranslateError::TooManyParamsInDropIndex.into());
+            };
+            let Some((name, table_name)) = object_name.0.split_last() else {
+                return Err(TranslateError::InvalidParamsInDropIndex.into());
+            };
+
+            if table_name.is_empty() {
+                return Err(TranslateError::InvalidParamsInDropIndex.into());
+            }
+
+            if name.value.eq_ignore_ascii_case("PRIMARY") {
+                return Err(TranslateError::ReservedIndexName(name.value.to_owned()).into());
+            }
+
+            Ok(Statement::DropIndex {
+                name: name.value.to_owned(),
+                table_name: translate_idents(table_name).join("."),
+            })
+        }
         _ => Err(TranslateError::UnsupportedStatement(sql_statement.to_string()).into()),
     }
 }
 
+/// Translates a statement parsed by [`parse_statements`](crate::parse_sql::parse_statements).
+pub fn translate_parsed(parsed: &ParsedStatement) -> Result<Statement> {
+    match parsed {
+        ParsedStatement::Sql(sql_statement) => translate(sql_statement),
+        ParsedStatement::RefreshView(name) => Ok(Statement::RefreshView {
+            name: translate_idents(&name.0).join("."),
+        }),
+    }
+}
+
 fn translate_show_variable(
     variable: &[SqlIdent],
     sql_statement: &SqlStatement,
@@ -51,6 +294,7 @@ fn translate_show_variable(
             "VERSION" => Ok(Statement::Show(Show::Variable(Variable::Version))),
             "CHAINS" => Ok(Statement::Show(Show::Variable(Variable::Chains))),
             "TABLES" => Ok(Statement::Show(Show::Variable(Variable::Tables))),
+            "FUNCTIONS" => Ok(Statement::Show(Show::Variable(Variable::Functions))),
             v => Err(TranslateError::UnsupportedShowVariableKeyword(v.to_owned()).into()),
         },
         (4, Some(keyword)) if keyword.value.eq_ignore_ascii_case("CHAIN") => {
@@ -134,7 +378,7 @@ fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
         return Err(TranslateError::JoinOnUpdateNotSupported.into());
     }
     match &table.relation {
-        TableFactor::Table { name, .. } => translate_object_name(name),
+        TableFactor::Table { name, .. } => Ok(translate_idents(&name.0).join(".")),
         t => Err(TranslateError::UnsupportedTableFactor(t.to_string()).into()),
     }
 }
@@ -159,10 +403,13 @@ fn translate_chain_and_table(sql_object_name: &SqlObjectName) -> Result<(Option<
         return Err(TranslateError::CompoundObjectNotSupported(compound_object_name).into());
     }
 
-    match (sql_object_name.first(), sql_object_name.get(1)) {
-        (Some(chain), Some(table)) => Ok((Some(chain.value.to_owned()), table.value.to_owned())),
-        (Some(table), None) => Ok((None, table.value.to_owned())),
-        _ => Err(TranslateError::UnreachableEmptyObject.into()),
+    match sql_object_name.split_first() {
+        Some((table, [])) => Ok((None, table.value.to_owned())),
+        Some((chain, entity)) => Ok((
+            Some(chain.value.to_owned()),
+            translate_idents(entity).join("."),
+        )),
+        None => Err(TranslateError::UnreachableEmptyObject.into()),
     }
 }
 
@@ -199,4 +446,126 @@ mod tests {
             Ok(Statement::Show(Show::Variable(Variable::Tables)))
         ));
     }
+
+    #[test]
+    fn test_translate_refresh_view() {
+        use crate::parse_sql::parse_statements;
+
+        let translate = |sql: &str| {
+            parse_statements(sql)?
+                .iter()
+                .map(translate_parsed)
+                .collect::<Result<Vec<_>>>()
+        };
+
+        assert_eq!(
+            translate("REFRESH MATERIALIZED VIEW Snapshot; SHOW TABLES;"),
+            Ok(vec![
+                Statement::RefreshView {
+                    name: "Snapshot".to_owned(),
+                },
+                Statement::Show(Show::Variable(Variable::Tables)),
+            ])
+        );
+        assert!(translate("REFRESH VIEW Snapshot").is_err());
+        assert!(translate("REFRESH MATERIALIZED VIEW Snapshot SHOW TABLES").is_err());
+    }
+
+    #[test]
+    fn test_translate_show_columns() {
+        let dialect = GenericDialect {};
+        let show = |sql: &str| {
+            let ast = Parser::parse_sql(&dialect, sql).unwrap();
+
+            translate(&ast[0])
+        };
+        let columns = |table_name: &str| {
+            Ok(Statement::Show(Show::Columns {
+                table_name: table_name.to_owned(),
+            }))
+        };
+
+        assert_eq!(show("DESCRIBE Foo"), columns("Foo"));
+        assert_eq!(
+            show("SHOW COLUMNS FROM base.blocks"),
+            columns("base.blocks")
+        );
+    }
+
+    #[test]
+    fn test_translate_chain_and_table() {
+        use crate::ast::{SetExpr, TableFactor};
+
+        let dialect = GenericDialect {};
+        let table_factor = |sql: &str| {
+            let ast = Parser::parse_sql(&dialect, sql).unwrap();
+            match translate(&ast[0]) {
+                Ok(Statement::Query(query)) => match query.body {
+                    SetExpr::Select(select) => select.from.relation,
+                    _ => panic!("select expected"),
+                },
+                other => panic!("query expected: {other:?}"),
+            }
+        };
+
+        assert!(matches!(
+            table_factor("SELECT * FROM eth.events.Transfer"),
+            TableFactor::Table { chain_name: Some(chain), name, .. }
+                if chain == "eth" && name == "events.Transfer"
+        ));
+        assert!(matches!(
+            table_factor("SELECT * FROM eth.blocks"),
+            TableFactor::Table { chain_name: Some(chain), name, .. }
+                if chain == "eth" && name == "blocks"
+        ));
+        assert!(matches!(
+            table_factor("SELECT * FROM Item"),
+            TableFactor::Table { chain_name: None, name, .. } if name == "Item"
+        ));
+    }
+
+    #[test]
+    fn test_translate_set_operation() {
+        use crate::ast::{Query, SetExpr, SetOperator, ToSql};
+
+        let dialect = GenericDialect {};
+        let query = |sql: &str| {
+            let ast = Parser::parse_sql(&dialect, sql).unwrap();
+
+            translate(&ast[0])
+        };
+
+        assert!(matches!(
+            query("SELECT id FROM eth.Transfer UNION ALL SELECT id FROM sui.Transfer"),
+            Ok(Statement::Query(Query {
+                body: SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all: true,
+                    ..
+                },
+                ..
+            }))
+        ));
+
+        let sql = r#"(VALUES (1) UNION VALUES (2)) INTERSECT VALUES (2) ORDER BY "column1""#;
+        assert_eq!(
+            query(sql).map(|statement| statement.to_sql()),
+            Ok(format!("{sql};"))
+        );
+
+        assert_eq!(
+            query("(SELECT id FROM Foo ORDER BY id) EXCEPT SELECT id FROM Bar"),
+            Err(TranslateError::UnsupportedSetOperandOrderByOrLimit(
+                "(SELECT id FROM Foo ORDER BY id)".to_owned()
+            )
+            .into())
+        );
+        assert_eq!(
+            query("SELECT id FROM Foo UNION (SELECT id FROM Bar LIMIT 1)"),
+            Err(TranslateError::UnsupportedSetOperandOrderByOrLimit(
+                "(SELECT id FROM Bar LIMIT 1)".to_owned()
+            )
+            .into())
+        );
+    }
 }
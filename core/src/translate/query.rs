@@ -5,17 +5,20 @@ use {
     },
     crate::{
         ast::{
-            AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            AstLiteral, Dictionary, Distinct, Expr, Join, JoinConstraint, JoinExecutor,
+            JoinOperator, Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+            TableWithJoins, Values,
         },
         result::Result,
     },
     sqlparser::ast::{
-        Expr as SqlExpr, FunctionArg as SqlFunctionArg, GroupByExpr as SqlGroupByExpr,
-        Join as SqlJoin, JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator,
-        Query as SqlQuery, Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
-        TableAlias as SqlTableAlias, TableFactor as SqlTableFactor,
-        TableFunctionArgs as SqlTableFunctionArgs, TableWithJoins as SqlTableWithJoins,
+        Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
+        GroupByExpr as SqlGroupByExpr, Join as SqlJoin, JoinConstraint as SqlJoinConstraint,
+        JoinOperator as SqlJoinOperator, Query as SqlQuery, Select as SqlSelect,
+        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, SetOperator as SqlSetOperator,
+        SetQuantifier as SqlSetQuantifier, TableAlias as SqlTableAlias,
+        TableFactor as SqlTableFactor, TableFunctionArgs as SqlTableFunctionArgs,
+        TableWithJoins as SqlTableWithJoins,
     },
 };
 
@@ -56,6 +59,48 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Union => SetOperator::Union,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+                SqlSetOperator::Except => SetOperator::Except,
+            };
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                SqlSetQuantifier::ByName
+                | SqlSetQuantifier::AllByName
+                | SqlSetQuantifier::DistinctByName => {
+                    return Err(
+                        TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                    );
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(translate_set_expr(left)?),
+                right: Box::new(translate_set_expr(right)?),
+            })
+        }
+        SqlSetExpr::Query(query) => match translate_query(query)? {
+            Query {
+                body,
+                order_by,
+                limit: None,
+                offset: None,
+            } if order_by.is_empty() => Ok(body),
+            _ => Err(
+                TranslateError::UnsupportedSetOperandOrderByOrLimit(sql_set_expr.to_string())
+                    .into(),
+            ),
+        },
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
@@ -75,10 +120,6 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         return Err(TranslateError::TooManyTables.into());
     }
 
-    if distinct.is_some() {
-        return Err(TranslateError::SelectDistinctNotSupported.into());
-    }
-
     let from = match from.first() {
         Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
         None => TableWithJoins {
@@ -100,7 +141,22 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         }
     };
 
+    let distinct = distinct
+        .as_ref()
+        .map(|distinct| -> Result<_> {
+            match distinct {
+                SqlDistinct::Distinct => Ok(Distinct::Distinct),
+                SqlDistinct::On(exprs) => exprs
+                    .iter()
+                    .map(translate_expr)
+                    .collect::<Result<_>>()
+                    .map(Distinct::On),
+            }
+        })
+        .transpose()?;
+
     Ok(Select {
+        distinct,
         projection: projection
             .iter()
             .map(translate_select_item)
@@ -181,7 +237,7 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
         SqlTableFactor::Table {
             name, alias, args, ..
         } => {
-            let object_name = translate_object_name(name)?.to_uppercase();
+            let object_name = translate_idents(&name.0).join(".").to_uppercase();
             let alias = translate_table_alias(alias);
 
             match (object_name.as_str(), args) {
@@ -204,10 +260,6 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
                 _ => {
                     let (chain_name, table_name) = translate_chain_and_table(name)?;
                     let existing_table = chain_name.is_none();
-                    println!(
-                        "chain_name: {:?}, table_name: {:?}, existing_table: {}",
-                        chain_name, table_name, existing_table
-                    );
                     Ok(TableFactor::Table {
                         chain_name: chain_name.to_owned(),
                         name: table_name,
@@ -2,11 +2,11 @@ use {
     crate::{
         ast::Statement,
         executor::{execute, Payload},
-        parse_sql::parse,
+        parse_sql::parse_statements,
         plan::plan,
         result::Result,
         store::{GStore, GStoreMut},
-        translate::translate,
+        translate::translate_parsed,
     },
     futures::{
         stream::{self, StreamExt},
@@ -25,10 +25,10 @@ impl<T: GStore + GStoreMut> Worm<T> {
     }
 
     pub async fn plan<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Statement>> {
-        let parsed = parse(sql)?;
+        let parsed = parse_statements(sql)?;
         let storage = &self.storage;
         stream::iter(parsed)
-            .map(|p| translate(&p))
+            .map(|p| translate_parsed(&p))
             .then(|statement| async move { plan(storage, statement?).await })
             .try_collect()
             .await
@@ -38,11 +38,14 @@ impl<T: GStore + GStoreMut> Worm<T> {
         execute(&mut self.storage, statement).await
     }
 
+    /// Executes the statements of `sql` in turn, each planned once the previous ones ran, so
+    /// that a statement sees the tables and views created before it in the same batch.
     pub async fn execute<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
-        let statements = self.plan(sql).await?;
+        let parsed = parse_statements(sql)?;
         let mut payloads = Vec::<Payload>::new();
-        for statement in statements.iter() {
-            let payload = self.execute_stmt(statement).await?;
+        for parsed in parsed.iter() {
+            let statement = plan(&self.storage, translate_parsed(parsed)?).await?;
+            let payload = self.execute_stmt(&statement).await?;
             payloads.push(payload);
         }
 
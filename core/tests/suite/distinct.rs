@@ -0,0 +1,129 @@
+use {
+    crate::{execute, select},
+    wql_core::prelude::Worm,
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn select_distinct() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(
+        &mut worm,
+        "CREATE TABLE Transfer (id INT NOT NULL, sender INT, amount INT)",
+    )
+    .unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO Transfer VALUES
+            (1, 1, 10), (2, 2, 30), (3, 1, 10), (4, 2, 20), (5, 1, 40), (6, 3, 20)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT sender FROM Transfer ORDER BY sender"
+        ),
+        Ok(select(&["sender"], vec![vec![1], vec![2], vec![3]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT sender, amount FROM Transfer ORDER BY sender, amount"
+        ),
+        Ok(select(
+            &["sender", "amount"],
+            vec![
+                vec![1, 10],
+                vec![1, 40],
+                vec![2, 20],
+                vec![2, 30],
+                vec![3, 20]
+            ]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT sender FROM Transfer ORDER BY sender LIMIT 1 OFFSET 1"
+        ),
+        Ok(select(&["sender"], vec![vec![2]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT ON (sender) sender, id FROM Transfer ORDER BY sender, amount DESC"
+        ),
+        Ok(select(
+            &["sender", "id"],
+            vec![vec![1, 5], vec![2, 2], vec![3, 6]]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT ON (sender) id FROM Transfer ORDER BY sender, id DESC"
+        ),
+        Ok(select(&["id"], vec![vec![5], vec![4], vec![6]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(DISTINCT amount), SUM(DISTINCT amount), COUNT(amount) FROM Transfer"
+        ),
+        Ok(select(
+            &[
+                "COUNT(DISTINCT amount)",
+                "SUM(DISTINCT amount)",
+                "COUNT(amount)"
+            ],
+            vec![vec![4, 100, 6]]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender, COUNT(DISTINCT amount) FROM Transfer GROUP BY sender ORDER BY sender"
+        ),
+        Ok(select(
+            &["sender", "COUNT(DISTINCT amount)"],
+            vec![vec![1, 2], vec![2, 2], vec![3, 1]]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT sender, amount FROM Transfer WHERE amount = 10"
+        ),
+        Ok(select(&["sender", "amount"], vec![vec![1, 10]]))
+    );
+
+    execute(&mut worm, "CREATE TABLE Event (id INT, data MAP)").unwrap();
+    execute(
+        &mut worm,
+        r#"INSERT INTO Event VALUES
+            (1, '{"a": 1, "b": [1, 2]}'), (2, '{"b": [1, 2], "a": 1}'),
+            (3, '{"a": 1, "b": [2, 1]}'), (4, NULL)"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(*) FROM (SELECT DISTINCT data FROM Event) AS t"
+        ),
+        Ok(select(&["COUNT(*)"], vec![vec![3]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT DISTINCT ON (data) id FROM Event ORDER BY id"
+        ),
+        Ok(select(&["id"], vec![vec![1], vec![3], vec![4]]))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT COUNT(DISTINCT data) FROM Event"),
+        Ok(select(&["COUNT(DISTINCT data)"], vec![vec![2]]))
+    );
+}
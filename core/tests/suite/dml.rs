@@ -0,0 +1,102 @@
+use {
+    crate::{execute, ids},
+    wql_core::{
+        data::Value,
+        error::{InsertError, UpdateError, ValueError},
+        prelude::{Payload, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn insert_update_and_delete() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(
+        &mut worm,
+        "CREATE TABLE Foo (id INT NOT NULL, name TEXT DEFAULT 'none', flag BOOLEAN)",
+    )
+    .unwrap();
+    assert_eq!(
+        execute(
+            &mut worm,
+            "INSERT INTO Foo VALUES (1, 'a', TRUE), (2, 'b', FALSE)"
+        ),
+        Ok(Payload::Insert(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "INSERT INTO Foo (id) VALUES (3)"),
+        Ok(Payload::Insert(1))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "INSERT INTO Foo (flag, id) SELECT flag, id + 10 FROM Foo WHERE id < 3"
+        ),
+        Ok(Payload::Insert(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "INSERT INTO Foo (name) VALUES ('c')"),
+        Err(InsertError::LackOfRequiredColumn("id".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "INSERT INTO Foo VALUES (NULL, 'c', TRUE)"),
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+    assert_eq!(
+        execute(&mut worm, "INSERT INTO Foo (id, missing) VALUES (4, 1)"),
+        Err(InsertError::ColumnNotFound("missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "INSERT INTO Foo (id, name) VALUES (4)"),
+        Err(InsertError::WrongNumberOfValues {
+            expected: 2,
+            found: 1
+        }
+        .into())
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "UPDATE Foo SET name = 'z', id = id * 2 WHERE flag = TRUE"
+        ),
+        Ok(Payload::Update(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "UPDATE Foo SET missing = 1"),
+        Err(UpdateError::ColumnNotFound("missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "UPDATE Foo SET id = NULL"),
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DELETE FROM Foo WHERE name = 'none'"),
+        Ok(Payload::Delete(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id, name, flag FROM Foo ORDER BY id"),
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned(), "flag".to_owned()],
+            rows: vec![
+                vec![Value::I64(2), Value::Str("z".to_owned()), Value::Bool(true)],
+                vec![
+                    Value::I64(2),
+                    Value::Str("b".to_owned()),
+                    Value::Bool(false)
+                ],
+                vec![
+                    Value::I64(22),
+                    Value::Str("z".to_owned()),
+                    Value::Bool(true)
+                ],
+            ],
+        })
+    );
+    assert_eq!(
+        execute(&mut worm, "DELETE FROM Foo"),
+        Ok(Payload::Delete(3))
+    );
+    assert_eq!(execute(&mut worm, "SELECT id FROM Foo"), Ok(ids(&[])));
+}
@@ -0,0 +1,151 @@
+use {
+    crate::execute,
+    wql_core::{
+        error::TranslateError,
+        executor::{Analyzed, ExplainRow, Operator},
+        prelude::{Payload, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn explain() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(&mut worm, "CREATE TABLE Foo (id INT, name TEXT)").unwrap();
+    execute(&mut worm, "CREATE TABLE Bar (id INT, foo_id INT)").unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO Foo VALUES (1, 'c'), (2, 'a'), (3, 'b'), (4, 'a')",
+    )
+    .unwrap();
+    execute(&mut worm, "INSERT INTO Bar VALUES (1, 2), (2, 4), (3, 4)").unwrap();
+    execute(&mut worm, "CREATE INDEX idx_name ON Foo (name)").unwrap();
+
+    let row = |depth, operator, detail: &str| ExplainRow {
+        depth,
+        operator,
+        detail: detail.to_owned(),
+        analyzed: None,
+    };
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "EXPLAIN SELECT id FROM Foo WHERE name = 'a' AND id > 2 ORDER BY id LIMIT 1"
+        ),
+        Ok(Payload::Explain(vec![
+            row(0, Operator::Limit, "LIMIT 1"),
+            row(1, Operator::Sort, r#""id""#),
+            row(2, Operator::Project, r#""id" AS "id""#),
+            row(3, Operator::Filter, r#""id" > 2"#),
+            row(4, Operator::Scan, r#""Foo" (index: "idx_name" = 'a')"#),
+        ]))
+    );
+    assert_eq!(
+        execute(&mut worm, "EXPLAIN SELECT id FROM Bar WHERE id > 1"),
+        Ok(Payload::Explain(vec![
+            row(0, Operator::Project, r#""id" AS "id""#),
+            row(1, Operator::Filter, r#""id" > 1"#),
+            row(2, Operator::Scan, r#""Bar""#),
+        ]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "EXPLAIN SELECT * FROM (SELECT id FROM Foo) AS Sub"
+        ),
+        Ok(Payload::Explain(vec![
+            row(0, Operator::Project, "*"),
+            row(1, Operator::Scan, r#"(subquery) AS "Sub""#),
+            row(2, Operator::Project, r#""id" AS "id""#),
+            row(3, Operator::Scan, r#""Foo""#),
+        ]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "EXPLAIN SELECT id FROM Foo UNION ALL SELECT id FROM Bar ORDER BY id"
+        ),
+        Ok(Payload::Explain(vec![
+            row(0, Operator::Sort, r#""id""#),
+            row(1, Operator::SetOperation, "UNION ALL"),
+            row(2, Operator::Project, r#""id" AS "id""#),
+            row(3, Operator::Scan, r#""Foo""#),
+            row(2, Operator::Project, r#""id" AS "id""#),
+            row(3, Operator::Scan, r#""Bar""#),
+        ]))
+    );
+
+    let mut analyze = |sql: &str| {
+        let Ok(Payload::Explain(explain_rows)) = execute(&mut worm, sql) else {
+            panic!("expected EXPLAIN payload");
+        };
+
+        explain_rows
+            .into_iter()
+            .map(|explain_row| {
+                let rows = explain_row.analyzed.map(|Analyzed { rows, .. }| rows);
+
+                (
+                    explain_row.depth,
+                    explain_row.operator,
+                    explain_row.detail,
+                    rows,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        analyze(
+            "EXPLAIN ANALYZE
+            SELECT Foo.id, COUNT(*) FROM Foo JOIN Bar ON Foo.id = Bar.foo_id GROUP BY Foo.id"
+        ),
+        vec![
+            (
+                0,
+                Operator::Project,
+                r#""Foo"."id" AS "id", COUNT(*) AS "COUNT(*)""#.to_owned(),
+                Some(2)
+            ),
+            (
+                1,
+                Operator::Aggregate,
+                r#"GROUP BY "Foo"."id""#.to_owned(),
+                Some(2)
+            ),
+            (
+                2,
+                Operator::Join,
+                r#"INNER, hash (key: "Bar"."foo_id" = "Foo"."id")"#.to_owned(),
+                Some(3)
+            ),
+            (3, Operator::Scan, r#""Foo""#.to_owned(), Some(4)),
+            (3, Operator::Scan, r#""Bar""#.to_owned(), Some(3)),
+        ]
+    );
+    assert_eq!(
+        analyze("EXPLAIN ANALYZE SELECT id FROM Foo WHERE id > 3 UNION SELECT foo_id FROM Bar"),
+        vec![
+            (0, Operator::SetOperation, "UNION".to_owned(), Some(2)),
+            (1, Operator::Project, r#""id" AS "id""#.to_owned(), Some(1)),
+            (2, Operator::Filter, r#""id" > 3"#.to_owned(), Some(1)),
+            (3, Operator::Scan, r#""Foo""#.to_owned(), Some(4)),
+            (
+                1,
+                Operator::Project,
+                r#""foo_id" AS "foo_id""#.to_owned(),
+                Some(3)
+            ),
+            (2, Operator::Scan, r#""Bar""#.to_owned(), Some(3)),
+        ]
+    );
+
+    assert_eq!(
+        execute(&mut worm, "EXPLAIN INSERT INTO Foo VALUES (5, 'e')"),
+        Err(TranslateError::UnsupportedStatement(
+            "EXPLAIN INSERT INTO Foo VALUES (5, 'e')".to_owned()
+        )
+        .into())
+    );
+}
@@ -0,0 +1,149 @@
+use {
+    crate::execute,
+    wql_core::{
+        data::Value,
+        error::{AlterError, EvaluateError},
+        prelude::{Payload, PayloadVariable, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn create_and_drop_function() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE FUNCTION add_ten(x INT, y INT DEFAULT 10) RETURN x + y"
+        ),
+        Ok(Payload::CreateFunction)
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE FUNCTION add_ten(x INT) RETURN x"),
+        Err(AlterError::FunctionAlreadyExists("add_ten".to_owned()).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE FUNCTION normalize(address TEXT, address TEXT) RETURN address"
+        ),
+        Err(AlterError::DuplicateArgName("address".to_owned()).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE FUNCTION sub(x INT DEFAULT 1, y INT) RETURN x - y"
+        ),
+        Err(AlterError::RequiredArgAfterDefault("y".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE FUNCTION add(x INT) RETURN x + y"),
+        Err(AlterError::UnknownArgName("y".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE FUNCTION lower(x TEXT) RETURN x"),
+        Err(AlterError::BuiltinFunctionName("lower".to_owned()).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE OR REPLACE FUNCTION normalize(address TEXT) RETURN LOWER(address)"
+        ),
+        Ok(Payload::CreateFunction)
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT add_ten(1) AS a, ADD_TEN(1, 2) AS b, normalize('0xAbC') AS c"
+        ),
+        Ok(Payload::Select {
+            labels: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+            rows: vec![vec![
+                Value::I64(11),
+                Value::I64(3),
+                Value::Str("0xabc".to_owned())
+            ]],
+        })
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT add_ten() AS a"),
+        Err(EvaluateError::FunctionArgsLengthNotWithinRange {
+            name: "add_ten".to_owned(),
+            expected_minimum: 1,
+            expected_maximum: 2,
+            found: 0,
+        }
+        .into())
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT missing(1) AS a"),
+        Err(EvaluateError::UnsupportedFunction("MISSING".to_owned()).into())
+    );
+
+    execute(
+        &mut worm,
+        "CREATE FUNCTION factorial(n INT) RETURN CASE WHEN n <= 1 THEN 1 ELSE n * factorial(n - 1) END",
+    )
+    .unwrap();
+    execute(
+        &mut worm,
+        "CREATE FUNCTION endless(n INT) RETURN endless(n + 1)",
+    )
+    .unwrap();
+    assert_eq!(
+        execute(&mut worm, "SELECT factorial(10) AS a"),
+        Ok(Payload::Select {
+            labels: vec!["a".to_owned()],
+            rows: vec![vec![Value::I64(3628800)]],
+        })
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT endless(1) AS a"),
+        Err(EvaluateError::FunctionNestedTooDeep("endless".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT factorial(factorial(3)) AS a"),
+        Ok(Payload::Select {
+            labels: vec!["a".to_owned()],
+            rows: vec![vec![Value::I64(720)]],
+        })
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT factorial(3) AS a"),
+        Ok(Payload::Select {
+            labels: vec!["a".to_owned()],
+            rows: vec![vec![Value::I64(6)]],
+        })
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP FUNCTION factorial, endless"),
+        Ok(Payload::DropFunction(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "SHOW FUNCTIONS"),
+        Ok(Payload::ShowVariable(PayloadVariable::Functions(vec![
+            "add_ten(x: INT, y: INT)".to_owned(),
+            "normalize(address: TEXT)".to_owned(),
+        ])))
+    );
+
+    assert_eq!(
+        execute(&mut worm, "DROP FUNCTION add_ten, missing"),
+        Err(AlterError::FunctionNotFound("missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "DROP FUNCTION IF EXISTS add_ten, missing, normalize"
+        ),
+        Ok(Payload::DropFunction(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "SHOW FUNCTIONS"),
+        Ok(Payload::ShowVariable(
+            PayloadVariable::Functions(Vec::new())
+        ))
+    );
+}
@@ -0,0 +1,59 @@
+use {
+    crate::{execute, ids},
+    wql_core::{
+        error::AlterError,
+        prelude::{Payload, Worm},
+        store::IndexError,
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn create_and_drop_index() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(&mut worm, "CREATE TABLE Foo (id INT, name TEXT)").unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO Foo VALUES (1, 'c'), (2, 'a'), (3, 'b'), (4, 'a')",
+    )
+    .unwrap();
+    assert_eq!(
+        execute(&mut worm, "CREATE INDEX idx_name ON Foo (name)"),
+        Ok(Payload::CreateIndex)
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE INDEX idx_missing ON Foo (missing)"),
+        Err(AlterError::IndexColumnNotFound("missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE INDEX idx_name ON Bar (name)"),
+        Err(AlterError::TableNotFound("Bar".to_owned()).into())
+    );
+
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Foo WHERE name = 'a' AND id > 2"),
+        Ok(ids(&[4]))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Foo WHERE name >= 'b'"),
+        Ok(ids(&[3, 1]))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Foo ORDER BY name DESC"),
+        Ok(ids(&[1, 3, 2, 4]))
+    );
+
+    assert_eq!(
+        execute(&mut worm, "DROP INDEX Foo.idx_name"),
+        Ok(Payload::DropIndex)
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP INDEX Foo.idx_name"),
+        Err(IndexError::IndexNameDoesNotExist("idx_name".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Foo WHERE name = 'a'"),
+        Ok(ids(&[2, 4]))
+    );
+}
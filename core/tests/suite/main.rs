@@ -0,0 +1,47 @@
+//! Engine tests, running statements through [`Worm`] against the memory storage.
+
+mod distinct;
+mod dml;
+mod explain;
+mod function;
+mod index;
+mod pushdown;
+mod set_operation;
+mod show;
+mod table;
+mod transaction;
+mod view;
+
+use {
+    futures::executor::block_on,
+    wql_core::{
+        data::Value,
+        error::Result,
+        prelude::{Payload, Worm},
+        store::{GStore, GStoreMut},
+    },
+};
+
+/// Runs `sql`, made of a single statement.
+pub fn execute<T: GStore + GStoreMut>(worm: &mut Worm<T>, sql: &str) -> Result<Payload> {
+    block_on(worm.execute(sql)).map(|mut payloads| payloads.remove(0))
+}
+
+/// Result of selecting `id` only.
+pub fn ids(ids: &[i64]) -> Payload {
+    Payload::Select {
+        labels: vec!["id".to_owned()],
+        rows: ids.iter().map(|id| vec![Value::I64(*id)]).collect(),
+    }
+}
+
+/// Result of selecting integer columns.
+pub fn select(labels: &[&str], rows: Vec<Vec<i64>>) -> Payload {
+    Payload::Select {
+        labels: labels.iter().map(|label| label.to_string()).collect(),
+        rows: rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Value::I64).collect())
+            .collect(),
+    }
+}
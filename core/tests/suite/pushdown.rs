@@ -0,0 +1,144 @@
+use {
+    crate::{execute, ids},
+    async_trait::async_trait,
+    futures::stream::TryStreamExt,
+    std::cell::RefCell,
+    wql_core::{
+        ast::IndexOperator,
+        data::{Key, Schema, Value},
+        error::Result,
+        prelude::Worm,
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, Predicate,
+            RowIter, Store, StoreMut, Transaction,
+        },
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+/// Store applying the predicates pushed down on its tables itself, recording them by table.
+struct Pushed {
+    storage: MemoryStorage,
+    predicates: RefCell<Vec<(String, Predicate)>>,
+}
+
+#[async_trait(?Send)]
+impl Store for Pushed {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.storage.fetch_schema(table_name).await
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.fetch_all_schemas().await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.storage.fetch_data(table_name, key).await
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        self.storage.scan_data(table_name).await
+    }
+
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let column_defs = self
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .unwrap_or_default();
+        let predicates = predicates
+            .iter()
+            .map(|predicate| {
+                let position = column_defs
+                    .iter()
+                    .position(|column_def| column_def.name == predicate.column)
+                    .unwrap();
+
+                (position, predicate.clone())
+            })
+            .collect::<Vec<_>>();
+
+        self.predicates.borrow_mut().extend(
+            predicates
+                .iter()
+                .map(|(_, predicate)| (table_name.to_owned(), predicate.clone())),
+        );
+
+        let handled = vec![true; predicates.len()];
+        let rows = self
+            .storage
+            .scan_data(table_name)
+            .await?
+            .try_filter(move |(_, row)| {
+                let DataRow::Vec(values) = row else {
+                    panic!("rows of tables with columns expected");
+                };
+                let matched = predicates
+                    .iter()
+                    .all(|(position, predicate)| predicate.check(&values[*position]) == Some(true));
+
+                async move { matched }
+            });
+
+        Ok((Box::pin(rows), handled))
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreMut for Pushed {}
+impl Index for Pushed {}
+impl IndexMut for Pushed {}
+impl Metadata for Pushed {}
+impl Transaction for Pushed {}
+impl CustomFunction for Pushed {}
+impl CustomFunctionMut for Pushed {}
+
+#[test]
+fn pushdown_in_join() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(&mut worm, "CREATE TABLE Foo (id INT, name TEXT)").unwrap();
+    execute(&mut worm, "CREATE TABLE Bar (id INT, foo_id INT)").unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO Foo VALUES (1, 'c'), (2, 'a'), (3, 'b'), (4, 'a')",
+    )
+    .unwrap();
+    execute(&mut worm, "INSERT INTO Bar VALUES (1, 2), (2, 4), (3, 4)").unwrap();
+
+    let mut worm = Worm::new(Pushed {
+        storage: worm.storage,
+        predicates: RefCell::new(Vec::new()),
+    });
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT f.id FROM Foo f JOIN Bar b ON f.id = b.foo_id
+             WHERE f.id > 1 AND b.id < 3 AND name = 'a' AND id BETWEEN 1 AND 10
+             ORDER BY f.id"
+        ),
+        Ok(ids(&[2, 4]))
+    );
+    assert_eq!(
+        worm.storage.predicates.take(),
+        vec![(
+            "Foo".to_owned(),
+            Predicate {
+                column: "id".to_owned(),
+                op: IndexOperator::Gt,
+                value: Value::I64(1),
+            }
+        )]
+    );
+
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Foo WHERE id >= 3 AND name = 'a'"),
+        Ok(ids(&[4]))
+    );
+    assert_eq!(worm.storage.predicates.take().len(), 2);
+}
@@ -0,0 +1,201 @@
+use {
+    crate::{execute, select},
+    wql_core::{
+        ast::DataType,
+        executor::{SelectError, SortError},
+        prelude::{Payload, Value, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn set_operation() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(&mut worm, "CREATE TABLE EthTransfer (id INT, sender INT)").unwrap();
+    execute(&mut worm, "CREATE TABLE SuiTransfer (id INT, account INT)").unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO EthTransfer VALUES (1, 10), (2, 20), (3, 20), (4, 30)",
+    )
+    .unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO SuiTransfer VALUES (5, 20), (6, 40), (7, 20)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer UNION SELECT account FROM SuiTransfer
+             ORDER BY sender"
+        ),
+        Ok(select(
+            &["sender"],
+            vec![vec![10], vec![20], vec![30], vec![40]]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer UNION ALL SELECT account FROM SuiTransfer
+             ORDER BY sender DESC LIMIT 3"
+        ),
+        Ok(select(&["sender"], vec![vec![40], vec![30], vec![20]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT id, sender FROM EthTransfer UNION SELECT id, account FROM SuiTransfer
+             ORDER BY 2 DESC, 1 LIMIT 3"
+        ),
+        Ok(select(
+            &["id", "sender"],
+            vec![vec![6, 40], vec![4, 30], vec![2, 20]]
+        ))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender AS account_id FROM EthTransfer EXCEPT SELECT account FROM SuiTransfer
+             ORDER BY account_id DESC"
+        ),
+        Ok(select(&["account_id"], vec![vec![30], vec![10]]))
+    );
+    assert_eq!(
+        execute(&mut worm, "VALUES (2), (1) UNION VALUES (3) ORDER BY 1"),
+        Ok(select(&["column1"], vec![vec![1], vec![2], vec![3]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer UNION SELECT account FROM SuiTransfer ORDER BY 2"
+        ),
+        Err(SortError::ColumnIndexOutOfRange(2).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer INTERSECT SELECT account FROM SuiTransfer"
+        ),
+        Ok(select(&["sender"], vec![vec![20]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer INTERSECT ALL
+             SELECT account FROM SuiTransfer WHERE id > 5"
+        ),
+        Ok(select(&["sender"], vec![vec![20]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer EXCEPT SELECT account FROM SuiTransfer
+             ORDER BY sender"
+        ),
+        Ok(select(&["sender"], vec![vec![10], vec![30]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT sender FROM EthTransfer EXCEPT ALL
+             SELECT account FROM SuiTransfer WHERE id = 5
+             ORDER BY sender"
+        ),
+        Ok(select(&["sender"], vec![vec![10], vec![20], vec![30]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(*) FROM (
+                 SELECT id FROM EthTransfer UNION SELECT id FROM SuiTransfer
+             ) AS t"
+        ),
+        Ok(select(&["COUNT(*)"], vec![vec![7]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT id FROM EthTransfer
+             WHERE sender IN (SELECT account FROM SuiTransfer EXCEPT VALUES (40))"
+        ),
+        Ok(select(&["id"], vec![vec![2], vec![3]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT id FROM EthTransfer UNION SELECT id, account FROM SuiTransfer"
+        ),
+        Err(SelectError::NumberOfColumnsDifferent("UNION".to_owned(), 1, 2).into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT id FROM EthTransfer UNION SELECT 'six' FROM SuiTransfer"
+        ),
+        Err(SelectError::ColumnTypesDifferent(
+            "UNION".to_owned(),
+            "id".to_owned(),
+            DataType::Int,
+            DataType::Text
+        )
+        .into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT id FROM EthTransfer WHERE id = 1 UNION ALL SELECT 1.5 UNION ALL SELECT NULL"
+        ),
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![
+                vec![Value::I64(1)],
+                vec![Value::F64(1.5)],
+                vec![Value::Null]
+            ],
+        })
+    );
+
+    execute(&mut worm, "CREATE TABLE EthEvent (id INT, data MAP)").unwrap();
+    execute(&mut worm, "CREATE TABLE SuiEvent (id INT, data MAP)").unwrap();
+    execute(
+        &mut worm,
+        r#"INSERT INTO EthEvent VALUES (1, '{"a": 1, "b": [1]}'), (2, '{"a": 2}')"#,
+    )
+    .unwrap();
+    execute(
+        &mut worm,
+        r#"INSERT INTO SuiEvent VALUES (3, '{"b": [1], "a": 1}'), (4, '{"a": 3}')"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(*) FROM (
+                 SELECT data FROM EthEvent UNION SELECT data FROM SuiEvent
+             ) AS t"
+        ),
+        Ok(select(&["COUNT(*)"], vec![vec![3]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(*) FROM (
+                 SELECT data FROM EthEvent INTERSECT SELECT data FROM SuiEvent
+             ) AS t"
+        ),
+        Ok(select(&["COUNT(*)"], vec![vec![1]]))
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT COUNT(*) FROM (
+                 SELECT id, data FROM EthEvent EXCEPT SELECT 1, data FROM SuiEvent
+             ) AS t"
+        ),
+        Ok(select(&["COUNT(*)"], vec![vec![1]]))
+    );
+}
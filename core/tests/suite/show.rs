@@ -0,0 +1,83 @@
+use {
+    crate::execute,
+    wql_core::{
+        ast::{AstLiteral, ColumnDef, DataType, Expr},
+        error::ExecuteError,
+        prelude::{Payload, PayloadVariable, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn show_tables_and_columns() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    assert_eq!(
+        execute(&mut worm, "SHOW TABLES"),
+        Ok(Payload::ShowVariable(PayloadVariable::Tables(Vec::new())))
+    );
+
+    execute(
+        &mut worm,
+        "CREATE TABLE Foo (id INT NOT NULL, name TEXT DEFAULT 'none' COMMENT 'nickname')",
+    )
+    .unwrap();
+    execute(&mut worm, "CREATE TABLE Bar (id INT)").unwrap();
+    assert_eq!(
+        execute(&mut worm, "SHOW TABLES"),
+        Ok(Payload::ShowVariable(PayloadVariable::Tables(vec![
+            "Bar".to_owned(),
+            "Foo".to_owned(),
+        ])))
+    );
+
+    let columns = Payload::ShowColumns(vec![
+        ColumnDef {
+            name: "id".to_owned(),
+            data_type: DataType::Int,
+            nullable: false,
+            default: None,
+            comment: None,
+        },
+        ColumnDef {
+            name: "name".to_owned(),
+            data_type: DataType::Text,
+            nullable: true,
+            default: Some(Expr::Literal(AstLiteral::QuotedString("none".to_owned()))),
+            comment: Some("nickname".to_owned()),
+        },
+    ]);
+    assert_eq!(execute(&mut worm, "DESCRIBE Foo"), Ok(columns.clone()));
+    assert_eq!(execute(&mut worm, "SHOW COLUMNS FROM Foo"), Ok(columns));
+    assert_eq!(
+        execute(&mut worm, "DESCRIBE Missing"),
+        Err(ExecuteError::TableNotFound("Missing".to_owned()).into())
+    );
+
+    execute(
+        &mut worm,
+        "CREATE VIEW Names AS SELECT id, name AS nickname, id + 1 AS next FROM Foo",
+    )
+    .unwrap();
+    let column = |name: &str, data_type| ColumnDef {
+        name: name.to_owned(),
+        data_type,
+        nullable: true,
+        default: None,
+        comment: None,
+    };
+    assert_eq!(
+        execute(&mut worm, "SHOW COLUMNS FROM Names"),
+        Ok(Payload::ShowColumns(vec![
+            column("id", DataType::Int),
+            column("nickname", DataType::Text),
+            column("next", DataType::Int),
+        ]))
+    );
+
+    execute(&mut worm, "CREATE TABLE Logs").unwrap();
+    assert_eq!(
+        execute(&mut worm, "DESCRIBE Logs"),
+        Err(ExecuteError::SchemalessTable("Logs".to_owned()).into())
+    );
+}
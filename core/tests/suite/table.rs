@@ -0,0 +1,164 @@
+use {
+    crate::execute,
+    futures::executor::block_on,
+    wql_core::{
+        ast::DataType,
+        data::Value,
+        error::{AlterError, InsertError, ValueError},
+        prelude::{Payload, Worm},
+        store::Store,
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn create_and_drop_table() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    assert_eq!(
+        execute(&mut worm, "CREATE TABLE Foo (id INT NOT NULL, name TEXT)"),
+        Ok(Payload::Create)
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE TABLE Foo (id INT)"),
+        Err(AlterError::TableAlreadyExists("Foo".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE TABLE IF NOT EXISTS Foo (id INT)"),
+        Ok(Payload::Create)
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE TABLE Bar (id INT, id TEXT)"),
+        Err(AlterError::DuplicateColumnName("id".to_owned()).into())
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE TABLE Baz AS SELECT 1 AS id, 'a' AS name, NULL AS flag"
+        ),
+        Ok(Payload::Create)
+    );
+    let schema = block_on(worm.storage.fetch_schema("Baz")).unwrap().unwrap();
+    let columns = schema
+        .column_defs
+        .unwrap()
+        .into_iter()
+        .map(|column_def| (column_def.name, column_def.data_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![
+            ("id".to_owned(), DataType::Int),
+            ("name".to_owned(), DataType::Text),
+            ("flag".to_owned(), DataType::Text),
+        ]
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT * FROM Baz"),
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned(), "flag".to_owned()],
+            rows: vec![vec![Value::I64(1), Value::Str("a".to_owned()), Value::Null]],
+        })
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE TABLE Qux (id INT, name TEXT) AS SELECT 1, 'a'"
+        ),
+        Ok(Payload::Create)
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE TABLE Quux (id INT, name TEXT) AS SELECT 1"
+        ),
+        Err(InsertError::WrongNumberOfValues {
+            expected: 2,
+            found: 1
+        }
+        .into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE TABLE Quux (id INT) AS SELECT 'a'"),
+        Err(ValueError::IncompatibleDataType {
+            data_type: DataType::Int,
+            value: Value::Str("a".to_owned()),
+        }
+        .into())
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE TABLE Quux (id INT NOT NULL) AS SELECT NULL"
+        ),
+        Err(ValueError::NullValueOnNotNullField.into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP TABLE Foo, Missing"),
+        Err(AlterError::TableNotFound("Missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP TABLE IF EXISTS Foo, Missing, Baz, Qux"),
+        Ok(Payload::DropTable(3))
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP TABLE Foo"),
+        Err(AlterError::TableNotFound("Foo".to_owned()).into())
+    );
+    assert_eq!(
+        block_on(worm.storage.fetch_all_schemas()).unwrap(),
+        Vec::new()
+    );
+}
+
+#[test]
+fn create_table_as_types() {
+    let mut worm = Worm::new(MemoryStorage::default());
+    for sql in [
+        "CREATE TABLE Foo (id INT8, amount DECIMAL, ok BOOLEAN, name TEXT)",
+        "INSERT INTO Foo VALUES (1, NULL, NULL, '2')",
+        "CREATE TABLE Empty AS SELECT * FROM Foo WHERE id < 0",
+        "CREATE TABLE Nulls AS SELECT f.amount, ok FROM Foo AS f",
+        "CREATE TABLE Exprs AS SELECT id > 1 AS big, CAST(name AS INT) AS n, -id AS neg FROM Foo",
+        "CREATE TABLE Aggregates AS SELECT MAX(id) AS top, COUNT(*) AS count FROM Foo",
+        "CREATE TABLE Sub AS SELECT total FROM (SELECT amount AS total FROM Foo) AS Bar",
+    ] {
+        assert_eq!(execute(&mut worm, sql).map(|_| ()), Ok(()), "{sql}");
+    }
+
+    let column_types = |name: &str| {
+        block_on(worm.storage.fetch_schema(name))
+            .unwrap()
+            .unwrap()
+            .column_defs
+            .unwrap()
+            .into_iter()
+            .map(|column_def| column_def.data_type)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        column_types("Empty"),
+        vec![
+            DataType::Int8,
+            DataType::Decimal,
+            DataType::Boolean,
+            DataType::Text
+        ]
+    );
+    assert_eq!(
+        column_types("Nulls"),
+        vec![DataType::Decimal, DataType::Boolean]
+    );
+    assert_eq!(
+        column_types("Exprs"),
+        vec![DataType::Boolean, DataType::Int, DataType::Int8]
+    );
+    assert_eq!(
+        column_types("Aggregates"),
+        vec![DataType::Int8, DataType::Int]
+    );
+    assert_eq!(column_types("Sub"), vec![DataType::Decimal]);
+}
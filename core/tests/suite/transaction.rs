@@ -0,0 +1,51 @@
+use {
+    crate::{execute, ids},
+    wql_core::{
+        data::KeyError,
+        prelude::{Payload, Worm},
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn transaction() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(&mut worm, "CREATE TABLE Foo (id INT NOT NULL)").unwrap();
+    assert_eq!(execute(&mut worm, "BEGIN"), Ok(Payload::StartTransaction));
+    execute(&mut worm, "INSERT INTO Foo VALUES (1), (2)").unwrap();
+    assert!(execute(&mut worm, "INSERT INTO Foo VALUES (NULL)").is_err());
+    assert_eq!(execute(&mut worm, "SELECT id FROM Foo"), Ok(ids(&[1, 2])));
+    assert_eq!(execute(&mut worm, "ROLLBACK"), Ok(Payload::Rollback));
+    assert_eq!(execute(&mut worm, "SELECT id FROM Foo"), Ok(ids(&[])));
+
+    assert_eq!(
+        execute(&mut worm, "START TRANSACTION"),
+        Ok(Payload::StartTransaction)
+    );
+    execute(&mut worm, "INSERT INTO Foo VALUES (3)").unwrap();
+    assert_eq!(execute(&mut worm, "COMMIT"), Ok(Payload::Commit));
+    assert_eq!(execute(&mut worm, "ROLLBACK"), Ok(Payload::Rollback));
+    assert_eq!(execute(&mut worm, "SELECT id FROM Foo"), Ok(ids(&[3])));
+
+    execute(&mut worm, "CREATE TABLE Bar (id INT, data MAP)").unwrap();
+    execute(&mut worm, "CREATE INDEX idx_data ON Bar (data)").unwrap();
+    assert_eq!(
+        execute(
+            &mut worm,
+            r#"INSERT INTO Bar VALUES (1, NULL), (2, '{"a": 1}')"#
+        ),
+        Err(KeyError::MapTypeKeyNotSupported.into())
+    );
+    assert_eq!(execute(&mut worm, "SELECT id FROM Bar"), Ok(ids(&[])));
+
+    execute(&mut worm, "INSERT INTO Bar VALUES (1, NULL), (2, NULL)").unwrap();
+    assert_eq!(
+        execute(
+            &mut worm,
+            r#"UPDATE Bar SET id = id + 10, data = CASE id WHEN 2 THEN '{"a": 1}' END"#
+        ),
+        Err(KeyError::MapTypeKeyNotSupported.into())
+    );
+    assert_eq!(execute(&mut worm, "SELECT id FROM Bar"), Ok(ids(&[1, 2])));
+}
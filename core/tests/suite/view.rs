@@ -0,0 +1,182 @@
+use {
+    crate::{execute, ids},
+    futures::executor::block_on,
+    wql_core::{
+        data::{Schema, SchemaView},
+        error::{AlterError, ExecuteError},
+        parse_sql::parse_query,
+        plan::PlanError,
+        prelude::{Payload, Worm},
+        store::StoreMut,
+        translate::translate_query,
+    },
+    wql_memory_storage::MemoryStorage,
+};
+
+#[test]
+fn create_and_drop_view() {
+    let mut worm = Worm::new(MemoryStorage::default());
+
+    execute(
+        &mut worm,
+        "CREATE TABLE Transfer (id INT NOT NULL, amount INT NOT NULL)",
+    )
+    .unwrap();
+    execute(
+        &mut worm,
+        "INSERT INTO Transfer VALUES (1, 10), (2, 200), (3, 3000)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE VIEW Large AS SELECT id, amount FROM Transfer WHERE amount > 100"
+        ),
+        Ok(Payload::CreateView)
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE VIEW Huge AS SELECT id FROM Large WHERE amount > 1000"
+        ),
+        Ok(Payload::CreateView)
+    );
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE MATERIALIZED VIEW Snapshot AS SELECT id FROM Large"
+        ),
+        Ok(Payload::CreateView)
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE VIEW Large AS SELECT id FROM Transfer"),
+        Err(AlterError::ViewAlreadyExists("Large".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "CREATE VIEW Transfer AS SELECT id FROM Large"),
+        Err(AlterError::TableAlreadyExists("Transfer".to_owned()).into())
+    );
+
+    execute(&mut worm, "INSERT INTO Transfer VALUES (4, 500)").unwrap();
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Large ORDER BY id"),
+        Ok(ids(&[2, 3, 4]))
+    );
+    assert_eq!(execute(&mut worm, "SELECT id FROM Huge"), Ok(ids(&[3])));
+    assert_eq!(
+        execute(
+            &mut worm,
+            "SELECT l.id FROM Large l JOIN Huge h ON l.id = h.id"
+        ),
+        Ok(ids(&[3]))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Snapshot ORDER BY id"),
+        Ok(ids(&[2, 3]))
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE OR REPLACE MATERIALIZED VIEW Snapshot AS SELECT id FROM Large"
+        ),
+        Ok(Payload::CreateView)
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Snapshot ORDER BY id"),
+        Ok(ids(&[2, 3, 4]))
+    );
+
+    execute(&mut worm, "INSERT INTO Transfer VALUES (5, 700)").unwrap();
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Snapshot ORDER BY id"),
+        Ok(ids(&[2, 3, 4]))
+    );
+    assert_eq!(
+        execute(&mut worm, "REFRESH MATERIALIZED VIEW Snapshot"),
+        Ok(Payload::RefreshView(4))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Snapshot ORDER BY id"),
+        Ok(ids(&[2, 3, 4, 5]))
+    );
+    assert_eq!(
+        execute(&mut worm, "REFRESH MATERIALIZED VIEW Large"),
+        Err(AlterError::NotAMaterializedView("Large".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "REFRESH MATERIALIZED VIEW Missing"),
+        Err(AlterError::ViewNotFound("Missing".to_owned()).into())
+    );
+
+    assert_eq!(
+        execute(
+            &mut worm,
+            "CREATE OR REPLACE VIEW Large AS SELECT id, amount * 10 AS amount FROM Transfer"
+        ),
+        Ok(Payload::CreateView)
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Huge ORDER BY id"),
+        Ok(ids(&[2, 3, 4, 5]))
+    );
+
+    for (sql, name) in [
+        ("INSERT INTO Large VALUES (6, 6000)", "Large"),
+        ("UPDATE Large SET amount = 1", "Large"),
+        ("DELETE FROM Snapshot", "Snapshot"),
+    ] {
+        assert_eq!(
+            execute(&mut worm, sql),
+            Err(ExecuteError::NotATable(name.to_owned()).into()),
+            "{sql}"
+        );
+    }
+    assert_eq!(
+        execute(&mut worm, "DROP TABLE Large"),
+        Err(AlterError::NotATable("Large".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP VIEW Transfer"),
+        Err(AlterError::NotAView("Transfer".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP VIEW Missing"),
+        Err(AlterError::ViewNotFound("Missing".to_owned()).into())
+    );
+    assert_eq!(
+        execute(&mut worm, "DROP VIEW IF EXISTS Huge, Missing, Snapshot"),
+        Ok(Payload::DropView(2))
+    );
+    assert_eq!(
+        execute(&mut worm, "SELECT id FROM Large WHERE amount = 5000"),
+        Ok(ids(&[4]))
+    );
+
+    assert_eq!(
+        block_on(worm.execute(
+            "CREATE VIEW Small AS SELECT id FROM Transfer WHERE amount < 100; SELECT id FROM Small"
+        )),
+        Ok(vec![Payload::CreateView, ids(&[1])])
+    );
+
+    let query = translate_query(&parse_query("SELECT id FROM Loop").unwrap()).unwrap();
+    let schema = Schema {
+        table_name: "Loop".to_owned(),
+        column_defs: None,
+        indexes: Vec::new(),
+        comment: None,
+        chain_name: None,
+        view: Some(SchemaView {
+            query,
+            materialized: false,
+        }),
+    };
+
+    block_on(worm.storage.insert_schema(&schema)).unwrap();
+    assert_eq!(
+        block_on(worm.execute("SELECT id FROM Loop")),
+        Err(PlanError::ViewNestedTooDeep("Loop".to_owned()).into())
+    );
+}
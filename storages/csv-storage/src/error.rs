@@ -0,0 +1,35 @@
+use {
+    thiserror::Error as ThisError,
+    wql_core::error::{Error, Result},
+};
+
+#[derive(ThisError, Debug, PartialEq, Eq)]
+pub enum CsvStorageError {
+    #[error("unknown data type in the types file of {table_name}: {data_type}")]
+    UnknownDataType {
+        table_name: String,
+        data_type: String,
+    },
+
+    #[error("types file of {0} requires a header and a single row of data types")]
+    InvalidTypesFile(String),
+
+    #[error("column of the types file not found in the header of {table_name}: {column}")]
+    TypedColumnNotFound { table_name: String, column: String },
+}
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<CsvStorageError> for Error {
+    fn from(e: CsvStorageError) -> Error {
+        Error::StorageMsg(e.to_string())
+    }
+}
@@ -0,0 +1,191 @@
+#![deny(clippy::str_to_string)]
+
+mod error;
+mod store;
+mod store_mut;
+
+pub use error::{CsvStorageError, ResultExt};
+
+use {
+    csv::{ReaderBuilder, StringRecord},
+    std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+    },
+    wql_core::{
+        ast::{ColumnDef, DataType},
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, Transaction,
+        },
+    },
+};
+
+/// Schema of a table along with its rows.
+type Table = (Schema, Vec<(Key, DataRow)>);
+
+/// Storage serving every `<table>.csv` file of a directory as a table.
+///
+/// The header of a file names the columns of its table. Their types are read from the
+/// `<table>.types.csv` file when it exists, which holds the same header followed by a single
+/// row of data types, and are inferred from the cells otherwise. Empty cells are `NULL`.
+#[derive(Debug, Clone)]
+pub struct CsvStorage {
+    pub path: PathBuf,
+}
+
+impl CsvStorage {
+    pub fn new<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let path = path.as_ref();
+        fs::create_dir_all(path).map_storage_err()?;
+
+        Ok(Self { path: path.into() })
+    }
+
+    fn data_path(&self, table_name: &str) -> PathBuf {
+        self.path.join(format!("{table_name}.csv"))
+    }
+
+    fn types_path(&self, table_name: &str) -> PathBuf {
+        self.path.join(format!("{table_name}.types.csv"))
+    }
+
+    /// Data types listed by the types file of `table_name`, keyed by column name.
+    fn read_types(&self, table_name: &str) -> Result<HashMap<String, DataType>> {
+        let path = self.types_path(table_name);
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let mut reader = ReaderBuilder::new().from_path(path).map_storage_err()?;
+        let header = reader.headers().map_storage_err()?.clone();
+        let mut records = reader.records();
+        let (Some(Ok(data_types)), None) = (records.next(), records.next()) else {
+            return Err(CsvStorageError::InvalidTypesFile(table_name.to_owned()).into());
+        };
+
+        header
+            .iter()
+            .zip(data_types.iter())
+            .map(|(column, data_type)| {
+                let data_type = data_type.trim().parse::<DataType>().map_err(|_| {
+                    CsvStorageError::UnknownDataType {
+                        table_name: table_name.to_owned(),
+                        data_type: data_type.to_owned(),
+                    }
+                })?;
+
+                Ok((column.to_owned(), data_type))
+            })
+            .collect()
+    }
+
+    /// Schema and rows of `table_name`, `None` when the directory holds no such file.
+    ///
+    /// Rows are keyed by their position in the file.
+    fn read_table(&self, table_name: &str) -> Result<Option<Table>> {
+        let path = self.data_path(table_name);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let mut reader = ReaderBuilder::new()
+            .flexible(true)
+            .from_path(path)
+            .map_storage_err()?;
+        let header = reader.headers().map_storage_err()?.clone();
+        let records = reader
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .map_storage_err()?;
+
+        let column_defs = match header.is_empty() {
+            true => None,
+            false => Some(self.column_defs(table_name, &header, &records)?),
+        };
+        let rows = records
+            .into_iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let values = column_defs
+                    .iter()
+                    .flatten()
+                    .enumerate()
+                    .map(|(j, column_def)| match record.get(j) {
+                        None | Some("") => Ok(Value::Null),
+                        Some(cell) => Value::Str(cell.to_owned()).cast(&column_def.data_type),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok((Key::I64(i as i64), DataRow::Vec(values)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let schema = Schema {
+            table_name: table_name.to_owned(),
+            column_defs,
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+
+        Ok(Some((schema, rows)))
+    }
+
+    fn column_defs(
+        &self,
+        table_name: &str,
+        header: &StringRecord,
+        records: &[StringRecord],
+    ) -> Result<Vec<ColumnDef>> {
+        let mut data_types = self.read_types(table_name)?;
+        let column_defs = header
+            .iter()
+            .enumerate()
+            .map(|(i, name)| ColumnDef {
+                name: name.to_owned(),
+                data_type: data_types
+                    .remove(name)
+                    .unwrap_or_else(|| infer(records.iter().filter_map(|record| record.get(i)))),
+                nullable: true,
+                default: None,
+                comment: None,
+            })
+            .collect();
+
+        match data_types.into_keys().next() {
+            Some(column) => Err(CsvStorageError::TypedColumnNotFound {
+                table_name: table_name.to_owned(),
+                column,
+            }
+            .into()),
+            None => Ok(column_defs),
+        }
+    }
+}
+
+/// Narrowest of `BOOLEAN`, `INT` and `FLOAT` holding every non empty cell, `TEXT` otherwise.
+fn infer<'a>(cells: impl Iterator<Item = &'a str>) -> DataType {
+    let cells = cells.filter(|cell| !cell.is_empty()).collect::<Vec<_>>();
+    let holds = |check: fn(&str) -> bool| !cells.is_empty() && cells.iter().all(|cell| check(cell));
+
+    if holds(|cell| cell.eq_ignore_ascii_case("true") || cell.eq_ignore_ascii_case("false")) {
+        DataType::Boolean
+    } else if holds(|cell| cell.parse::<i64>().is_ok()) {
+        DataType::Int
+    } else if holds(|cell| cell.parse::<f64>().is_ok()) {
+        DataType::Float
+    } else {
+        DataType::Text
+    }
+}
+
+impl Metadata for CsvStorage {}
+impl Index for CsvStorage {}
+impl IndexMut for CsvStorage {}
+impl Transaction for CsvStorage {}
+impl CustomFunction for CsvStorage {}
+impl CustomFunctionMut for CsvStorage {}
@@ -0,0 +1,202 @@
+use {
+    crate::{CsvStorage, ResultExt},
+    async_trait::async_trait,
+    futures::stream,
+    std::{ffi::OsStr, fs},
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl Store for CsvStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema = self.read_table(table_name)?.map(|(schema, _)| schema);
+
+        Ok(schema)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut table_names = Vec::new();
+        for entry in fs::read_dir(&self.path).map_storage_err()? {
+            let path = entry.map_storage_err()?.path();
+            let table_name = path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|file_name| file_name.strip_suffix(".csv"))
+                .filter(|table_name| !table_name.ends_with(".types"));
+
+            table_names.extend(table_name.map(ToOwned::to_owned));
+        }
+        table_names.sort();
+
+        let mut schemas = Vec::new();
+        for table_name in table_names {
+            schemas.extend(self.fetch_schema(&table_name).await?);
+        }
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let row = self.read_table(table_name)?.and_then(|(_, rows)| {
+            rows.into_iter()
+                .find_map(|(row_key, row)| (&row_key == key).then_some(row))
+        });
+
+        Ok(row)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        let rows = self
+            .read_table(table_name)?
+            .map(|(_, rows)| rows)
+            .unwrap_or_default();
+
+        Ok(Box::pin(stream::iter(rows.into_iter().map(Ok))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::CsvStorage,
+        futures::{executor::block_on, TryStreamExt},
+        std::fs,
+        wql_core::{
+            ast::DataType,
+            data::{Key, Value},
+            prelude::{Payload, Worm},
+            store::{DataRow, Store},
+        },
+    };
+
+    const BLOCKS: &str = "number,miner,gas_used,base_fee,finalized
+1,0xaa,21000,1.5,true
+2,0xbb,,2,FALSE
+3,0xaa,42000,,
+";
+
+    const MINERS: &str = "address,name,reward
+0xaa,alice,2
+0xbb,bob,
+";
+
+    fn storage() -> (tempfile::TempDir, CsvStorage) {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("Block.csv"), BLOCKS).unwrap();
+        fs::write(dir.path().join("Miner.csv"), MINERS).unwrap();
+        fs::write(
+            dir.path().join("Miner.types.csv"),
+            "reward,address\nUINT64,TEXT\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("README.md"), "not a table").unwrap();
+
+        let storage = CsvStorage::new(dir.path()).unwrap();
+
+        (dir, storage)
+    }
+
+    #[test]
+    fn scan() {
+        let (_dir, storage) = storage();
+
+        let schemas = block_on(storage.fetch_all_schemas()).unwrap();
+        let columns = schemas
+            .iter()
+            .map(|schema| {
+                let column_defs = schema.column_defs.as_ref().unwrap();
+                let columns = column_defs
+                    .iter()
+                    .map(|column_def| (column_def.name.as_str(), column_def.data_type.clone()))
+                    .collect::<Vec<_>>();
+
+                (schema.table_name.as_str(), columns)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![
+                (
+                    "Block",
+                    vec![
+                        ("number", DataType::Int),
+                        ("miner", DataType::Text),
+                        ("gas_used", DataType::Int),
+                        ("base_fee", DataType::Float),
+                        ("finalized", DataType::Boolean),
+                    ]
+                ),
+                (
+                    "Miner",
+                    vec![
+                        ("address", DataType::Text),
+                        ("name", DataType::Text),
+                        ("reward", DataType::Uint64),
+                    ]
+                ),
+            ]
+        );
+
+        let rows: Vec<_> = block_on(async {
+            let rows = storage.scan_data("Block").await.unwrap();
+
+            rows.try_collect().await.unwrap()
+        });
+        assert_eq!(
+            rows[1],
+            (
+                Key::I64(1),
+                DataRow::Vec(vec![
+                    Value::I64(2),
+                    Value::Str("0xbb".to_owned()),
+                    Value::Null,
+                    Value::F64(2.0),
+                    Value::Bool(false),
+                ])
+            )
+        );
+        assert_eq!(
+            block_on(storage.fetch_data("Miner", &Key::I64(0))).unwrap(),
+            Some(DataRow::Vec(vec![
+                Value::Str("0xaa".to_owned()),
+                Value::Str("alice".to_owned()),
+                Value::U64(2),
+            ]))
+        );
+        assert_eq!(block_on(storage.fetch_schema("Missing")).unwrap(), None);
+
+        fs::write(storage.path.join("Miner.types.csv"), "reward\nMONEY\n").unwrap();
+        assert!(block_on(storage.fetch_schema("Miner")).is_err());
+
+        fs::write(storage.path.join("Miner.types.csv"), "stake\nINT\n").unwrap();
+        assert!(block_on(storage.fetch_schema("Miner")).is_err());
+    }
+
+    #[test]
+    fn select() {
+        let (_dir, storage) = storage();
+        let mut worm = Worm::new(storage);
+
+        let payloads = block_on(worm.execute(
+            "SELECT Block.number, Miner.name FROM Block
+             JOIN Miner ON Block.miner = Miner.address
+             WHERE Block.gas_used IS NOT NULL",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            payloads,
+            vec![Payload::Select {
+                labels: vec!["number".to_owned(), "name".to_owned()],
+                rows: vec![
+                    vec![Value::I64(1), Value::Str("alice".to_owned())],
+                    vec![Value::I64(3), Value::Str("alice".to_owned())],
+                ],
+            }]
+        );
+    }
+}
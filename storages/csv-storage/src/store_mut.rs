@@ -0,0 +1,283 @@
+use {
+    crate::{CsvStorage, ResultExt},
+    async_trait::async_trait,
+    csv::Writer,
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fs::{self, OpenOptions},
+        io::Write,
+        path::Path,
+    },
+    wql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+};
+
+impl CsvStorage {
+    /// Columns written for `rows`, those of a schemaless table being the keys of its rows.
+    fn columns(schema: &Schema, rows: &[DataRow]) -> Vec<String> {
+        match &schema.column_defs {
+            Some(column_defs) => column_defs
+                .iter()
+                .map(|column_def| column_def.name.clone())
+                .collect(),
+            None => rows
+                .iter()
+                .flat_map(|row| match row {
+                    DataRow::Map(values) => values.keys().cloned().collect(),
+                    DataRow::Vec(_) => Vec::new(),
+                })
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Cells of `row` in the order of `columns`.
+    fn to_record(columns: &[String], row: DataRow) -> Vec<String> {
+        match row {
+            DataRow::Vec(values) => values.into_iter().map(to_cell).collect(),
+            DataRow::Map(mut values) => columns
+                .iter()
+                .map(|column| values.remove(column).map(to_cell).unwrap_or_default())
+                .collect(),
+        }
+    }
+
+    /// Replaces the data file of the table with a header followed by `rows`.
+    fn write_rows(&self, schema: &Schema, rows: Vec<DataRow>) -> Result<()> {
+        let columns = Self::columns(schema, &rows);
+        let mut writer = Writer::from_path(self.data_path(&schema.table_name)).map_storage_err()?;
+
+        if !columns.is_empty() {
+            writer.write_record(&columns).map_storage_err()?;
+        }
+
+        for row in rows {
+            writer
+                .write_record(Self::to_record(&columns, row))
+                .map_storage_err()?;
+        }
+
+        writer.flush().map_storage_err()
+    }
+}
+
+/// `NULL` is written as an empty cell.
+fn to_cell(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        value => value.into(),
+    }
+}
+
+fn remove_file(path: &Path) -> Result<()> {
+    match path.exists() {
+        true => fs::remove_file(path).map_storage_err(),
+        false => Ok(()),
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreMut for CsvStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let table_name = &schema.table_name;
+        if !self.data_path(table_name).exists() {
+            self.write_rows(schema, Vec::new())?;
+        }
+
+        let Some(column_defs) = &schema.column_defs else {
+            return Ok(());
+        };
+
+        let mut writer = Writer::from_path(self.types_path(table_name)).map_storage_err()?;
+        writer
+            .write_record(column_defs.iter().map(|column_def| &column_def.name))
+            .map_storage_err()?;
+        writer
+            .write_record(
+                column_defs
+                    .iter()
+                    .map(|column_def| column_def.data_type.to_string()),
+            )
+            .map_storage_err()?;
+
+        writer.flush().map_storage_err()
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        remove_file(&self.data_path(table_name))?;
+        remove_file(&self.types_path(table_name))
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let Some((schema, prev_rows)) = self.read_table(table_name)? else {
+            return Ok(());
+        };
+
+        if schema.column_defs.is_none() {
+            let rows = prev_rows.into_iter().map(|(_, row)| row).chain(rows);
+
+            return self.write_rows(&schema, rows.collect());
+        }
+
+        let data_path = self.data_path(table_name);
+        let ends_with_newline = fs::read(&data_path)
+            .map_storage_err()?
+            .last()
+            .is_none_or(|byte| *byte == b'\n');
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(data_path)
+            .map_storage_err()?;
+        if !ends_with_newline {
+            writeln!(file).map_storage_err()?;
+        }
+
+        let columns = Self::columns(&schema, &rows);
+        let mut writer = Writer::from_writer(file);
+        for row in rows {
+            writer
+                .write_record(Self::to_record(&columns, row))
+                .map_storage_err()?;
+        }
+
+        writer.flush().map_storage_err()
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let Some((schema, prev_rows)) = self.read_table(table_name)? else {
+            return Ok(());
+        };
+
+        let mut prev_rows = prev_rows.into_iter().collect::<BTreeMap<_, _>>();
+        prev_rows.extend(rows);
+
+        self.write_rows(&schema, prev_rows.into_values().collect())
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let Some((schema, prev_rows)) = self.read_table(table_name)? else {
+            return Ok(());
+        };
+
+        let rows = prev_rows
+            .into_iter()
+            .filter(|(key, _)| !keys.contains(key))
+            .map(|(_, row)| row)
+            .collect();
+
+        self.write_rows(&schema, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::CsvStorage,
+        futures::{executor::block_on, TryStreamExt},
+        std::{collections::HashMap, fs},
+        wql_core::{
+            ast::{ColumnDef, DataType},
+            data::{Key, Schema, Value},
+            store::{DataRow, Store, StoreMut},
+        },
+    };
+
+    fn scan(storage: &CsvStorage, table_name: &str) -> Vec<(Key, DataRow)> {
+        block_on(async {
+            let rows = storage.scan_data(table_name).await.unwrap();
+
+            rows.try_collect().await.unwrap()
+        })
+    }
+
+    #[test]
+    fn write() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut storage = CsvStorage::new(dir.path()).unwrap();
+        let column = |name: &str, data_type| ColumnDef {
+            name: name.to_owned(),
+            data_type,
+            nullable: true,
+            default: None,
+            comment: None,
+        };
+        let schema = Schema {
+            table_name: "Block".to_owned(),
+            column_defs: Some(vec![
+                column("number", DataType::Uint64),
+                column("hash", DataType::Text),
+            ]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+        let row = |number, hash: &str| {
+            DataRow::Vec(vec![Value::U64(number), Value::Str(hash.to_owned())])
+        };
+
+        block_on(async {
+            storage.insert_schema(&schema).await.unwrap();
+            storage
+                .append_data("Block", vec![row(1, "0x01"), row(2, "0x02")])
+                .await
+                .unwrap();
+            storage
+                .append_data(
+                    "Block",
+                    vec![DataRow::Vec(vec![Value::U64(3), Value::Null])],
+                )
+                .await
+                .unwrap();
+            storage
+                .insert_data("Block", vec![(Key::I64(1), row(20, "0x20"))])
+                .await
+                .unwrap();
+            storage
+                .delete_data("Block", vec![Key::I64(0)])
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Block.csv")).unwrap(),
+            "number,hash\n20,0x20\n3,\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Block.types.csv")).unwrap(),
+            "number,hash\nUINT64,TEXT\n"
+        );
+        assert_eq!(
+            block_on(storage.fetch_schema("Block")).unwrap(),
+            Some(schema)
+        );
+
+        fs::write(dir.path().join("Log.csv"), "id,data\n1,0xff").unwrap();
+        block_on(storage.append_data(
+            "Log",
+            vec![DataRow::Map(HashMap::from([(
+                "id".to_owned(),
+                Value::I64(2),
+            )]))],
+        ))
+        .unwrap();
+        assert_eq!(
+            scan(&storage, "Log"),
+            vec![
+                (
+                    Key::I64(0),
+                    DataRow::Vec(vec![Value::I64(1), Value::Str("0xff".to_owned())])
+                ),
+                (Key::I64(1), DataRow::Vec(vec![Value::I64(2), Value::Null])),
+            ]
+        );
+
+        block_on(storage.delete_schema("Block")).unwrap();
+        assert_eq!(block_on(storage.fetch_schema("Block")).unwrap(), None);
+        assert!(!dir.path().join("Block.types.csv").exists());
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    thiserror::Error as ThisError,
+    wql_core::error::{Error, Result},
+};
+
+#[derive(ThisError, Debug, PartialEq, Eq)]
+pub enum JsonStorageError {
+    #[error("both {0}.jsonl and {0}.json files exist, remove or rename one of them")]
+    BothJsonlAndJsonExist(String),
+
+    #[error("table name does not match with the schema file: {0}")]
+    TableNameDoesNotMatchWithFile(String),
+
+    #[error("invalid json content: {0}")]
+    InvalidJsonContent(String),
+
+    #[error("json object type is required, found: {0}")]
+    JsonObjectTypeRequired(String),
+}
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<JsonStorageError> for Error {
+    fn from(e: JsonStorageError) -> Error {
+        Error::StorageMsg(e.to_string())
+    }
+}
@@ -0,0 +1,160 @@
+#![deny(clippy::str_to_string)]
+
+mod error;
+mod store;
+mod store_mut;
+
+pub use error::{JsonStorageError, ResultExt};
+
+use {
+    serde_json::{Map as JsonMap, Value as JsonValue},
+    std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+    },
+    wql_core::{
+        data::{HashMapJsonExt, Key, Schema, Value},
+        error::Result,
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, Transaction,
+        },
+    },
+};
+
+/// Storage serving every `<table>.jsonl` or `<table>.json` file of a directory as a table.
+///
+/// A `<table>.sql` file holding the `CREATE TABLE` statement of the table gives its columns,
+/// the table is schemaless otherwise and its rows are maps. A `.jsonl` file holds an object
+/// per line while a `.json` file holds an array of objects or a single object.
+#[derive(Debug, Clone)]
+pub struct JsonStorage {
+    pub path: PathBuf,
+}
+
+impl JsonStorage {
+    pub fn new<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let path = path.as_ref();
+        fs::create_dir_all(path).map_storage_err()?;
+
+        Ok(Self { path: path.into() })
+    }
+
+    fn path_by(&self, table_name: &str, extension: &str) -> PathBuf {
+        self.path.join(format!("{table_name}.{extension}"))
+    }
+
+    fn schema_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "sql")
+    }
+
+    fn json_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "json")
+    }
+
+    fn jsonl_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "jsonl")
+    }
+
+    /// Schema of `table_name`, `None` when the directory holds neither its schema nor its rows.
+    fn read_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let has_json = self.json_path(table_name).exists();
+        let has_jsonl = self.jsonl_path(table_name).exists();
+        if has_json && has_jsonl {
+            return Err(JsonStorageError::BothJsonlAndJsonExist(table_name.to_owned()).into());
+        }
+
+        let schema_path = self.schema_path(table_name);
+        if schema_path.exists() {
+            let ddl = fs::read_to_string(schema_path).map_storage_err()?;
+            let schema = Schema::from_ddl(&ddl)?;
+            if schema.table_name != table_name {
+                return Err(
+                    JsonStorageError::TableNameDoesNotMatchWithFile(table_name.to_owned()).into(),
+                );
+            }
+
+            return Ok(Some(schema));
+        }
+
+        let schema = (has_json || has_jsonl).then(|| Schema {
+            table_name: table_name.to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        });
+
+        Ok(schema)
+    }
+
+    /// JSON objects stored in the data file of `table_name`.
+    fn read_objects(&self, table_name: &str) -> Result<Vec<JsonMap<String, JsonValue>>> {
+        let jsonl_path = self.jsonl_path(table_name);
+        let json_path = self.json_path(table_name);
+
+        let values = if jsonl_path.exists() {
+            fs::read_to_string(jsonl_path)
+                .map_storage_err()?
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(parse_json)
+                .collect::<Result<Vec<_>>>()?
+        } else if json_path.exists() {
+            match parse_json(&fs::read_to_string(json_path).map_storage_err()?)? {
+                JsonValue::Array(values) => values,
+                value => vec![value],
+            }
+        } else {
+            Vec::new()
+        };
+
+        values
+            .into_iter()
+            .map(|value| match value {
+                JsonValue::Object(object) => Ok(object),
+                value => Err(JsonStorageError::JsonObjectTypeRequired(value.to_string()).into()),
+            })
+            .collect()
+    }
+
+    /// Rows of `table_name` keyed by their position in the data file.
+    fn read_rows(&self, schema: &Schema) -> Result<Vec<(Key, DataRow)>> {
+        self.read_objects(&schema.table_name)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, object)| {
+                let row = match &schema.column_defs {
+                    Some(column_defs) => {
+                        let mut values = HashMap::try_from_json_map(object)?;
+                        let values = column_defs
+                            .iter()
+                            .map(|column_def| match values.remove(&column_def.name) {
+                                None | Some(Value::Null) => Ok(Value::Null),
+                                Some(value) => value.cast(&column_def.data_type),
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+
+                        DataRow::Vec(values)
+                    }
+                    None => DataRow::Map(HashMap::try_from_json_map(object)?),
+                };
+
+                Ok((Key::I64(i as i64), row))
+            })
+            .collect()
+    }
+}
+
+fn parse_json(content: &str) -> Result<JsonValue> {
+    serde_json::from_str(content)
+        .map_err(|e| JsonStorageError::InvalidJsonContent(e.to_string()).into())
+}
+
+impl Metadata for JsonStorage {}
+impl Index for JsonStorage {}
+impl IndexMut for JsonStorage {}
+impl Transaction for JsonStorage {}
+impl CustomFunction for JsonStorage {}
+impl CustomFunctionMut for JsonStorage {}
@@ -0,0 +1,182 @@
+use {
+    crate::{JsonStorage, ResultExt},
+    async_trait::async_trait,
+    futures::stream,
+    std::{collections::BTreeSet, ffi::OsStr, fs},
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl Store for JsonStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.read_schema(table_name)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut table_names = BTreeSet::new();
+        for entry in fs::read_dir(&self.path).map_storage_err()? {
+            let path = entry.map_storage_err()?.path();
+            let extension = path.extension().and_then(OsStr::to_str);
+            let table_name = path.file_stem().and_then(OsStr::to_str);
+
+            if let (Some("sql" | "json" | "jsonl"), Some(table_name)) = (extension, table_name) {
+                table_names.insert(table_name.to_owned());
+            }
+        }
+
+        let mut schemas = Vec::new();
+        for table_name in table_names {
+            schemas.extend(self.read_schema(&table_name)?);
+        }
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let Some(schema) = self.read_schema(table_name)? else {
+            return Ok(None);
+        };
+
+        let row = self
+            .read_rows(&schema)?
+            .into_iter()
+            .find_map(|(row_key, row)| (&row_key == key).then_some(row));
+
+        Ok(row)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        let rows = match self.read_schema(table_name)? {
+            Some(schema) => self.read_rows(&schema)?,
+            None => Vec::new(),
+        };
+
+        Ok(Box::pin(stream::iter(rows.into_iter().map(Ok))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::JsonStorage,
+        futures::{executor::block_on, TryStreamExt},
+        std::{collections::HashMap, fs},
+        wql_core::{
+            data::{Key, Value},
+            prelude::{Payload, Worm},
+            store::{DataRow, Store},
+        },
+    };
+
+    const TRANSFERS: &str = r#"{"hash": "0x01", "sender": "0xaa", "value": 10}
+{"hash": "0x02", "sender": "0xbb", "value": 20}
+
+{"hash": "0x03", "sender": "0xaa", "value": 30, "memo": "refund"}
+"#;
+
+    const ACCOUNTS: &str = r#"[
+    {"address": "0xaa", "label": "alice", "balance": "100"},
+    {"address": "0xbb"}
+]"#;
+
+    const ACCOUNTS_DDL: &str = r#"CREATE TABLE "Account" ("address" TEXT NOT NULL, "label" TEXT NULL, "balance" INT NULL);"#;
+
+    fn storage() -> (tempfile::TempDir, JsonStorage) {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("Transfer.jsonl"), TRANSFERS).unwrap();
+        fs::write(dir.path().join("Account.json"), ACCOUNTS).unwrap();
+        fs::write(dir.path().join("Account.sql"), ACCOUNTS_DDL).unwrap();
+        fs::write(dir.path().join("README.md"), "not a table").unwrap();
+
+        let storage = JsonStorage::new(dir.path()).unwrap();
+
+        (dir, storage)
+    }
+
+    #[test]
+    fn scan() {
+        let (_dir, storage) = storage();
+
+        let table_names = block_on(storage.fetch_all_schemas())
+            .unwrap()
+            .into_iter()
+            .map(|schema| (schema.table_name, schema.column_defs.is_some()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            table_names,
+            vec![("Account".to_owned(), true), ("Transfer".to_owned(), false)]
+        );
+
+        let rows: Vec<_> = block_on(async {
+            let rows = storage.scan_data("Account").await.unwrap();
+
+            rows.try_collect().await.unwrap()
+        });
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    Key::I64(0),
+                    DataRow::Vec(vec![
+                        Value::Str("0xaa".to_owned()),
+                        Value::Str("alice".to_owned()),
+                        Value::I64(100),
+                    ])
+                ),
+                (
+                    Key::I64(1),
+                    DataRow::Vec(vec![
+                        Value::Str("0xbb".to_owned()),
+                        Value::Null,
+                        Value::Null
+                    ])
+                ),
+            ]
+        );
+
+        assert_eq!(
+            block_on(storage.fetch_data("Transfer", &Key::I64(2))).unwrap(),
+            Some(DataRow::Map(HashMap::from([
+                ("hash".to_owned(), Value::Str("0x03".to_owned())),
+                ("sender".to_owned(), Value::Str("0xaa".to_owned())),
+                ("value".to_owned(), Value::I64(30)),
+                ("memo".to_owned(), Value::Str("refund".to_owned())),
+            ])))
+        );
+        assert_eq!(block_on(storage.fetch_schema("Missing")).unwrap(), None);
+
+        fs::write(storage.path.join("Transfer.json"), "[]").unwrap();
+        assert!(block_on(storage.fetch_schema("Transfer")).is_err());
+    }
+
+    #[test]
+    fn select() {
+        let (_dir, storage) = storage();
+        let mut worm = Worm::new(storage);
+
+        let payloads = block_on(worm.execute(
+            "SELECT Transfer.hash, Account.label FROM Transfer
+             JOIN Account ON Transfer.sender = Account.address
+             WHERE Transfer.value > 10",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            payloads,
+            vec![Payload::Select {
+                labels: vec!["hash".to_owned(), "label".to_owned()],
+                rows: vec![
+                    vec![Value::Str("0x02".to_owned()), Value::Null],
+                    vec![
+                        Value::Str("0x03".to_owned()),
+                        Value::Str("alice".to_owned())
+                    ],
+                ],
+            }]
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use {
+    crate::{JsonStorage, ResultExt},
+    async_trait::async_trait,
+    serde_json::{Map as JsonMap, Value as JsonValue},
+    std::{
+        collections::BTreeMap,
+        fs::{self, OpenOptions},
+        io::Write,
+        path::Path,
+    },
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+};
+
+impl JsonStorage {
+    fn to_json(schema: &Schema, row: DataRow) -> Result<JsonValue> {
+        let object = match (row, &schema.column_defs) {
+            (DataRow::Vec(values), Some(column_defs)) => column_defs
+                .iter()
+                .zip(values)
+                .map(|(column_def, value)| Ok((column_def.name.clone(), value.try_into()?)))
+                .collect::<Result<JsonMap<_, _>>>()?,
+            (DataRow::Vec(values), None) => values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| Ok((i.to_string(), value.try_into()?)))
+                .collect::<Result<JsonMap<_, _>>>()?,
+            (DataRow::Map(values), _) => values
+                .into_iter()
+                .map(|(key, value)| Ok((key, value.try_into()?)))
+                .collect::<Result<JsonMap<_, _>>>()?,
+        };
+
+        Ok(JsonValue::Object(object))
+    }
+
+    /// Replaces the data file of the table with `rows`, keeping its format.
+    fn write_rows(&self, schema: &Schema, rows: Vec<DataRow>) -> Result<()> {
+        let objects = rows
+            .into_iter()
+            .map(|row| Self::to_json(schema, row))
+            .collect::<Result<Vec<_>>>()?;
+
+        let json_path = self.json_path(&schema.table_name);
+        if json_path.exists() {
+            let content = serde_json::to_string_pretty(&objects).map_storage_err()?;
+
+            return fs::write(json_path, content).map_storage_err();
+        }
+
+        let content = objects
+            .iter()
+            .map(|object| format!("{object}\n"))
+            .collect::<String>();
+
+        fs::write(self.jsonl_path(&schema.table_name), content).map_storage_err()
+    }
+}
+
+fn remove_file(path: &Path) -> Result<()> {
+    match path.exists() {
+        true => fs::remove_file(path).map_storage_err(),
+        false => Ok(()),
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreMut for JsonStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let table_name = &schema.table_name;
+        if schema.column_defs.is_some() {
+            fs::write(self.schema_path(table_name), schema.to_ddl()).map_storage_err()?;
+        }
+
+        let jsonl_path = self.jsonl_path(table_name);
+        if !jsonl_path.exists() && !self.json_path(table_name).exists() {
+            fs::File::create(jsonl_path).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        remove_file(&self.schema_path(table_name))?;
+        remove_file(&self.json_path(table_name))?;
+        remove_file(&self.jsonl_path(table_name))
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let Some(schema) = self.read_schema(table_name)? else {
+            return Ok(());
+        };
+
+        if self.json_path(table_name).exists() {
+            let prev_rows = self.read_rows(&schema)?.into_iter().map(|(_, row)| row);
+            let rows = prev_rows.chain(rows).collect();
+
+            return self.write_rows(&schema, rows);
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.jsonl_path(table_name))
+            .map_storage_err()?;
+        for row in rows {
+            writeln!(file, "{}", Self::to_json(&schema, row)?).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let Some(schema) = self.read_schema(table_name)? else {
+            return Ok(());
+        };
+
+        let mut prev_rows = self
+            .read_rows(&schema)?
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        prev_rows.extend(rows);
+
+        self.write_rows(&schema, prev_rows.into_values().collect())
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let Some(schema) = self.read_schema(table_name)? else {
+            return Ok(());
+        };
+
+        let rows = self
+            .read_rows(&schema)?
+            .into_iter()
+            .filter(|(key, _)| !keys.contains(key))
+            .map(|(_, row)| row)
+            .collect();
+
+        self.write_rows(&schema, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::JsonStorage,
+        futures::{executor::block_on, TryStreamExt},
+        std::{collections::HashMap, fs},
+        wql_core::{
+            ast::{ColumnDef, DataType},
+            data::{Key, Schema, Value},
+            store::{DataRow, Store, StoreMut},
+        },
+    };
+
+    fn scan(storage: &JsonStorage, table_name: &str) -> Vec<(Key, DataRow)> {
+        block_on(async {
+            let rows = storage.scan_data(table_name).await.unwrap();
+
+            rows.try_collect().await.unwrap()
+        })
+    }
+
+    #[test]
+    fn write() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut storage = JsonStorage::new(dir.path()).unwrap();
+        let schema = Schema {
+            table_name: "Block".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "number".to_owned(),
+                data_type: DataType::Uint64,
+                nullable: false,
+                default: None,
+                comment: None,
+            }]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        };
+        let row = |number| DataRow::Vec(vec![Value::U64(number)]);
+
+        block_on(async {
+            storage.insert_schema(&schema).await.unwrap();
+            storage
+                .append_data("Block", vec![row(1), row(2)])
+                .await
+                .unwrap();
+            storage.append_data("Block", vec![row(3)]).await.unwrap();
+            storage
+                .insert_data("Block", vec![(Key::I64(1), row(20))])
+                .await
+                .unwrap();
+            storage
+                .delete_data("Block", vec![Key::I64(0)])
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Block.jsonl")).unwrap(),
+            "{\"number\":20}\n{\"number\":3}\n"
+        );
+        assert_eq!(
+            block_on(storage.fetch_schema("Block")).unwrap(),
+            Some(schema)
+        );
+
+        fs::write(dir.path().join("Log.json"), r#"[{"id": 1}]"#).unwrap();
+        block_on(storage.append_data(
+            "Log",
+            vec![DataRow::Map(HashMap::from([(
+                "id".to_owned(),
+                Value::I64(2),
+            )]))],
+        ))
+        .unwrap();
+        assert_eq!(scan(&storage, "Log").len(), 2);
+        assert!(!dir.path().join("Log.jsonl").exists());
+
+        block_on(storage.delete_schema("Block")).unwrap();
+        assert_eq!(block_on(storage.fetch_schema("Block")).unwrap(), None);
+        assert!(!dir.path().join("Block.sql").exists());
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    super::{MemoryStorage, Undo},
+    async_trait::async_trait,
+    wql_core::{
+        data::CustomFunction as StructCustomFunction,
+        error::Result,
+        store::{CustomFunction, CustomFunctionMut},
+    },
+};
+
+#[async_trait(?Send)]
+impl CustomFunction for MemoryStorage {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        Ok(self.functions.get(&func_name.to_uppercase()))
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        let mut functions = self.functions.values().collect::<Vec<_>>();
+        functions.sort_by(|a, b| a.func_name.cmp(&b.func_name));
+
+        Ok(functions)
+    }
+}
+
+#[async_trait(?Send)]
+impl CustomFunctionMut for MemoryStorage {
+    async fn insert_function(&mut self, func: StructCustomFunction) -> Result<()> {
+        let func_name = func.func_name.to_uppercase();
+        let old_func = self.functions.insert(func_name.clone(), func);
+        self.undo.push(|| Undo::Function {
+            func_name,
+            func: old_func,
+        });
+
+        Ok(())
+    }
+
+    async fn delete_function(&mut self, func_name: &str) -> Result<()> {
+        let func_name = func_name.to_uppercase();
+        let func = self.functions.remove(&func_name);
+        self.undo.push(|| Undo::Function { func_name, func });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use {
+    super::{Item, MemoryStorage, Undo},
+    async_trait::async_trait,
+    futures::stream::{self, StreamExt},
+    std::collections::{BTreeSet, HashMap},
+    wql_core::{
+        ast::{IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{Key, SchemaIndex, SchemaIndexOrd, Value},
+        error::Result,
+        store::{index_value, DataRow, Index, IndexError, IndexMut, Predicate, RowIter},
+    },
+};
+
+impl Item {
+    /// Entries of `index` for every row of the table.
+    pub(crate) async fn index_entries(&self, index: &SchemaIndex) -> Result<BTreeSet<(Key, Key)>> {
+        let mut entries = BTreeSet::new();
+        for (key, row) in &self.rows {
+            let value = index_value(&self.schema, index, row).await?;
+
+            entries.insert((Key::try_from(value)?, key.clone()));
+        }
+
+        Ok(entries)
+    }
+
+    /// Rebuilds every index of the table from its rows.
+    pub(crate) async fn reindex(&mut self) -> Result<()> {
+        let mut indexes = HashMap::new();
+        for index in &self.schema.indexes {
+            indexes.insert(index.name.clone(), self.index_entries(index).await?);
+        }
+
+        self.indexes = indexes;
+
+        Ok(())
+    }
+
+    /// Adds `row` stored at `key` to every index of the table.
+    pub(crate) async fn index_row(&mut self, key: &Key, row: &DataRow) -> Result<()> {
+        for index in &self.schema.indexes {
+            let value = index_value(&self.schema, index, row).await?;
+
+            self.indexes
+                .entry(index.name.clone())
+                .or_default()
+                .insert((Key::try_from(value)?, key.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Removes `row` stored at `key` from every index of the table.
+    pub(crate) async fn unindex_row(&mut self, key: &Key, row: &DataRow) -> Result<()> {
+        for index in &self.schema.indexes {
+            let value = index_value(&self.schema, index, row).await?;
+
+            if let Some(entries) = self.indexes.get_mut(&index.name) {
+                entries.remove(&(Key::try_from(value)?, key.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl Index for MemoryStorage {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        let item = self
+            .items
+            .get(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+        let entries = item
+            .indexes
+            .get(index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+        let predicate = cmp_value.map(|(op, value)| Predicate {
+            column: index_name.to_owned(),
+            op: op.clone(),
+            value,
+        });
+
+        let entries = entries
+            .iter()
+            .filter(|(value, _)| match &predicate {
+                Some(predicate) => predicate.check(&Value::from(value.clone())) == Some(true),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        // Rows of an equal value stay ordered by key either way.
+        let entries: Vec<_> = match asc {
+            Some(false) => entries
+                .chunk_by(|(a, _), (b, _)| a == b)
+                .rev()
+                .flatten()
+                .copied()
+                .collect(),
+            _ => entries,
+        };
+        let rows = entries
+            .into_iter()
+            .filter_map(|(_, key)| item.rows.get(key).map(|row| Ok((key.clone(), row.clone()))))
+            .collect::<Vec<_>>();
+
+        Ok(stream::iter(rows).boxed_local())
+    }
+}
+
+#[async_trait(?Send)]
+impl IndexMut for MemoryStorage {
+    async fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if item
+            .schema
+            .indexes
+            .iter()
+            .any(|index| index.name == index_name)
+        {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let order = match column.asc {
+            Some(true) => SchemaIndexOrd::Asc,
+            Some(false) => SchemaIndexOrd::Desc,
+            None => SchemaIndexOrd::Both,
+        };
+
+        let index = SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order,
+            created: Utc::now().naive_utc(),
+        };
+        let entries = item.index_entries(&index).await?;
+
+        item.schema.indexes.push(index);
+        item.indexes.insert(index_name.to_owned(), entries);
+        self.undo.push(|| Undo::Index {
+            table_name: table_name.to_owned(),
+            index_name: index_name.to_owned(),
+            index: None,
+        });
+
+        Ok(())
+    }
+
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+        let indexes = &mut item.schema.indexes;
+        let position = indexes
+            .iter()
+            .position(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let index = indexes.remove(position);
+        item.indexes.remove(index_name);
+        self.undo.push(|| Undo::Index {
+            table_name: table_name.to_owned(),
+            index_name: index_name.to_owned(),
+            index: Some((position, index)),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            tests::{row, schema},
+            MemoryStorage,
+        },
+        futures::{executor::block_on, TryStreamExt},
+        wql_core::{
+            ast::{Expr, IndexOperator, OrderByExpr},
+            data::{Key, Value},
+            store::{Index, IndexMut, Store, StoreMut},
+        },
+    };
+
+    fn scan(
+        storage: &MemoryStorage,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Vec<Key> {
+        block_on(async {
+            storage
+                .scan_indexed_data("Foo", "idx_name", asc, cmp_value)
+                .await
+                .unwrap()
+                .map_ok(|(key, _)| key)
+                .try_collect()
+                .await
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn index() {
+        let mut storage = MemoryStorage::default();
+        let column = OrderByExpr {
+            expr: Expr::Identifier("name".to_owned()),
+            asc: None,
+        };
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage
+                .append_data("Foo", vec![row(1, "c"), row(2, "a"), row(3, "b")])
+                .await
+                .unwrap();
+            storage
+                .create_index("Foo", "idx_name", &column)
+                .await
+                .unwrap();
+
+            assert!(storage
+                .create_index("Foo", "idx_name", &column)
+                .await
+                .is_err());
+            assert!(storage
+                .create_index("Bar", "idx_name", &column)
+                .await
+                .is_err());
+        });
+
+        let schema = block_on(storage.fetch_schema("Foo")).unwrap().unwrap();
+        assert_eq!(schema.indexes.len(), 1);
+
+        assert_eq!(
+            scan(&storage, None, None),
+            vec![Key::I64(2), Key::I64(3), Key::I64(1)]
+        );
+        assert_eq!(
+            scan(&storage, Some(false), None),
+            vec![Key::I64(1), Key::I64(3), Key::I64(2)]
+        );
+        assert_eq!(
+            scan(
+                &storage,
+                None,
+                Some((&IndexOperator::GtEq, Value::Str("b".to_owned())))
+            ),
+            vec![Key::I64(3), Key::I64(1)]
+        );
+
+        block_on(async {
+            storage
+                .insert_data("Foo", vec![(Key::I64(1), row(1, "a"))])
+                .await
+                .unwrap();
+            storage.delete_data("Foo", vec![Key::I64(3)]).await.unwrap();
+            storage.append_data("Foo", vec![row(4, "b")]).await.unwrap();
+        });
+        assert_eq!(
+            scan(&storage, None, None),
+            vec![Key::I64(1), Key::I64(2), Key::I64(4)]
+        );
+
+        block_on(storage.drop_index("Foo", "idx_name")).unwrap();
+        assert!(block_on(storage.drop_index("Foo", "idx_name")).is_err());
+        assert!(block_on(storage.scan_indexed_data("Foo", "idx_name", None, None)).is_err());
+    }
+}
@@ -0,0 +1,287 @@
+#![deny(clippy::str_to_string)]
+
+mod function;
+mod index;
+mod metadata;
+mod transaction;
+
+use {
+    async_trait::async_trait,
+    futures::stream::{self, StreamExt},
+    std::collections::{BTreeMap, BTreeSet, HashMap},
+    transaction::{Undo, UndoLog},
+    wql_core::{
+        chrono::Utc,
+        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        error::Result,
+        store::{DataRow, RowIter, Store, StoreMut},
+    },
+};
+
+/// Schema of a table along with its rows, ordered by key.
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub schema: Schema,
+    pub rows: BTreeMap<Key, DataRow>,
+    /// Entries of each index by name, a pair of the indexed value and the key of the row, kept
+    /// in step with the rows so that scanning an index needs no sort.
+    pub indexes: HashMap<String, BTreeSet<(Key, Key)>>,
+}
+
+/// Storage which keeps every table in memory, dropped along with the storage.
+///
+/// Transactions log the entries each write overwrites, which `ROLLBACK` restores, and so does
+/// every statement run outside of one, rolled back when the statement fails.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    pub id_counter: i64,
+    pub items: HashMap<String, Item>,
+    pub metadata: HashMap<String, HashMap<String, Value>>,
+    pub functions: HashMap<String, StructCustomFunction>,
+    undo: UndoLog,
+}
+
+impl MemoryStorage {
+    /// Rows of `table_name`, empty when the table does not exist.
+    fn rows(&self, table_name: &str) -> Vec<(Key, DataRow)> {
+        self.items
+            .get(table_name)
+            .map(|item| {
+                item.rows
+                    .iter()
+                    .map(|(key, row)| (key.clone(), row.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait(?Send)]
+impl Store for MemoryStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        Ok(self.items.get(table_name).map(|item| item.schema.clone()))
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut schemas = self
+            .items
+            .values()
+            .map(|item| item.schema.clone())
+            .collect::<Vec<_>>();
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let row = self
+            .items
+            .get(table_name)
+            .and_then(|item| item.rows.get(key).cloned());
+
+        Ok(row)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        let rows = self.rows(table_name).into_iter().map(Ok);
+
+        Ok(stream::iter(rows).boxed_local())
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreMut for MemoryStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let table_name = schema.table_name.clone();
+        self.log_table(&table_name);
+
+        let created = HashMap::from([(
+            "CREATED".to_owned(),
+            Value::Timestamp(Utc::now().naive_utc()),
+        )]);
+        let rows = self
+            .items
+            .remove(&table_name)
+            .map(|item| item.rows)
+            .unwrap_or_default();
+
+        let mut item = Item {
+            schema: schema.clone(),
+            rows,
+            indexes: HashMap::new(),
+        };
+        item.reindex().await?;
+
+        self.metadata.entry(table_name.clone()).or_insert(created);
+        self.items.insert(table_name, item);
+
+        Ok(())
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let item = self.items.remove(table_name).map(Box::new);
+        let metadata = self.metadata.remove(table_name);
+
+        self.undo.push(|| Undo::Table {
+            table_name: table_name.to_owned(),
+            item,
+            metadata,
+        });
+
+        Ok(())
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        if let Some(item) = self.items.get_mut(table_name) {
+            for row in rows {
+                self.id_counter += 1;
+
+                let key = Key::I64(self.id_counter);
+                self.undo.push(|| Undo::Row {
+                    table_name: table_name.to_owned(),
+                    key: key.clone(),
+                    row: None,
+                });
+
+                item.index_row(&key, &row).await?;
+                item.rows.insert(key, row);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        if let Some(item) = self.items.get_mut(table_name) {
+            for (key, row) in rows {
+                let old_row = item.rows.remove(&key);
+                self.undo.push(|| Undo::Row {
+                    table_name: table_name.to_owned(),
+                    key: key.clone(),
+                    row: old_row.clone(),
+                });
+
+                if let Some(old_row) = old_row {
+                    item.unindex_row(&key, &old_row).await?;
+                }
+
+                item.index_row(&key, &row).await?;
+                item.rows.insert(key, row);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        if let Some(item) = self.items.get_mut(table_name) {
+            for key in keys {
+                let Some(row) = item.rows.remove(&key) else {
+                    continue;
+                };
+
+                item.unindex_row(&key, &row).await?;
+                self.undo.push(|| Undo::Row {
+                    table_name: table_name.to_owned(),
+                    key,
+                    row: Some(row),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::MemoryStorage,
+        futures::{executor::block_on, TryStreamExt},
+        wql_core::{
+            ast::{ColumnDef, DataType},
+            data::{Key, Schema, Value},
+            store::{DataRow, Store, StoreMut},
+        },
+    };
+
+    pub fn schema(table_name: &str) -> Schema {
+        let column = |name: &str, data_type| ColumnDef {
+            name: name.to_owned(),
+            data_type,
+            nullable: true,
+            default: None,
+            comment: None,
+        };
+
+        Schema {
+            table_name: table_name.to_owned(),
+            column_defs: Some(vec![
+                column("id", DataType::Int),
+                column("name", DataType::Text),
+            ]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        }
+    }
+
+    pub fn row(id: i64, name: &str) -> DataRow {
+        DataRow::Vec(vec![Value::I64(id), Value::Str(name.to_owned())])
+    }
+
+    pub fn scan(storage: &MemoryStorage, table_name: &str) -> Vec<(Key, DataRow)> {
+        block_on(async {
+            storage
+                .scan_data(table_name)
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn store() {
+        let mut storage = MemoryStorage::default();
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage.insert_schema(&schema("Bar")).await.unwrap();
+            storage
+                .append_data("Foo", vec![row(1, "a"), row(2, "b")])
+                .await
+                .unwrap();
+            storage
+                .insert_data("Foo", vec![(Key::I64(2), row(2, "c"))])
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            scan(&storage, "Foo"),
+            vec![(Key::I64(1), row(1, "a")), (Key::I64(2), row(2, "c"))]
+        );
+        assert_eq!(scan(&storage, "Missing"), Vec::new());
+        assert_eq!(
+            block_on(storage.fetch_data("Foo", &Key::I64(1))).unwrap(),
+            Some(row(1, "a"))
+        );
+
+        let table_names = block_on(storage.fetch_all_schemas())
+            .unwrap()
+            .into_iter()
+            .map(|schema| schema.table_name)
+            .collect::<Vec<_>>();
+        assert_eq!(table_names, vec!["Bar", "Foo"]);
+
+        block_on(storage.delete_data("Foo", vec![Key::I64(1)])).unwrap();
+        assert_eq!(scan(&storage, "Foo"), vec![(Key::I64(2), row(2, "c"))]);
+
+        block_on(storage.delete_schema("Foo")).unwrap();
+        assert_eq!(block_on(storage.fetch_schema("Foo")).unwrap(), None);
+        assert!(!storage.metadata.contains_key("Foo"));
+    }
+}
@@ -0,0 +1,310 @@
+use {
+    super::{Item, MemoryStorage},
+    async_trait::async_trait,
+    std::collections::HashMap,
+    wql_core::{
+        data::{CustomFunction as StructCustomFunction, Key, SchemaIndex, Value},
+        error::{Error, Result},
+        store::{DataRow, Transaction},
+    },
+};
+
+/// Entry overwritten in a transaction, restored on `ROLLBACK`.
+#[derive(Debug, Clone)]
+pub(crate) enum Undo {
+    IdCounter(i64),
+    /// Row `key` of `table_name`, `None` when it was missing.
+    Row {
+        table_name: String,
+        key: Key,
+        row: Option<DataRow>,
+    },
+    /// Table `table_name` along with its metadata, `None` when it was missing.
+    Table {
+        table_name: String,
+        item: Option<Box<Item>>,
+        metadata: Option<HashMap<String, Value>>,
+    },
+    /// Index `index_name` of `table_name` along with its position, `None` when it was missing.
+    Index {
+        table_name: String,
+        index_name: String,
+        index: Option<(usize, SchemaIndex)>,
+    },
+    /// Function `func_name`, `None` when it was missing.
+    Function {
+        func_name: String,
+        func: Option<StructCustomFunction>,
+    },
+}
+
+/// Entries overwritten in the current transaction, in the order they were, `None` outside of a
+/// transaction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UndoLog(Option<Vec<Undo>>);
+
+impl UndoLog {
+    /// Records the entry `undo` builds, only built within a transaction.
+    pub(crate) fn push(&mut self, undo: impl FnOnce() -> Undo) {
+        if let Some(entries) = &mut self.0 {
+            entries.push(undo());
+        }
+    }
+}
+
+impl MemoryStorage {
+    /// Records the table `table_name` as it is before it gets replaced or dropped.
+    pub(crate) fn log_table(&mut self, table_name: &str) {
+        let Self {
+            items,
+            metadata,
+            undo,
+            ..
+        } = self;
+
+        undo.push(|| Undo::Table {
+            table_name: table_name.to_owned(),
+            item: items.get(table_name).cloned().map(Box::new),
+            metadata: metadata.get(table_name).cloned(),
+        });
+    }
+
+    async fn restore(&mut self, undo: Undo) -> Result<()> {
+        match undo {
+            Undo::IdCounter(id_counter) => {
+                self.id_counter = id_counter;
+            }
+            Undo::Row {
+                table_name,
+                key,
+                row,
+            } => {
+                let Some(item) = self.items.get_mut(&table_name) else {
+                    return Ok(());
+                };
+
+                if let Some(current) = item.rows.remove(&key) {
+                    item.unindex_row(&key, &current).await?;
+                }
+
+                if let Some(row) = row {
+                    item.index_row(&key, &row).await?;
+                    item.rows.insert(key, row);
+                }
+            }
+            Undo::Table {
+                table_name,
+                item,
+                metadata,
+            } => {
+                match item {
+                    Some(item) => self.items.insert(table_name.clone(), *item),
+                    None => self.items.remove(&table_name),
+                };
+
+                match metadata {
+                    Some(metadata) => self.metadata.insert(table_name, metadata),
+                    None => self.metadata.remove(&table_name),
+                };
+            }
+            Undo::Index {
+                table_name,
+                index_name,
+                index,
+            } => {
+                let Some(item) = self.items.get_mut(&table_name) else {
+                    return Ok(());
+                };
+
+                item.schema.indexes.retain(|index| index.name != index_name);
+                item.indexes.remove(&index_name);
+
+                if let Some((position, index)) = index {
+                    let entries = item.index_entries(&index).await?;
+
+                    item.schema.indexes.insert(position, index);
+                    item.indexes.insert(index_name, entries);
+                }
+            }
+            Undo::Function { func_name, func } => {
+                match func {
+                    Some(func) => self.functions.insert(func_name, func),
+                    None => self.functions.remove(&func_name),
+                };
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A transaction logs the entries each write overwrites, which `ROLLBACK` restores in reverse.
+///
+/// Outside of an explicit transaction, each statement runs in an autocommit transaction of its
+/// own, so that a failing statement leaves none of its writes behind.
+#[async_trait(?Send)]
+impl Transaction for MemoryStorage {
+    async fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        match (autocommit, self.undo.0.is_some()) {
+            (true, true) => return Ok(false),
+            (false, true) => {
+                return Err(Error::StorageMsg(
+                    "[MemoryStorage] nested transaction is not supported".to_owned(),
+                ));
+            }
+            (_, false) => {}
+        }
+
+        self.undo = UndoLog(Some(vec![Undo::IdCounter(self.id_counter)]));
+
+        Ok(autocommit)
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        let UndoLog(Some(entries)) = std::mem::take(&mut self.undo) else {
+            return Ok(());
+        };
+
+        for undo in entries.into_iter().rev() {
+            self.restore(undo).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        self.undo = UndoLog::default();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            tests::{row, scan, schema},
+            MemoryStorage,
+        },
+        futures::{executor::block_on, TryStreamExt},
+        wql_core::{
+            ast::{AstLiteral, Expr, OrderByExpr},
+            data::{CustomFunction as StructCustomFunction, Key},
+            store::{
+                CustomFunction, CustomFunctionMut, Index, IndexMut, Store, StoreMut, Transaction,
+            },
+        },
+    };
+
+    #[test]
+    fn rollback() {
+        let mut storage = MemoryStorage::default();
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage.append_data("Foo", vec![row(1, "a")]).await.unwrap();
+
+            storage.begin(false).await.unwrap();
+            assert!(storage.begin(false).await.is_err());
+            storage.append_data("Foo", vec![row(2, "b")]).await.unwrap();
+            storage.insert_schema(&schema("Bar")).await.unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(scan(&storage, "Foo"), vec![(Key::I64(1), row(1, "a"))]);
+        assert_eq!(block_on(storage.fetch_schema("Bar")).unwrap(), None);
+
+        block_on(async {
+            storage.begin(false).await.unwrap();
+            storage.append_data("Foo", vec![row(2, "b")]).await.unwrap();
+            storage.commit().await.unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(
+            scan(&storage, "Foo"),
+            vec![(Key::I64(1), row(1, "a")), (Key::I64(2), row(2, "b"))]
+        );
+    }
+
+    #[test]
+    fn rollback_overwritten_entries() {
+        let mut storage = MemoryStorage::default();
+        let column = |name: &str| OrderByExpr {
+            expr: Expr::Identifier(name.to_owned()),
+            asc: None,
+        };
+        let function = |func_name: &str| StructCustomFunction {
+            func_name: func_name.to_owned(),
+            args: Vec::new(),
+            body: Expr::Literal(AstLiteral::Null),
+        };
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage
+                .append_data("Foo", vec![row(1, "b"), row(2, "a")])
+                .await
+                .unwrap();
+            storage
+                .create_index("Foo", "idx_name", &column("name"))
+                .await
+                .unwrap();
+            storage.insert_function(function("F")).await.unwrap();
+
+            storage.begin(false).await.unwrap();
+            storage
+                .insert_data("Foo", vec![(Key::I64(2), row(2, "c"))])
+                .await
+                .unwrap();
+            storage.delete_data("Foo", vec![Key::I64(1)]).await.unwrap();
+            storage.append_data("Foo", vec![row(3, "d")]).await.unwrap();
+            storage.drop_index("Foo", "idx_name").await.unwrap();
+            storage
+                .create_index("Foo", "idx_id", &column("id"))
+                .await
+                .unwrap();
+            storage.delete_function("F").await.unwrap();
+            storage.insert_function(function("G")).await.unwrap();
+            storage.delete_schema("Foo").await.unwrap();
+            storage.insert_schema(&schema("Bar")).await.unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(
+            scan(&storage, "Foo"),
+            vec![(Key::I64(1), row(1, "b")), (Key::I64(2), row(2, "a"))]
+        );
+        assert_eq!(storage.id_counter, 2);
+        assert!(storage.metadata.contains_key("Foo"));
+        assert_eq!(block_on(storage.fetch_schema("Bar")).unwrap(), None);
+
+        let indexes = block_on(storage.fetch_schema("Foo"))
+            .unwrap()
+            .unwrap()
+            .indexes
+            .into_iter()
+            .map(|index| index.name)
+            .collect::<Vec<_>>();
+        assert_eq!(indexes, vec!["idx_name"]);
+
+        let keys = block_on(async {
+            storage
+                .scan_indexed_data("Foo", "idx_name", None, None)
+                .await
+                .unwrap()
+                .map_ok(|(key, _)| key)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+        });
+        assert_eq!(keys, vec![Key::I64(2), Key::I64(1)]);
+
+        let functions = block_on(storage.fetch_all_functions())
+            .unwrap()
+            .into_iter()
+            .map(|func| func.func_name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(functions, vec!["F"]);
+    }
+}
@@ -0,0 +1,198 @@
+#![deny(clippy::str_to_string)]
+
+mod error;
+mod store;
+mod value;
+
+pub use error::ResultExt;
+
+use {
+    parquet::arrow::{
+        arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder},
+        ProjectionMask,
+    },
+    std::{
+        cmp::Ordering,
+        fs::{self, File},
+        path::{Path, PathBuf},
+    },
+    wql_core::{
+        ast::{ColumnDef, IndexOperator},
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata, Predicate,
+            StoreMut, Transaction,
+        },
+    },
+};
+
+/// Read-only storage serving every `<table>.parquet` file of a directory as a table.
+///
+/// Scans decode only the columns a query reads, and skip the row groups whose statistics
+/// show that no row can satisfy the predicates of the `WHERE` clause. Rows are keyed by their
+/// position in the file.
+#[derive(Debug, Clone)]
+pub struct ParquetStorage {
+    pub path: PathBuf,
+}
+
+/// Opened file of a table along with its metadata.
+struct Table {
+    file: File,
+    metadata: ArrowReaderMetadata,
+}
+
+impl ParquetStorage {
+    pub fn new<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let path = path.as_ref();
+        fs::create_dir_all(path).map_storage_err()?;
+
+        Ok(Self { path: path.into() })
+    }
+
+    fn data_path(&self, table_name: &str) -> PathBuf {
+        self.path.join(format!("{table_name}.parquet"))
+    }
+
+    /// Opens the file of `table_name`, `None` when the directory holds no such file.
+    fn open(&self, table_name: &str) -> Result<Option<Table>> {
+        let path = self.data_path(table_name);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let file = File::open(path).map_storage_err()?;
+        let metadata =
+            ArrowReaderMetadata::load(&file, ArrowReaderOptions::default()).map_storage_err()?;
+
+        Ok(Some(Table { file, metadata }))
+    }
+}
+
+impl Table {
+    fn schema(&self, table_name: &str) -> Schema {
+        let column_defs = self
+            .metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| ColumnDef {
+                name: field.name().clone(),
+                data_type: value::data_type(field.data_type()),
+                nullable: field.is_nullable(),
+                default: None,
+                comment: None,
+            })
+            .collect();
+
+        Schema {
+            table_name: table_name.to_owned(),
+            column_defs: Some(column_defs),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        }
+    }
+
+    /// Indexes of the row groups which may hold rows satisfying every predicate, along with
+    /// the position of their first row.
+    fn row_groups(&self, predicates: &[Predicate]) -> Vec<(usize, i64)> {
+        let fields = self.metadata.schema().fields();
+        let mut offset = 0;
+
+        self.metadata
+            .metadata()
+            .row_groups()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row_group)| {
+                let first = offset;
+                offset += row_group.num_rows();
+
+                let pruned = predicates.iter().any(|predicate| {
+                    let data_type = fields
+                        .iter()
+                        .find(|field| field.name() == &predicate.column)
+                        .map(|field| field.data_type());
+                    let column = row_group
+                        .columns()
+                        .iter()
+                        .find(|column| column.column_path().string() == predicate.column);
+
+                    match (column, data_type) {
+                        (Some(column), Some(data_type)) => value::min_max(column, data_type)
+                            .is_some_and(|(min, max)| !may_satisfy(predicate, &min, &max)),
+                        _ => false,
+                    }
+                });
+
+                (!pruned).then_some((i, first))
+            })
+            .collect()
+    }
+
+    /// Rows of the row group `i` starting at `offset`, only the columns at `indexes` being
+    /// read and the others left `NULL`.
+    fn read_row_group(
+        &self,
+        i: usize,
+        offset: i64,
+        indexes: &[usize],
+    ) -> Result<Vec<(Key, DataRow)>> {
+        let mask = ProjectionMask::roots(
+            self.metadata.metadata().file_metadata().schema_descr(),
+            indexes.iter().copied(),
+        );
+        let file = self.file.try_clone().map_storage_err()?;
+        let reader =
+            ParquetRecordBatchReaderBuilder::new_with_metadata(file, self.metadata.clone())
+                .with_projection(mask)
+                .with_row_groups(vec![i])
+                .build()
+                .map_storage_err()?;
+        let width = self.metadata.schema().fields().len();
+
+        let mut rows = Vec::new();
+        for batch in reader {
+            let batch = batch.map_storage_err()?;
+
+            for row in 0..batch.num_rows() {
+                let mut values = vec![Value::Null; width];
+                for (column, index) in batch.columns().iter().zip(indexes) {
+                    values[*index] = value::value(column.as_ref(), row)?;
+                }
+
+                let key = Key::I64(offset + rows.len() as i64);
+                rows.push((key, DataRow::Vec(values)));
+            }
+        }
+
+        Ok(rows)
+    }
+}
+
+/// Whether a column whose values lie within `min..=max` may satisfy `predicate`.
+fn may_satisfy(predicate: &Predicate, min: &Value, max: &Value) -> bool {
+    let min = min.evaluate_cmp(&predicate.value);
+    let max = max.evaluate_cmp(&predicate.value);
+
+    match predicate.op {
+        IndexOperator::Eq => {
+            !matches!(min, Some(Ordering::Greater)) && !matches!(max, Some(Ordering::Less))
+        }
+        IndexOperator::Gt => !matches!(max, Some(Ordering::Less | Ordering::Equal)),
+        IndexOperator::GtEq => !matches!(max, Some(Ordering::Less)),
+        IndexOperator::Lt => !matches!(min, Some(Ordering::Greater | Ordering::Equal)),
+        IndexOperator::LtEq => !matches!(min, Some(Ordering::Greater)),
+    }
+}
+
+impl StoreMut for ParquetStorage {}
+impl Metadata for ParquetStorage {}
+impl Index for ParquetStorage {}
+impl IndexMut for ParquetStorage {}
+impl Transaction for ParquetStorage {}
+impl CustomFunction for ParquetStorage {}
+impl CustomFunctionMut for ParquetStorage {}
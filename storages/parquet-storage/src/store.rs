@@ -0,0 +1,301 @@
+use {
+    crate::{ParquetStorage, ResultExt},
+    async_trait::async_trait,
+    futures::stream,
+    std::{ffi::OsStr, fs},
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, Predicate, RowIter, Store},
+    },
+};
+
+impl ParquetStorage {
+    /// Rows of `table_name` in the row groups which may satisfy `predicates`, only `columns`
+    /// being read when given.
+    fn scan(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[Predicate],
+    ) -> Result<RowIter<'_>> {
+        let Some(table) = self.open(table_name)? else {
+            return Ok(Box::pin(stream::empty()));
+        };
+
+        let indexes = table
+            .metadata
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| columns.is_none_or(|columns| columns.contains(field.name())))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let row_groups = table.row_groups(predicates);
+        let rows = row_groups.into_iter().flat_map(move |(i, offset)| {
+            match table.read_row_group(i, offset, &indexes) {
+                Ok(rows) => rows.into_iter().map(Ok).collect(),
+                Err(error) => vec![Err(error)],
+            }
+        });
+
+        Ok(Box::pin(stream::iter(rows)))
+    }
+}
+
+#[async_trait(?Send)]
+impl Store for ParquetStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema = self.open(table_name)?.map(|table| table.schema(table_name));
+
+        Ok(schema)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut table_names = Vec::new();
+        for entry in fs::read_dir(&self.path).map_storage_err()? {
+            let path = entry.map_storage_err()?.path();
+            let extension = path.extension().and_then(OsStr::to_str);
+            let table_name = path.file_stem().and_then(OsStr::to_str);
+
+            if let (Some("parquet"), Some(table_name)) = (extension, table_name) {
+                table_names.push(table_name.to_owned());
+            }
+        }
+        table_names.sort();
+
+        let mut schemas = Vec::new();
+        for table_name in table_names {
+            schemas.extend(self.fetch_schema(&table_name).await?);
+        }
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let (Key::I64(position), Some(table)) = (key, self.open(table_name)?) else {
+            return Ok(None);
+        };
+
+        let Some((i, offset)) = table
+            .row_groups(&[])
+            .into_iter()
+            .take_while(|(_, offset)| offset <= position)
+            .last()
+        else {
+            return Ok(None);
+        };
+
+        let indexes = (0..table.metadata.schema().fields().len()).collect::<Vec<_>>();
+        let row = table
+            .read_row_group(i, offset, &indexes)?
+            .into_iter()
+            .find_map(|(row_key, row)| (&row_key == key).then_some(row));
+
+        Ok(row)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        self.scan(table_name, None, &[])
+    }
+
+    async fn scan_data_with_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let rows = self.scan(table_name, None, predicates)?;
+
+        Ok((rows, vec![false; predicates.len()]))
+    }
+
+    async fn scan_data_with_projection(
+        &self,
+        table_name: &str,
+        columns: &[String],
+        predicates: &[Predicate],
+    ) -> Result<(RowIter<'_>, Vec<bool>)> {
+        let rows = self.scan(table_name, Some(columns), predicates)?;
+
+        Ok((rows, vec![false; predicates.len()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::ParquetStorage,
+        arrow::{
+            array::{ArrayRef, Int64Array, ListBuilder, StringArray, StringBuilder, UInt64Array},
+            record_batch::RecordBatch,
+        },
+        futures::{executor::block_on, TryStreamExt},
+        parquet::{arrow::ArrowWriter, file::properties::WriterProperties},
+        std::{fs::File, sync::Arc},
+        wql_core::{
+            ast::{DataType, IndexOperator},
+            data::{Key, Value},
+            prelude::{Payload, Worm},
+            store::{DataRow, Predicate, RowIter, Store},
+        },
+    };
+
+    /// Six blocks written in row groups of two.
+    fn storage() -> (tempfile::TempDir, ParquetStorage) {
+        let dir = tempfile::tempdir().unwrap();
+        let numbers = UInt64Array::from(vec![1, 2, 3, 4, 5, 6]);
+        let miners = StringArray::from(vec!["0xaa", "0xbb", "0xaa", "0xcc", "0xbb", "0xaa"]);
+        let gas_used = Int64Array::from(vec![Some(21000), None, Some(42000), Some(0), None, None]);
+        let mut topics = ListBuilder::new(StringBuilder::new());
+        for i in 0..6 {
+            topics.values().append_value(format!("0x{i}"));
+            topics.append(true);
+        }
+        let batch = RecordBatch::try_from_iter([
+            ("number", Arc::new(numbers) as ArrayRef),
+            ("miner", Arc::new(miners) as ArrayRef),
+            ("gas_used", Arc::new(gas_used) as ArrayRef),
+            ("topics", Arc::new(topics.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let file = File::create(dir.path().join("Block.parquet")).unwrap();
+        let properties = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(properties)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let storage = ParquetStorage::new(dir.path()).unwrap();
+
+        (dir, storage)
+    }
+
+    fn collect(rows: RowIter<'_>) -> Vec<(Key, DataRow)> {
+        block_on(rows.try_collect()).unwrap()
+    }
+
+    fn number(op: IndexOperator, value: i64) -> Predicate {
+        Predicate {
+            column: "number".to_owned(),
+            op,
+            value: Value::I64(value),
+        }
+    }
+
+    #[test]
+    fn scan() {
+        let (_dir, storage) = storage();
+
+        let schemas = block_on(storage.fetch_all_schemas()).unwrap();
+        assert_eq!(schemas.len(), 1);
+        let columns = schemas[0]
+            .column_defs
+            .iter()
+            .flatten()
+            .map(|column_def| (column_def.name.as_str(), column_def.data_type.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![
+                ("number", DataType::Uint64),
+                ("miner", DataType::Text),
+                ("gas_used", DataType::Int),
+                ("topics", DataType::List),
+            ]
+        );
+
+        let rows = collect(block_on(storage.scan_data("Block")).unwrap());
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            block_on(storage.fetch_data("Block", &Key::I64(2))).unwrap(),
+            Some(DataRow::Vec(vec![
+                Value::U64(3),
+                Value::Str("0xaa".to_owned()),
+                Value::I64(42000),
+                Value::List(vec![Value::Str("0x2".to_owned())]),
+            ]))
+        );
+        assert_eq!(
+            block_on(storage.fetch_data("Block", &Key::I64(6))).unwrap(),
+            None
+        );
+        assert_eq!(block_on(storage.fetch_schema("Missing")).unwrap(), None);
+    }
+
+    #[test]
+    fn projection_and_pruning() {
+        let (_dir, storage) = storage();
+
+        let columns = ["number".to_owned(), "gas_used".to_owned()];
+        let (rows, handled) =
+            block_on(storage.scan_data_with_projection("Block", &columns, &[])).unwrap();
+        assert!(handled.is_empty());
+        assert_eq!(
+            collect(rows)[0],
+            (
+                Key::I64(0),
+                DataRow::Vec(vec![
+                    Value::U64(1),
+                    Value::Null,
+                    Value::I64(21000),
+                    Value::Null
+                ])
+            )
+        );
+
+        let predicates = [number(IndexOperator::GtEq, 4)];
+        let (rows, handled) =
+            block_on(storage.scan_data_with_predicates("Block", &predicates)).unwrap();
+        let keys = collect(rows)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        assert_eq!(handled, vec![false]);
+        assert_eq!(
+            keys,
+            vec![Key::I64(2), Key::I64(3), Key::I64(4), Key::I64(5)]
+        );
+
+        let predicates = [number(IndexOperator::Gt, 2), number(IndexOperator::Lt, 4)];
+        let (rows, _) =
+            block_on(storage.scan_data_with_projection("Block", &[], &predicates)).unwrap();
+        assert_eq!(
+            collect(rows),
+            vec![
+                (Key::I64(2), DataRow::Vec(vec![Value::Null; 4])),
+                (Key::I64(3), DataRow::Vec(vec![Value::Null; 4])),
+            ]
+        );
+
+        let predicates = [number(IndexOperator::Eq, 7)];
+        let (rows, _) = block_on(storage.scan_data_with_predicates("Block", &predicates)).unwrap();
+        assert_eq!(collect(rows), Vec::new());
+    }
+
+    #[test]
+    fn select() {
+        let (_dir, storage) = storage();
+        let mut worm = Worm::new(storage);
+
+        let payloads = block_on(worm.execute(
+            "SELECT miner, COUNT(*) AS blocks FROM Block
+             WHERE number >= 3 AND gas_used IS NULL
+             GROUP BY miner ORDER BY miner",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            payloads,
+            vec![Payload::Select {
+                labels: vec!["miner".to_owned(), "blocks".to_owned()],
+                rows: vec![
+                    vec![Value::Str("0xaa".to_owned()), Value::I64(1)],
+                    vec![Value::Str("0xbb".to_owned()), Value::I64(1)],
+                ],
+            }]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use {
+    crate::ResultExt,
+    arrow::{
+        array::{Array, AsArray},
+        datatypes::{
+            DataType as ArrowDataType, Date32Type, Date64Type, Float32Type, Float64Type, Int16Type,
+            Int32Type, Int64Type, Int8Type, Time32MillisecondType, Time32SecondType,
+            Time64MicrosecondType, Time64NanosecondType, TimeUnit, TimestampMicrosecondType,
+            TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type,
+            UInt32Type, UInt64Type, UInt8Type,
+        },
+        util::display::array_value_to_string,
+    },
+    parquet::file::{metadata::ColumnChunkMetaData, statistics::Statistics},
+    wql_core::{ast::DataType, data::Value, error::Result},
+};
+
+/// Data type of the values read from a column of arrow type `data_type`.
+///
+/// Types without counterpart are read as their text representation.
+pub fn data_type(data_type: &ArrowDataType) -> DataType {
+    match data_type {
+        ArrowDataType::Boolean => DataType::Boolean,
+        ArrowDataType::Int8 => DataType::Int8,
+        ArrowDataType::Int16 => DataType::Int16,
+        ArrowDataType::Int32 => DataType::Int32,
+        ArrowDataType::Int64 => DataType::Int,
+        ArrowDataType::UInt8 => DataType::Uint8,
+        ArrowDataType::UInt16 => DataType::Uint16,
+        ArrowDataType::UInt32 => DataType::Uint32,
+        ArrowDataType::UInt64 => DataType::Uint64,
+        ArrowDataType::Float32 => DataType::Float32,
+        ArrowDataType::Float64 => DataType::Float,
+        ArrowDataType::Decimal128(..) | ArrowDataType::Decimal256(..) => DataType::Decimal,
+        ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::FixedSizeBinary(_) => {
+            DataType::Bytea
+        }
+        ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
+        ArrowDataType::Timestamp(..) => DataType::Timestamp,
+        ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => DataType::Time,
+        ArrowDataType::List(_) | ArrowDataType::LargeList(_) => DataType::List,
+        ArrowDataType::Struct(_) => DataType::Map,
+        _ => DataType::Text,
+    }
+}
+
+/// Value at `i` of `array`.
+pub fn value(array: &dyn Array, i: usize) -> Result<Value> {
+    if array.is_null(i) {
+        return Ok(Value::Null);
+    }
+
+    let value = match array.data_type() {
+        ArrowDataType::Boolean => Value::Bool(array.as_boolean().value(i)),
+        ArrowDataType::Int8 => Value::I8(array.as_primitive::<Int8Type>().value(i)),
+        ArrowDataType::Int16 => Value::I16(array.as_primitive::<Int16Type>().value(i)),
+        ArrowDataType::Int32 => Value::I32(array.as_primitive::<Int32Type>().value(i)),
+        ArrowDataType::Int64 => Value::I64(array.as_primitive::<Int64Type>().value(i)),
+        ArrowDataType::UInt8 => Value::U8(array.as_primitive::<UInt8Type>().value(i)),
+        ArrowDataType::UInt16 => Value::U16(array.as_primitive::<UInt16Type>().value(i)),
+        ArrowDataType::UInt32 => Value::U32(array.as_primitive::<UInt32Type>().value(i)),
+        ArrowDataType::UInt64 => Value::U64(array.as_primitive::<UInt64Type>().value(i)),
+        ArrowDataType::Float32 => Value::F32(array.as_primitive::<Float32Type>().value(i)),
+        ArrowDataType::Float64 => Value::F64(array.as_primitive::<Float64Type>().value(i)),
+        ArrowDataType::Utf8 => Value::Str(array.as_string::<i32>().value(i).to_owned()),
+        ArrowDataType::LargeUtf8 => Value::Str(array.as_string::<i64>().value(i).to_owned()),
+        ArrowDataType::Binary => Value::Bytea(array.as_binary::<i32>().value(i).to_vec()),
+        ArrowDataType::LargeBinary => Value::Bytea(array.as_binary::<i64>().value(i).to_vec()),
+        ArrowDataType::FixedSizeBinary(_) => {
+            Value::Bytea(array.as_fixed_size_binary().value(i).to_vec())
+        }
+        ArrowDataType::Date32 => temporal(
+            array.as_primitive::<Date32Type>().value_as_date(i),
+            Value::Date,
+        ),
+        ArrowDataType::Date64 => temporal(
+            array.as_primitive::<Date64Type>().value_as_date(i),
+            Value::Date,
+        ),
+        ArrowDataType::Timestamp(unit, _) => {
+            let timestamp = match unit {
+                TimeUnit::Second => array
+                    .as_primitive::<TimestampSecondType>()
+                    .value_as_datetime(i),
+                TimeUnit::Millisecond => array
+                    .as_primitive::<TimestampMillisecondType>()
+                    .value_as_datetime(i),
+                TimeUnit::Microsecond => array
+                    .as_primitive::<TimestampMicrosecondType>()
+                    .value_as_datetime(i),
+                TimeUnit::Nanosecond => array
+                    .as_primitive::<TimestampNanosecondType>()
+                    .value_as_datetime(i),
+            };
+
+            temporal(timestamp, Value::Timestamp)
+        }
+        ArrowDataType::Time32(TimeUnit::Second) => temporal(
+            array.as_primitive::<Time32SecondType>().value_as_time(i),
+            Value::Time,
+        ),
+        ArrowDataType::Time32(_) => temporal(
+            array
+                .as_primitive::<Time32MillisecondType>()
+                .value_as_time(i),
+            Value::Time,
+        ),
+        ArrowDataType::Time64(TimeUnit::Microsecond) => temporal(
+            array
+                .as_primitive::<Time64MicrosecondType>()
+                .value_as_time(i),
+            Value::Time,
+        ),
+        ArrowDataType::Time64(_) => temporal(
+            array
+                .as_primitive::<Time64NanosecondType>()
+                .value_as_time(i),
+            Value::Time,
+        ),
+        ArrowDataType::List(_) => list(array.as_list::<i32>().value(i).as_ref())?,
+        ArrowDataType::LargeList(_) => list(array.as_list::<i64>().value(i).as_ref())?,
+        ArrowDataType::Struct(fields) => {
+            let values = fields
+                .iter()
+                .zip(array.as_struct().columns())
+                .map(|(field, column)| Ok((field.name().clone(), value(column.as_ref(), i)?)))
+                .collect::<Result<_>>()?;
+
+            Value::Map(values)
+        }
+        ArrowDataType::Decimal128(..) | ArrowDataType::Decimal256(..) => {
+            Value::Str(array_value_to_string(array, i).map_storage_err()?)
+                .cast(&DataType::Decimal)?
+        }
+        _ => Value::Str(array_value_to_string(array, i).map_storage_err()?),
+    };
+
+    Ok(value)
+}
+
+/// Temporal values out of the range of chrono are read as `NULL`.
+fn temporal<T>(value: Option<T>, into: fn(T) -> Value) -> Value {
+    value.map(into).unwrap_or(Value::Null)
+}
+
+fn list(array: &dyn Array) -> Result<Value> {
+    (0..array.len())
+        .map(|i| value(array, i))
+        .collect::<Result<_>>()
+        .map(Value::List)
+}
+
+/// Minimum and maximum values of a column chunk of arrow type `data_type`, `None` when its
+/// statistics are missing or not comparable with the values read from the column.
+pub fn min_max(column: &ColumnChunkMetaData, data_type: &ArrowDataType) -> Option<(Value, Value)> {
+    macro_rules! min_max {
+        ($statistics: expr, $into: expr) => {
+            ($into($statistics.min_opt()?), $into($statistics.max_opt()?))
+        };
+    }
+
+    let min_max = match (column.statistics()?, data_type) {
+        (Statistics::Boolean(statistics), ArrowDataType::Boolean) => {
+            min_max!(statistics, |v: &bool| Value::Bool(*v))
+        }
+        (
+            Statistics::Int32(statistics),
+            ArrowDataType::Int8 | ArrowDataType::Int16 | ArrowDataType::Int32,
+        ) => min_max!(statistics, |v: &i32| Value::I64(*v as i64)),
+        (
+            Statistics::Int32(statistics),
+            ArrowDataType::UInt8 | ArrowDataType::UInt16 | ArrowDataType::UInt32,
+        ) => min_max!(statistics, |v: &i32| Value::U64(*v as u32 as u64)),
+        (Statistics::Int64(statistics), ArrowDataType::Int64) => {
+            min_max!(statistics, |v: &i64| Value::I64(*v))
+        }
+        (Statistics::Int64(statistics), ArrowDataType::UInt64) => {
+            min_max!(statistics, |v: &i64| Value::U64(*v as u64))
+        }
+        (Statistics::Float(statistics), ArrowDataType::Float32) => {
+            min_max!(statistics, |v: &f32| Value::F32(*v))
+        }
+        (Statistics::Double(statistics), ArrowDataType::Float64) => {
+            min_max!(statistics, |v: &f64| Value::F64(*v))
+        }
+        (Statistics::ByteArray(statistics), ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) => {
+            let min = statistics.min_opt()?.as_utf8().ok()?;
+            let max = statistics.max_opt()?.as_utf8().ok()?;
+
+            (Value::Str(min.to_owned()), Value::Str(max.to_owned()))
+        }
+        _ => return None,
+    };
+
+    Some(min_max)
+}
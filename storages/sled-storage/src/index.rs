@@ -0,0 +1,327 @@
+use {
+    super::{data_key, data_prefix, decode, schema_key, ResultExt, SledStorage, Writes},
+    async_trait::async_trait,
+    futures::stream,
+    std::ops::Bound,
+    wql_core::{
+        ast::{IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{Key, Schema, SchemaIndex, SchemaIndexOrd, Value},
+        error::Result,
+        store::{index_value, DataRow, Index, IndexError, IndexMut, RowIter},
+    },
+};
+
+fn index_prefix(table_name: &str, index_name: &str) -> Vec<u8> {
+    format!("index/{table_name}/{index_name}/").into_bytes()
+}
+
+/// Key of the entry of `index` listing the rows indexed by `value`.
+fn index_key(schema: &Schema, index: &SchemaIndex, value: Value) -> Result<Vec<u8>> {
+    let mut index_key = index_prefix(&schema.table_name, &index.name);
+    index_key.extend(Key::try_from(value)?.to_cmp_be_bytes()?);
+
+    Ok(index_key)
+}
+
+impl SledStorage {
+    /// Stages the entries indexing `row` under `key` in every index of `schema`.
+    pub(crate) async fn index(
+        &self,
+        writes: &mut Writes,
+        schema: &Schema,
+        key: &Key,
+        row: &DataRow,
+    ) -> Result<()> {
+        for index in schema.indexes.iter() {
+            self.add_entry(writes, schema, index, key, row).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stages the removal of `key` from the entries indexing `row` in every index of `schema`.
+    pub(crate) async fn unindex(
+        &self,
+        writes: &mut Writes,
+        schema: &Schema,
+        key: &Key,
+        row: &DataRow,
+    ) -> Result<()> {
+        for index in schema.indexes.iter() {
+            let value = index_value(schema, index, row).await?;
+            let index_key = index_key(schema, index, value)?;
+            let mut keys = writes
+                .get::<Vec<Key>>(self, &index_key)?
+                .unwrap_or_default();
+            keys.retain(|indexed| indexed != key);
+
+            match keys.is_empty() {
+                true => writes.remove(index_key),
+                false => writes.insert(index_key, &keys)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn add_entry(
+        &self,
+        writes: &mut Writes,
+        schema: &Schema,
+        index: &SchemaIndex,
+        key: &Key,
+        row: &DataRow,
+    ) -> Result<()> {
+        let value = index_value(schema, index, row).await?;
+        let index_key = index_key(schema, index, value)?;
+        let mut keys = writes
+            .get::<Vec<Key>>(self, &index_key)?
+            .unwrap_or_default();
+
+        if let Err(i) = keys.binary_search(key) {
+            keys.insert(i, key.clone());
+        }
+
+        writes.insert(index_key, &keys)
+    }
+
+    async fn fetch_indexed_schema(&self, table_name: &str) -> Result<Schema> {
+        self.get(&schema_key(table_name))?
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()).into())
+    }
+}
+
+#[async_trait(?Send)]
+impl Index for SledStorage {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        let schema = self.fetch_indexed_schema(table_name).await?;
+        let index = schema
+            .indexes
+            .iter()
+            .find(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let prefix = index_prefix(table_name, index_name);
+        let end = {
+            let mut end = prefix.clone();
+            if let Some(last) = end.last_mut() {
+                *last += 1;
+            }
+
+            end
+        };
+        let entries = match cmp_value {
+            None => self.tree.scan_prefix(&prefix),
+            Some((op, value)) => {
+                let bound = index_key(&schema, index, value)?;
+                let (start, end) = match op {
+                    IndexOperator::Eq => (Bound::Included(bound.clone()), Bound::Included(bound)),
+                    IndexOperator::Gt => (Bound::Excluded(bound), Bound::Excluded(end)),
+                    IndexOperator::GtEq => (Bound::Included(bound), Bound::Excluded(end)),
+                    IndexOperator::Lt => (Bound::Included(prefix), Bound::Excluded(bound)),
+                    IndexOperator::LtEq => (Bound::Included(prefix), Bound::Included(bound)),
+                };
+
+                self.tree.range((start, end))
+            }
+        };
+        let entries: Box<dyn Iterator<Item = _>> = match asc {
+            Some(false) => Box::new(entries.rev()),
+            _ => Box::new(entries),
+        };
+
+        let mut rows = Vec::new();
+        for entry in entries {
+            let (_, keys) = entry.map_storage_err()?;
+
+            for key in decode::<Vec<Key>>(&keys)? {
+                if let Some(row) = self.get::<(Key, DataRow)>(&data_key(table_name, &key)?)? {
+                    rows.push(Ok(row));
+                }
+            }
+        }
+
+        Ok(Box::pin(stream::iter(rows)))
+    }
+}
+
+#[async_trait(?Send)]
+impl IndexMut for SledStorage {
+    async fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        let mut schema = self.fetch_indexed_schema(table_name).await?;
+
+        if schema.indexes.iter().any(|index| index.name == index_name) {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let order = match column.asc {
+            Some(true) => SchemaIndexOrd::Asc,
+            Some(false) => SchemaIndexOrd::Desc,
+            None => SchemaIndexOrd::Both,
+        };
+        let index = SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order,
+            created: Utc::now().naive_utc(),
+        };
+
+        let mut writes = Writes::default();
+        for entry in self.tree.scan_prefix(data_prefix(table_name)) {
+            let (key, row) = decode::<(Key, DataRow)>(&entry.map_storage_err()?.1)?;
+
+            self.add_entry(&mut writes, &schema, &index, &key, &row)
+                .await?;
+        }
+
+        schema.indexes.push(index);
+        writes.insert(schema_key(table_name), &schema)?;
+
+        self.apply(writes)
+    }
+
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let mut schema = self.fetch_indexed_schema(table_name).await?;
+        let position = schema
+            .indexes
+            .iter()
+            .position(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+        schema.indexes.remove(position);
+
+        let mut writes = Writes::default();
+        self.remove_prefix(&mut writes, &index_prefix(table_name, index_name))?;
+        writes.insert(schema_key(table_name), &schema)?;
+
+        self.apply(writes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            tests::{row, schema, storage},
+            SledStorage,
+        },
+        futures::{executor::block_on, TryStreamExt},
+        wql_core::{
+            ast::{Expr, IndexOperator, OrderByExpr},
+            data::{Key, Value},
+            store::{Index, IndexMut, StoreMut},
+        },
+    };
+
+    fn scan(
+        storage: &SledStorage,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Vec<Key> {
+        block_on(async {
+            storage
+                .scan_indexed_data("Foo", "idx_name", asc, cmp_value)
+                .await
+                .unwrap()
+                .map_ok(|(key, _)| key)
+                .try_collect()
+                .await
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn index() {
+        let mut storage = storage();
+        let column = OrderByExpr {
+            expr: Expr::Identifier("name".to_owned()),
+            asc: None,
+        };
+        let str = |value: &str| Value::Str(value.to_owned());
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage
+                .insert_data(
+                    "Foo",
+                    vec![(Key::I64(1), row(1, "c")), (Key::I64(2), row(2, "a"))],
+                )
+                .await
+                .unwrap();
+            storage
+                .create_index("Foo", "idx_name", &column)
+                .await
+                .unwrap();
+            storage
+                .insert_data(
+                    "Foo",
+                    vec![(Key::I64(3), row(3, "b")), (Key::I64(4), row(4, "b"))],
+                )
+                .await
+                .unwrap();
+
+            assert!(storage
+                .create_index("Foo", "idx_name", &column)
+                .await
+                .is_err());
+            assert!(storage
+                .create_index("Bar", "idx_name", &column)
+                .await
+                .is_err());
+        });
+
+        assert_eq!(
+            scan(&storage, None, None),
+            vec![Key::I64(2), Key::I64(3), Key::I64(4), Key::I64(1)]
+        );
+        assert_eq!(
+            scan(&storage, Some(false), None),
+            vec![Key::I64(1), Key::I64(3), Key::I64(4), Key::I64(2)]
+        );
+        assert_eq!(
+            scan(&storage, None, Some((&IndexOperator::Eq, str("b")))),
+            vec![Key::I64(3), Key::I64(4)]
+        );
+        assert_eq!(
+            scan(&storage, None, Some((&IndexOperator::Gt, str("b")))),
+            vec![Key::I64(1)]
+        );
+        assert_eq!(
+            scan(&storage, None, Some((&IndexOperator::LtEq, str("b")))),
+            vec![Key::I64(2), Key::I64(3), Key::I64(4)]
+        );
+
+        block_on(async {
+            storage
+                .insert_data("Foo", vec![(Key::I64(3), row(3, "d"))])
+                .await
+                .unwrap();
+            storage.delete_data("Foo", vec![Key::I64(2)]).await.unwrap();
+        });
+
+        assert_eq!(
+            scan(&storage, None, Some((&IndexOperator::GtEq, str("b")))),
+            vec![Key::I64(4), Key::I64(1), Key::I64(3)]
+        );
+        assert_eq!(
+            scan(&storage, None, Some((&IndexOperator::Lt, str("b")))),
+            Vec::new()
+        );
+
+        block_on(storage.drop_index("Foo", "idx_name")).unwrap();
+        assert!(block_on(storage.drop_index("Foo", "idx_name")).is_err());
+        assert!(block_on(storage.scan_indexed_data("Foo", "idx_name", None, None)).is_err());
+        assert_eq!(storage.tree.scan_prefix("index/").count(), 0);
+    }
+}
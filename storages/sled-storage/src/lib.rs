@@ -0,0 +1,358 @@
+#![deny(clippy::str_to_string)]
+
+mod error;
+mod index;
+mod transaction;
+
+pub use error::ResultExt;
+
+use {
+    async_trait::async_trait,
+    futures::stream,
+    serde::{de::DeserializeOwned, Serialize},
+    sled::{transaction::ConflictableTransactionResult, Config, Db, IVec},
+    std::collections::HashMap,
+    wql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{CustomFunction, CustomFunctionMut, DataRow, Metadata, RowIter, Store, StoreMut},
+    },
+};
+
+/// Storage which persists tables in a sled database.
+///
+/// Every record lives in the default tree under a key prefixed by its kind:
+///
+/// - `schema/<table>` holds the schema of the table,
+/// - `data/<table>/<key>` holds a row, `<key>` being [`Key::to_cmp_be_bytes`] so that scans
+///   are ordered by key,
+/// - `index/<table>/<index>/<value>` holds the keys of the rows indexed by `<value>`.
+#[derive(Debug, Clone)]
+pub struct SledStorage {
+    pub tree: Db,
+    /// Values overwritten since `BEGIN`, restored by `ROLLBACK`.
+    undo: Option<HashMap<IVec, Option<IVec>>>,
+}
+
+impl SledStorage {
+    pub fn new(path: &str) -> Result<Self> {
+        Self::try_from(Config::default().path(path))
+    }
+
+    fn get<T: DeserializeOwned>(&self, key: &[u8]) -> Result<Option<T>> {
+        self.tree
+            .get(key)
+            .map_storage_err()?
+            .map(|value| decode(&value))
+            .transpose()
+    }
+
+    /// Applies `writes` atomically, a `None` value removing the key.
+    fn apply(&mut self, writes: Writes) -> Result<()> {
+        let writes = writes.0.into_iter().collect::<Vec<_>>();
+        let overwritten = self
+            .tree
+            .transaction(|tx| -> ConflictableTransactionResult<Vec<_>, sled::Error> {
+                writes
+                    .iter()
+                    .map(|(key, value)| {
+                        let overwritten = match value {
+                            Some(value) => tx.insert(key, value)?,
+                            None => tx.remove(key)?,
+                        };
+
+                        Ok(overwritten)
+                    })
+                    .collect()
+            })
+            .map_storage_err()?;
+
+        if let Some(undo) = self.undo.as_mut() {
+            for ((key, _), overwritten) in writes.into_iter().zip(overwritten) {
+                undo.entry(key).or_insert(overwritten);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stages the removal of every key starting with `prefix`.
+    fn remove_prefix(&self, writes: &mut Writes, prefix: &[u8]) -> Result<()> {
+        for entry in self.tree.scan_prefix(prefix) {
+            let (key, _) = entry.map_storage_err()?;
+
+            writes.0.insert(key, None);
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Config> for SledStorage {
+    type Error = wql_core::error::Error;
+
+    fn try_from(config: Config) -> Result<Self> {
+        let tree = config.open().map_storage_err()?;
+
+        Ok(Self { tree, undo: None })
+    }
+}
+
+/// Writes staged before being applied at once by [`SledStorage::apply`].
+#[derive(Default)]
+struct Writes(HashMap<IVec, Option<IVec>>);
+
+impl Writes {
+    /// Value of `key` once the staged writes are applied.
+    fn get<T: DeserializeOwned>(&self, storage: &SledStorage, key: &[u8]) -> Result<Option<T>> {
+        match self.0.get(key) {
+            Some(Some(value)) => decode(value).map(Some),
+            Some(None) => Ok(None),
+            None => storage.get(key),
+        }
+    }
+
+    fn insert<T: Serialize>(&mut self, key: Vec<u8>, value: &T) -> Result<()> {
+        let value = serde_json::to_vec(value).map_storage_err()?;
+        self.0.insert(key.into(), Some(value.into()));
+
+        Ok(())
+    }
+
+    fn remove(&mut self, key: Vec<u8>) {
+        self.0.insert(key.into(), None);
+    }
+}
+
+fn decode<T: DeserializeOwned>(value: &[u8]) -> Result<T> {
+    serde_json::from_slice(value).map_storage_err()
+}
+
+fn schema_key(table_name: &str) -> Vec<u8> {
+    format!("schema/{table_name}").into_bytes()
+}
+
+fn data_prefix(table_name: &str) -> Vec<u8> {
+    format!("data/{table_name}/").into_bytes()
+}
+
+fn data_key(table_name: &str, key: &Key) -> Result<Vec<u8>> {
+    let mut data_key = data_prefix(table_name);
+    data_key.extend(key.to_cmp_be_bytes()?);
+
+    Ok(data_key)
+}
+
+#[async_trait(?Send)]
+impl Store for SledStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.get(&schema_key(table_name))
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.tree
+            .scan_prefix("schema/")
+            .map(|entry| decode(&entry.map_storage_err()?.1))
+            .collect()
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let row = self
+            .get::<(Key, DataRow)>(&data_key(table_name, key)?)?
+            .map(|(_, row)| row);
+
+        Ok(row)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>> {
+        let rows = self
+            .tree
+            .scan_prefix(data_prefix(table_name))
+            .map(|entry| decode(&entry.map_storage_err()?.1));
+
+        Ok(Box::pin(stream::iter(rows)))
+    }
+}
+
+#[async_trait(?Send)]
+impl StoreMut for SledStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let mut writes = Writes::default();
+        writes.insert(schema_key(&schema.table_name), schema)?;
+
+        self.apply(writes)
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let mut writes = Writes::default();
+        writes.remove(schema_key(table_name));
+        self.remove_prefix(&mut writes, &data_prefix(table_name))?;
+        self.remove_prefix(&mut writes, format!("index/{table_name}/").as_bytes())?;
+
+        self.apply(writes)
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let id = self.tree.generate_id().map_storage_err()?;
+
+                Ok((Key::I64(id as i64), row))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.insert_data(table_name, rows).await
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let Some(schema) = self.fetch_schema(table_name).await? else {
+            return Ok(());
+        };
+
+        let mut writes = Writes::default();
+        for (key, row) in rows {
+            let data_key = data_key(table_name, &key)?;
+            if let Some((_, old)) = writes.get::<(Key, DataRow)>(self, &data_key)? {
+                self.unindex(&mut writes, &schema, &key, &old).await?;
+            }
+
+            self.index(&mut writes, &schema, &key, &row).await?;
+            writes.insert(data_key, &(key, row))?;
+        }
+
+        self.apply(writes)
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let Some(schema) = self.fetch_schema(table_name).await? else {
+            return Ok(());
+        };
+
+        let mut writes = Writes::default();
+        for key in keys {
+            let data_key = data_key(table_name, &key)?;
+            if let Some((_, old)) = writes.get::<(Key, DataRow)>(self, &data_key)? {
+                self.unindex(&mut writes, &schema, &key, &old).await?;
+                writes.remove(data_key);
+            }
+        }
+
+        self.apply(writes)
+    }
+}
+
+impl Metadata for SledStorage {}
+impl CustomFunction for SledStorage {}
+impl CustomFunctionMut for SledStorage {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::SledStorage,
+        futures::{executor::block_on, TryStreamExt},
+        sled::Config,
+        wql_core::{
+            ast::{ColumnDef, DataType},
+            data::{Key, Schema, Value},
+            store::{DataRow, Store, StoreMut},
+        },
+    };
+
+    pub fn storage() -> SledStorage {
+        SledStorage::try_from(Config::default().temporary(true)).unwrap()
+    }
+
+    pub fn schema(table_name: &str) -> Schema {
+        let column = |name: &str, data_type| ColumnDef {
+            name: name.to_owned(),
+            data_type,
+            nullable: true,
+            default: None,
+            comment: None,
+        };
+
+        Schema {
+            table_name: table_name.to_owned(),
+            column_defs: Some(vec![
+                column("id", DataType::Int),
+                column("name", DataType::Text),
+            ]),
+            indexes: Vec::new(),
+            comment: None,
+            chain_name: None,
+            view: None,
+        }
+    }
+
+    pub fn row(id: i64, name: &str) -> DataRow {
+        DataRow::Vec(vec![Value::I64(id), Value::Str(name.to_owned())])
+    }
+
+    pub fn scan(storage: &SledStorage, table_name: &str) -> Vec<(Key, DataRow)> {
+        block_on(async {
+            storage
+                .scan_data(table_name)
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn store() {
+        let mut storage = storage();
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage.insert_schema(&schema("Bar")).await.unwrap();
+            storage
+                .insert_data(
+                    "Foo",
+                    vec![
+                        (Key::I64(300), row(3, "c")),
+                        (Key::I64(-1), row(1, "a")),
+                        (Key::I64(2), row(2, "b")),
+                    ],
+                )
+                .await
+                .unwrap();
+            storage
+                .insert_data("Foo", vec![(Key::I64(2), row(2, "d"))])
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            scan(&storage, "Foo"),
+            vec![
+                (Key::I64(-1), row(1, "a")),
+                (Key::I64(2), row(2, "d")),
+                (Key::I64(300), row(3, "c")),
+            ]
+        );
+        assert_eq!(
+            block_on(storage.fetch_data("Foo", &Key::I64(300))).unwrap(),
+            Some(row(3, "c"))
+        );
+
+        let table_names = block_on(storage.fetch_all_schemas())
+            .unwrap()
+            .into_iter()
+            .map(|schema| schema.table_name)
+            .collect::<Vec<_>>();
+        assert_eq!(table_names, vec!["Bar", "Foo"]);
+
+        block_on(storage.append_data("Bar", vec![row(1, "a"), row(2, "b")])).unwrap();
+        assert_eq!(scan(&storage, "Bar").len(), 2);
+
+        block_on(storage.delete_data("Foo", vec![Key::I64(-1), Key::I64(5)])).unwrap();
+        assert_eq!(scan(&storage, "Foo").len(), 2);
+
+        block_on(storage.delete_schema("Foo")).unwrap();
+        assert_eq!(block_on(storage.fetch_schema("Foo")).unwrap(), None);
+        assert_eq!(scan(&storage, "Foo"), Vec::new());
+    }
+}
@@ -0,0 +1,132 @@
+use {
+    super::{ResultExt, SledStorage, Writes},
+    async_trait::async_trait,
+    wql_core::{
+        error::{Error, Result},
+        store::Transaction,
+    },
+};
+
+/// Writes go through sled transactions, so that each of them is applied atomically. A
+/// transaction additionally keeps the values it overwrites to restore them on `ROLLBACK`.
+///
+/// Outside of an explicit transaction, each statement runs in an autocommit transaction of its
+/// own, so that a failing statement leaves none of its writes behind.
+#[async_trait(?Send)]
+impl Transaction for SledStorage {
+    async fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        if autocommit {
+            if self.undo.is_some() {
+                return Ok(false);
+            }
+
+            self.undo = Some(Default::default());
+
+            return Ok(true);
+        }
+
+        if self.undo.is_some() {
+            return Err(Error::StorageMsg(
+                "[SledStorage] nested transaction is not supported".to_owned(),
+            ));
+        }
+
+        self.undo = Some(Default::default());
+
+        Ok(false)
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        if let Some(undo) = self.undo.take() {
+            self.apply(Writes(undo))?;
+        }
+
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        self.undo = None;
+        self.tree.flush_async().await.map_storage_err()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::tests::{row, scan, schema, storage},
+        futures::executor::block_on,
+        wql_core::{
+            data::Key,
+            store::{Store, StoreMut, Transaction},
+        },
+    };
+
+    #[test]
+    fn rollback() {
+        let mut storage = storage();
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+            storage
+                .insert_data("Foo", vec![(Key::I64(1), row(1, "a"))])
+                .await
+                .unwrap();
+
+            storage.begin(false).await.unwrap();
+            assert!(storage.begin(false).await.is_err());
+            storage
+                .insert_data(
+                    "Foo",
+                    vec![(Key::I64(1), row(1, "b")), (Key::I64(2), row(2, "c"))],
+                )
+                .await
+                .unwrap();
+            storage.insert_schema(&schema("Bar")).await.unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(scan(&storage, "Foo"), vec![(Key::I64(1), row(1, "a"))]);
+        assert_eq!(block_on(storage.fetch_schema("Bar")).unwrap(), None);
+
+        block_on(async {
+            storage.begin(false).await.unwrap();
+            storage.delete_data("Foo", vec![Key::I64(1)]).await.unwrap();
+            storage.commit().await.unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(scan(&storage, "Foo"), Vec::new());
+    }
+
+    #[test]
+    fn autocommit() {
+        let mut storage = storage();
+
+        block_on(async {
+            storage.insert_schema(&schema("Foo")).await.unwrap();
+
+            assert!(storage.begin(true).await.unwrap());
+            storage
+                .insert_data("Foo", vec![(Key::I64(1), row(1, "a"))])
+                .await
+                .unwrap();
+            storage.rollback().await.unwrap();
+        });
+
+        assert_eq!(scan(&storage, "Foo"), Vec::new());
+
+        block_on(async {
+            storage.begin(false).await.unwrap();
+            assert!(!storage.begin(true).await.unwrap());
+            storage
+                .insert_data("Foo", vec![(Key::I64(1), row(1, "a"))])
+                .await
+                .unwrap();
+            storage.commit().await.unwrap();
+        });
+
+        assert_eq!(scan(&storage, "Foo"), vec![(Key::I64(1), row(1, "a"))]);
+    }
+}